@@ -0,0 +1,40 @@
+//! Type checks of format argument values
+//!
+//! This module checks the values passed as format arguments where they are
+//! written, so a value that can't be formatted is reported at the argument
+//! along with its name and the translation path, instead of inside the
+//! generated replacements.
+
+use std::fmt::Display;
+
+/// Value that can replace a template of a translation
+///
+/// `Name` and `Path` are marker types the macros declare after the argument
+/// name and the translation path, so they show up in the error message.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't implement `Display`, so it can't be the `{Name}` argument of \
+               `{Path}`",
+    label = "the `{Name}` argument",
+    note = "format arguments are written with `std::fmt::Display`"
+)]
+pub trait FormatArgument<Name, Path> {
+    /// Gets the value as a `Display` trait object
+    fn as_display(&self) -> &dyn Display;
+}
+
+impl<Name, Path, T: Display> FormatArgument<Name, Path> for T {
+    fn as_display(&self) -> &dyn Display {
+        self
+    }
+}
+
+/// Checks that a format argument value can replace a template
+///
+/// The value is returned as a trait object, so a value that can't be
+/// formatted is only reported once, by this check.
+///
+/// # Returns
+/// The value as a `Display` trait object
+pub fn format_argument<Name, Path, T: FormatArgument<Name, Path>>(value: &T) -> &dyn Display {
+    value.as_display()
+}
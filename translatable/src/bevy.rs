@@ -0,0 +1,230 @@
+//! Bevy plugin for game localization
+//!
+//! This module provides the [`TranslatablePlugin`], which inserts the
+//! [`Locale`] resource games switch their language with, the [`Translations`]
+//! system parameter resolving paths for it, and optionally loads translation
+//! files as assets, so they are reloaded while editing them with the
+//! `file_watcher` feature of Bevy.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::{Error as IoError, ErrorKind};
+
+use ::bevy::app::{App, Plugin, PreUpdate, Startup};
+use ::bevy::asset::io::Reader;
+use ::bevy::asset::{
+    Asset, AssetApp, AssetEvent, AssetLoader, AssetServer, Assets, Handle, LoadContext,
+};
+use ::bevy::ecs::change_detection::{DetectChanges, DetectChangesMut};
+use ::bevy::ecs::message::MessageReader;
+use ::bevy::ecs::resource::Resource;
+use ::bevy::ecs::system::{Local, Res, ResMut, SystemParam};
+use ::bevy::reflect::TypePath;
+use translatable_shared::{Language, Precedence, TranslationNodeCollection};
+
+use crate::loading::parse_translations;
+use crate::{Error, LoadError, Locale, Translator, set_overrides};
+
+/// Plugin inserting the [`Locale`] resource
+///
+/// # Example
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(TranslatablePlugin::new(Language::EN).with_file("translations/game.toml"))
+///     .run();
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranslatablePlugin {
+    /// Initial language of the locale
+    language: Language,
+    /// Asset paths of the translation files loaded as overrides
+    files: Vec<String>,
+}
+
+impl TranslatablePlugin {
+    /// Creates a plugin with the initial language of the locale
+    pub fn new(language: Language) -> Self {
+        Self { language, files: Vec::new() }
+    }
+
+    /// Loads a translation file from the assets as overrides of the
+    /// process-wide translations
+    ///
+    /// Files added first take precedence, every time one of them is loaded
+    /// or modified the overrides are replaced with the loaded ones, so the
+    /// `Locale` resource is marked as changed. Requires the `AssetPlugin`.
+    pub fn with_file(mut self, path: impl Into<String>) -> Self {
+        self.files.push(path.into());
+        self
+    }
+}
+
+impl Plugin for TranslatablePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Locale::new(self.language.clone()))
+            .add_systems(PreUpdate, detect_language_change);
+
+        if !self.files.is_empty() {
+            app.init_asset::<TranslationFile>()
+                .register_asset_loader(TranslationFileLoader)
+                .insert_resource(TranslationFiles {
+                    paths: self.files.clone(),
+                    handles: Vec::new(),
+                })
+                .add_systems(Startup, load_translation_files)
+                .add_systems(PreUpdate, apply_translation_files);
+        }
+    }
+}
+
+/// Translation file loaded as an asset
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct TranslationFile(pub TranslationNodeCollection);
+
+/// Loader of the `.toml` translation files
+#[derive(TypePath, Default)]
+pub struct TranslationFileLoader;
+
+impl AssetLoader for TranslationFileLoader {
+    type Asset = TranslationFile;
+    type Settings = ();
+    type Error = LoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().to_string();
+        let mut content = Vec::new();
+
+        reader.read_to_end(&mut content).await.map_err(|err| LoadError::Io(err, path.clone()))?;
+
+        let content = String::from_utf8(content).map_err(|err| {
+            LoadError::Io(IoError::new(ErrorKind::InvalidData, err), path.clone())
+        })?;
+
+        Ok(TranslationFile(parse_translations(&content, &path)?))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["toml"]
+    }
+}
+
+/// Translation files configured with [`TranslatablePlugin::with_file`]
+#[derive(Resource)]
+struct TranslationFiles {
+    /// Asset paths, in precedence order
+    paths: Vec<String>,
+    /// Handles keeping the files loaded, in precedence order
+    handles: Vec<Handle<TranslationFile>>,
+}
+
+/// Starts loading the configured translation files
+fn load_translation_files(mut files: ResMut<TranslationFiles>, server: Res<AssetServer>) {
+    files.handles = files.paths.iter().map(|path| server.load(path.clone())).collect();
+}
+
+/// Replaces the overrides when a translation file is loaded or modified
+fn apply_translation_files(
+    mut events: MessageReader<AssetEvent<TranslationFile>>,
+    files: Res<TranslationFiles>,
+    assets: Res<Assets<TranslationFile>>,
+    mut locale: ResMut<Locale>,
+) {
+    let reloaded = events.read().any(|event| {
+        files
+            .handles
+            .iter()
+            .any(|handle| event.is_loaded_with_dependencies(handle) || event.is_modified(handle))
+    });
+
+    if !reloaded {
+        return;
+    }
+
+    let overrides = files
+        .handles
+        .iter()
+        .filter_map(|handle| assets.get(handle))
+        .map(|file| file.0.clone())
+        .reduce(|overrides, file| overrides.inherit(file, Precedence::Application));
+
+    if let Some(overrides) = overrides {
+        set_overrides(overrides);
+        locale.set_changed();
+    }
+}
+
+/// Marks the `Locale` resource as changed when its language is switched
+///
+/// [`Locale::set_language`] doesn't go through `ResMut`, so change detection
+/// is applied once per frame here instead.
+fn detect_language_change(mut locale: ResMut<Locale>, mut last: Local<Option<Language>>) {
+    let language = locale.language();
+
+    if last.as_ref() != Some(&language) {
+        *last = Some(language);
+        locale.set_changed();
+    }
+}
+
+/// System parameter resolving translations for the [`Locale`] resource
+///
+/// Compile-time validated lookups take [`Translations::language`], either
+/// with the typed accessors or with the `translation!` macro and a static
+/// path.
+///
+/// # Example
+/// ```ignore
+/// fn update_title(translations: Translations, mut titles: Query<&mut Text, With<Title>>) {
+///     if translations.is_changed() {
+///         for mut title in &mut titles {
+///             **title = menu::title(translations.language()).to_string();
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct Translations<'w> {
+    /// Locale resource inserted by the plugin
+    locale: Res<'w, Locale>,
+}
+
+impl Translations<'_> {
+    /// Gets the current language
+    pub fn language(&self) -> Language {
+        self.locale.language()
+    }
+
+    /// Whether the language or the translation files changed since the
+    /// system last ran, to refresh the texts
+    pub fn is_changed(&self) -> bool {
+        self.locale.is_changed()
+    }
+
+    /// Creates a translator for the current language, for lookups of
+    /// dynamic paths
+    pub fn translator(&self) -> Translator<'static> {
+        self.locale.translator()
+    }
+
+    /// Resolves a dot-separated path for the current language, see
+    /// [`Translator::get`]
+    pub fn get(&self, path: &str) -> Result<Cow<'static, str>, Error> {
+        self.translator().get(path)
+    }
+
+    /// Resolves a dot-separated path for the current language replacing its
+    /// templates, see [`Translator::get_with`]
+    pub fn get_with(
+        &self,
+        path: &str,
+        arguments: &[(&str, &dyn Display)],
+    ) -> Result<Cow<'static, str>, Error> {
+        self.translator().get_with(path, arguments)
+    }
+}
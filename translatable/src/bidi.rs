@@ -0,0 +1,80 @@
+//! Bidi isolation of argument values
+//!
+//! This module wraps the values replaced in right-to-left translations in
+//! the Unicode first strong isolate (FSI) and pop directional isolate (PDI)
+//! characters, so left-to-right values such as user names or URLs don't
+//! reorder the text around them.
+
+use std::borrow::Cow;
+
+use translatable_shared::{Language, TextDirection, split_filters};
+
+/// First strong isolate, opens an isolated value
+pub(crate) const FSI: char = '\u{2068}';
+
+/// Pop directional isolate, closes an isolated value
+pub(crate) const PDI: char = '\u{2069}';
+
+/// Returns whether the values of a language's translations are isolated
+pub(crate) fn isolates(language: &Language) -> bool {
+    language.direction() == TextDirection::RightToLeft
+}
+
+/// Wraps the templates of some arguments in isolation characters
+///
+/// Only right-to-left translations are changed, templates with filter
+/// pipes are wrapped as a whole and escaped templates are kept as they are.
+///
+/// # Arguments
+/// * `language` - Language the translation is formatted for
+/// * `translation` - Translation containing the templates
+/// * `names` - Names of the arguments whose templates are wrapped
+pub fn isolate_templates<'a>(
+    language: &Language,
+    translation: Cow<'a, str>,
+    names: &[&str],
+) -> Cow<'a, str> {
+    if !isolates(language) {
+        return translation;
+    }
+
+    let mut isolated = String::new();
+    let mut rest = translation.as_ref();
+    let mut replaced = false;
+
+    while let Some(start) = rest.find('{') {
+        isolated.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Escaped templates are kept as they are
+        if rest.starts_with("{{") {
+            let end = rest[2..].find("}}").map_or(2, |end| end + 4);
+            isolated.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some(end) = rest.find('}') else { break };
+        let template = &rest[1..end];
+
+        if !template.contains('{') && names.contains(&split_filters(template).0) {
+            isolated.push(FSI);
+            isolated.push_str(&rest[..=end]);
+            isolated.push(PDI);
+            replaced = true;
+
+            rest = &rest[end + 1..];
+        } else {
+            // Anything else is rescanned after the brace
+            isolated.push('{');
+            rest = &rest[1..];
+        }
+    }
+
+    if !replaced {
+        return translation;
+    }
+
+    isolated.push_str(rest);
+    Cow::Owned(isolated)
+}
@@ -0,0 +1,55 @@
+//! Browser locale detection
+//!
+//! This module reads the preferred languages of the browser running a
+//! WebAssembly application, such as a Yew or Leptos one, to pick the
+//! language its [`Translator`](crate::Translator) starts with.
+//!
+//! Only available with the `web` feature on `wasm32` targets.
+
+use translatable_shared::Language;
+
+/// Parses the primary language subtag of a BCP 47 tag, such as `"es-AR"`
+fn primary_language(tag: &str) -> Option<Language> {
+    tag.split(['-', '_']).next()?.to_lowercase().parse().ok()
+}
+
+/// Returns the preferred languages of the browser, in preference order
+///
+/// The `navigator.languages` tags are reduced to their primary language,
+/// falling back to `navigator.language`. Tags of languages that aren't ISO
+/// 639-1 are skipped, and every language is only returned once.
+pub fn browser_languages() -> Vec<Language> {
+    let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+        return Vec::new();
+    };
+
+    let mut tags =
+        navigator.languages().iter().filter_map(|tag| tag.as_string()).collect::<Vec<_>>();
+
+    if tags.is_empty() {
+        tags.extend(navigator.language());
+    }
+
+    let mut languages = Vec::new();
+    for language in tags.iter().filter_map(|tag| primary_language(tag)) {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+
+    languages
+}
+
+/// Returns the most preferred browser language among the available ones
+///
+/// # Arguments
+/// * `available` - Languages the application is translated to, in any order
+///
+/// # Example
+/// ```ignore
+/// let language = browser_language(&[Language::EN, Language::ES]).unwrap_or(Language::EN);
+/// let translator = Translator::new(language);
+/// ```
+pub fn browser_language(available: &[Language]) -> Option<Language> {
+    browser_languages().into_iter().find(|language| available.contains(language))
+}
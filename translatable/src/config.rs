@@ -0,0 +1,236 @@
+//! Programmatic macro configuration
+//!
+//! This module builds the `translatable.toml` configuration in code, so
+//! build scripts of crates whose layout doesn't fit a configuration file in
+//! the workspace root, such as monorepos sharing a translations directory, can
+//! write it and forward it to the macros with `TRANSLATABLE_CONFIG`.
+
+use std::env::var;
+use std::fs::{create_dir_all, write};
+use std::io::{Error as IoError, ErrorKind, Result as IoResult};
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+use translatable_shared::{
+    Escape, Language, Lint, MissingArguments, SeekMode, Severity, TranslationOverlap,
+};
+
+/// Configuration of the translation macros, see [`TranslatableConfig::builder`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TranslatableConfig {
+    /// Configured values, as written in `translatable.toml`
+    table: Table,
+}
+
+impl TranslatableConfig {
+    /// Creates a builder of a configuration, where every value is the
+    /// default one until it's set
+    ///
+    /// # Example
+    /// ```no_run
+    /// // build.rs
+    /// use translatable::{Language, TranslatableConfig};
+    ///
+    /// TranslatableConfig::builder()
+    ///     .path(concat!(env!("CARGO_MANIFEST_DIR"), "/../../locales"))
+    ///     .languages([Language::EN, Language::ES])
+    ///     .alias("pt-br", Language::PT)
+    ///     .build()
+    ///     .emit()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> TranslatableConfigBuilder {
+        TranslatableConfigBuilder::default()
+    }
+
+    /// Gets the configuration as the content of a `translatable.toml` file
+    pub fn to_toml(&self) -> String {
+        // Tables of strings always serialize
+        toml::to_string(&self.table).unwrap_or_default()
+    }
+
+    /// Writes the configuration to a file, creating its directory if it
+    /// doesn't exist
+    pub fn write(&self, path: impl AsRef<Path>) -> IoResult<()> {
+        if let Some(directory) = path.as_ref().parent() {
+            create_dir_all(directory)?;
+        }
+
+        write(path, self.to_toml())
+    }
+
+    /// Writes the configuration to `$OUT_DIR/translatable.toml` and points
+    /// the macros of the crate to it, from a build script
+    ///
+    /// The file is forwarded with `cargo:rustc-env=TRANSLATABLE_CONFIG`, so
+    /// the `translatable.toml` of the workspace root is ignored, and the values
+    /// can still be overridden by the other environment variables.
+    ///
+    /// # Returns
+    /// - `Ok(PathBuf)` with the path of the written file
+    /// - `Err(IoError)` if `OUT_DIR` isn't set, as outside build scripts, or
+    ///   the file can't be written
+    pub fn emit(&self) -> IoResult<PathBuf> {
+        let out_dir = var("OUT_DIR").map_err(|_| {
+            IoError::new(ErrorKind::NotFound, "OUT_DIR is only set for build scripts")
+        })?;
+
+        let path = Path::new(&out_dir).join("translatable.toml");
+        self.write(&path)?;
+
+        println!("cargo:rustc-env=TRANSLATABLE_CONFIG={}", path.display());
+
+        Ok(path)
+    }
+}
+
+/// Builder of a [`TranslatableConfig`]
+///
+/// Every method sets the `translatable.toml` key of the same name, the
+/// values are validated by the macros like the ones of a file.
+#[derive(Clone, Debug, Default)]
+pub struct TranslatableConfigBuilder {
+    /// Values set so far
+    table: Table,
+}
+
+impl TranslatableConfigBuilder {
+    /// Sets a value of the configuration
+    fn value(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.table.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Sets an entry of a table of the configuration, such as `aliases`
+    fn entry(mut self, table: &str, key: &str, value: impl Into<Value>) -> Self {
+        if let Value::Table(table) =
+            self.table.entry(table).or_insert_with(|| Value::Table(Table::new()))
+        {
+            table.insert(key.to_string(), value.into());
+        }
+
+        self
+    }
+
+    /// Sets an enumerated value of the configuration, written in lowercase
+    fn keyword(self, key: &str, value: impl Into<&'static str>) -> Self {
+        self.value(key, value.into())
+    }
+
+    /// Sets a list of languages of the configuration
+    fn language_list(self, key: &str, languages: impl IntoIterator<Item = Language>) -> Self {
+        let codes = languages.into_iter().map(|language| Value::from(language.code()));
+
+        self.value(key, Value::Array(codes.collect()))
+    }
+
+    /// Sets the directory of the translation files, relative to the
+    /// workspace root unless absolute
+    pub fn path(self, path: impl AsRef<Path>) -> Self {
+        self.value("path", path.as_ref().to_string_lossy().to_string())
+    }
+
+    /// Sets the dot-separated path the translation files are mounted under
+    pub fn namespace(self, namespace: impl Into<String>) -> Self {
+        self.value("namespace", namespace.into())
+    }
+
+    /// Sets the file processing order
+    pub fn seek_mode(self, seek_mode: SeekMode) -> Self {
+        self.keyword("seek_mode", seek_mode)
+    }
+
+    /// Sets the strategy for overlapping translations
+    pub fn overlap(self, overlap: TranslationOverlap) -> Self {
+        self.keyword("overlap", overlap)
+    }
+
+    /// Sets the strategy for templates without an argument
+    pub fn missing_arguments(self, missing_arguments: MissingArguments) -> Self {
+        self.keyword("missing_arguments", missing_arguments)
+    }
+
+    /// Sets the severity of static resolutions for a missing language
+    pub fn on_missing_language(self, severity: Severity) -> Self {
+        self.keyword("on_missing_language", severity)
+    }
+
+    /// Sets the severity of unbalanced template braces
+    pub fn on_unbalanced_braces(self, severity: Severity) -> Self {
+        self.keyword("on_unbalanced_braces", severity)
+    }
+
+    /// Sets whether arguments are isolated for right-to-left languages
+    pub fn bidi_isolation(self, bidi_isolation: bool) -> Self {
+        self.value("bidi_isolation", bidi_isolation)
+    }
+
+    /// Sets the escaping of argument values
+    pub fn escape(self, escape: Escape) -> Self {
+        self.keyword("escape", escape)
+    }
+
+    /// Sets whether translations must be normalized and visible
+    pub fn strict_unicode(self, strict_unicode: bool) -> Self {
+        self.value("strict_unicode", strict_unicode)
+    }
+
+    /// Sets the language the other ones are translated from
+    pub fn source_language(self, language: Language) -> Self {
+        self.value("source_language", language.code())
+    }
+
+    /// Sets the severity of a lint
+    pub fn lint(self, lint: Lint, severity: Severity) -> Self {
+        self.entry("lints", lint.name(), <&str>::from(severity))
+    }
+
+    /// Maps a custom language identifier to a language
+    pub fn alias(self, alias: &str, language: Language) -> Self {
+        self.entry("aliases", alias, language.code())
+    }
+
+    /// Migrates a translation path to another one
+    pub fn migration(self, path: &str, new_path: &str) -> Self {
+        self.entry("migrations", path, new_path)
+    }
+
+    /// Sets the languages kept in the embedded translations
+    pub fn embed_languages(self, languages: impl IntoIterator<Item = Language>) -> Self {
+        self.language_list("embed_languages", languages)
+    }
+
+    /// Sets the languages the application can resolve
+    pub fn languages(self, languages: impl IntoIterator<Item = Language>) -> Self {
+        self.language_list("languages", languages)
+    }
+
+    /// Sets the oldest catalog version the translation files can declare
+    ///
+    /// Versions are TOML integers, the macros reject negative ones.
+    pub fn min_catalog_version(self, version: i64) -> Self {
+        self.value("min_catalog_version", version)
+    }
+
+    /// Sets the cargo features enabled for `_cfg` predicates
+    pub fn features<T: Into<String>>(self, features: impl IntoIterator<Item = T>) -> Self {
+        let features = features.into_iter().map(|feature| Value::String(feature.into()));
+
+        self.value("features", Value::Array(features.collect()))
+    }
+
+    /// Sets the target operating system of the translation variants
+    pub fn target_os(self, target_os: impl Into<String>) -> Self {
+        self.value("target_os", target_os.into())
+    }
+
+    /// Sets the directory of the persistent translation cache
+    pub fn cache_path(self, path: impl AsRef<Path>) -> Self {
+        self.value("cache_path", path.as_ref().to_string_lossy().to_string())
+    }
+
+    /// Builds the configuration
+    pub fn build(self) -> TranslatableConfig {
+        TranslatableConfig { table: self.table }
+    }
+}
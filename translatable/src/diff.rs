@@ -0,0 +1,208 @@
+//! Catalog differences between releases
+//!
+//! This module compares two translation catalogs, such as the translations
+//! directory of the last release and the current one, reporting the
+//! translations added, removed and changed per language.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+use translatable_shared::TranslationNodeCollection;
+
+use crate::Language;
+use crate::export::catalog_entries;
+use crate::loading::{LoadError, load_directory};
+
+/// Change of a single path and language pair between two catalogs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CatalogChange {
+    /// The path was translated to the language in the new catalog only
+    Added {
+        /// Dot-separated translation path
+        path: String,
+        /// Language the path was translated to
+        language: Language,
+        /// Translation in the new catalog
+        translation: String,
+    },
+
+    /// The path was translated to the language in the old catalog only
+    Removed {
+        /// Dot-separated translation path
+        path: String,
+        /// Language the path was translated to
+        language: Language,
+        /// Translation in the old catalog
+        translation: String,
+    },
+
+    /// The translation of the path to the language changed
+    Changed {
+        /// Dot-separated translation path
+        path: String,
+        /// Language the path is translated to
+        language: Language,
+        /// Translation in the old catalog
+        old: String,
+        /// Translation in the new catalog
+        new: String,
+    },
+}
+
+impl CatalogChange {
+    /// Gets the dot-separated path of the change
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            },
+        }
+    }
+
+    /// Gets the language of the change
+    pub fn language(&self) -> &Language {
+        match self {
+            Self::Added { language, .. }
+            | Self::Removed { language, .. }
+            | Self::Changed { language, .. } => language,
+        }
+    }
+}
+
+/// Differences between two translation catalogs
+///
+/// Paths are compared by the translation they are looked up to, the first
+/// file containing a path winning, so moving a path between files is not a
+/// change.
+///
+/// # Example
+/// ```no_run
+/// # use translatable::CatalogDiff;
+/// let diff = CatalogDiff::load("release/translations", "translations").unwrap();
+///
+/// for path in diff.removed_paths() {
+///     eprintln!("The path '{path}' was removed");
+/// }
+///
+/// print!("{diff}");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CatalogDiff {
+    /// Changes sorted by path and language code
+    changes: Vec<CatalogChange>,
+    /// Paths of the old catalog missing from the new one, sorted
+    removed_paths: Vec<String>,
+}
+
+impl CatalogDiff {
+    /// Compares two translation catalogs
+    pub fn new(old: &TranslationNodeCollection, new: &TranslationNodeCollection) -> Self {
+        let old = catalog_entries(old);
+        let new = catalog_entries(new);
+        let mut changes = Vec::new();
+
+        let removed_paths = old.keys().filter(|path| !new.contains_key(*path)).cloned().collect();
+
+        let mut paths = old.keys().chain(new.keys()).collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+
+        for path in paths {
+            let old = old.get(path).map(|entry| entry.translations);
+            let new = new.get(path).map(|entry| entry.translations);
+
+            let mut languages = old
+                .into_iter()
+                .chain(new)
+                .flat_map(|translations| translations.keys())
+                .collect::<Vec<_>>();
+            languages.sort_by_key(|language| language.code());
+            languages.dedup();
+
+            for language in languages {
+                let old = old.and_then(|translations| translations.get(language)).cloned();
+                let new = new.and_then(|translations| translations.get(language)).cloned();
+                let path = path.clone();
+                let language = language.clone();
+
+                changes.push(match (old, new) {
+                    (None, Some(translation)) => {
+                        CatalogChange::Added { path, language, translation }
+                    },
+                    (Some(translation), None) => {
+                        CatalogChange::Removed { path, language, translation }
+                    },
+                    (Some(old), Some(new)) if old != new => {
+                        CatalogChange::Changed { path, language, old, new }
+                    },
+                    _ => continue,
+                });
+            }
+        }
+
+        Self { changes, removed_paths }
+    }
+
+    /// Compares the translation files of two directories, such as the
+    /// translations directory of two git revisions checked out with
+    /// `git worktree add`
+    ///
+    /// Every TOML file is loaded, recursively, and validated the same way
+    /// the macros validate the translations directory.
+    ///
+    /// # Returns
+    /// - `Ok(CatalogDiff)` with the changes from `old` to `new`
+    /// - `Err(LoadError)` if a translation file is not valid
+    pub fn load(old: impl AsRef<Path>, new: impl AsRef<Path>) -> Result<Self, LoadError> {
+        Ok(Self::new(&load_directory(old.as_ref())?, &load_directory(new.as_ref())?))
+    }
+
+    /// Gets every change, sorted by path and language code
+    pub fn changes(&self) -> &[CatalogChange] {
+        &self.changes
+    }
+
+    /// Gets the changes of a single language
+    pub fn language<'a>(
+        &'a self,
+        language: &'a Language,
+    ) -> impl Iterator<Item = &'a CatalogChange> + 'a {
+        self.changes.iter().filter(move |change| change.language() == language)
+    }
+
+    /// Gets the paths removed from every language, the ones the new catalog
+    /// doesn't have at all, sorted
+    pub fn removed_paths(&self) -> &[String] {
+        &self.removed_paths
+    }
+
+    /// Checks whether the catalogs have the same translations
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Writes a line per change, prefixed with `+` if added, `-` if removed and
+/// `~` if changed
+impl Display for CatalogDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for change in &self.changes {
+            let code = change.language().code();
+            let path = change.path();
+
+            match change {
+                CatalogChange::Added { translation, .. } => {
+                    writeln!(f, "+ {code} {path}: {translation:?}")?
+                },
+                CatalogChange::Removed { translation, .. } => {
+                    writeln!(f, "- {code} {path}: {translation:?}")?
+                },
+                CatalogChange::Changed { old, new, .. } => {
+                    writeln!(f, "~ {code} {path}: {old:?} -> {new:?}")?
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! Dioxus bindings of the shared locale
+//!
+//! This module provides the [`Locale`] to Dioxus components through their
+//! context, as a signal the components reading it subscribe to, so switching
+//! the language re-renders them.
+
+use ::dioxus::hooks::{use_context, use_context_provider};
+use ::dioxus::signals::{ReadableExt, SyncSignal, WritableExt};
+use translatable_shared::Language;
+
+use crate::{Locale, Translator};
+
+/// Context provided by [`use_locale_provider`]
+#[derive(Clone)]
+struct LocaleContext {
+    /// Handle shared with the rest of the application
+    locale: Locale,
+    /// Current language, updated by the handle subscription
+    language: SyncSignal<Language>,
+}
+
+/// Provides a locale to the current component and its descendants
+///
+/// The locale is only created on the first render, its language follows
+/// [`Locale::set_language`] wherever it's called from, so a desktop or mobile
+/// app can switch it from a menu or a background task.
+///
+/// # Example
+/// ```ignore
+/// fn App() -> Element {
+///     use_locale_provider(|| Locale::new(Language::EN));
+///
+///     rsx! { Greeting {} }
+/// }
+/// ```
+pub fn use_locale_provider(init: impl FnOnce() -> Locale) -> Locale {
+    use_context_provider(|| {
+        let locale = init();
+        let language = SyncSignal::new_maybe_sync(locale.language());
+
+        // A dropped signal rejects the value instead of panicking
+        locale.subscribe(move |changed| {
+            let mut language = language;
+
+            if let Ok(mut current) = language.try_write() {
+                *current = changed.clone();
+            }
+        });
+
+        LocaleContext { locale, language }
+    })
+    .locale
+}
+
+/// Gets the locale provided by an ancestor, to switch the language
+///
+/// # Panics
+/// If no ancestor called [`use_locale_provider`].
+pub fn use_locale() -> Locale {
+    use_context::<LocaleContext>().locale
+}
+
+/// Gets the current language, re-rendering the component when it changes
+///
+/// Compile-time validated lookups take it, either with the typed accessors or
+/// with the `translation!` macro and a static path.
+///
+/// ```ignore
+/// let language = use_language();
+///
+/// rsx! { p { {common::greeting(language, "john")} } }
+/// ```
+///
+/// # Panics
+/// If no ancestor called [`use_locale_provider`].
+pub fn use_language() -> Language {
+    use_context::<LocaleContext>().language.cloned()
+}
+
+/// Gets a translator for the current language, for lookups of dynamic paths,
+/// re-rendering the component when it changes
+///
+/// # Panics
+/// If no ancestor called [`use_locale_provider`].
+pub fn use_translation() -> Translator<'static> {
+    Translator::new(use_language())
+}
@@ -0,0 +1,124 @@
+//! Duplicate string detection
+//!
+//! This module finds the paths of a catalog translated to the same string,
+//! so they can be consolidated into a single path and translated once.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use translatable_shared::TranslationNodeCollection;
+
+use crate::Language;
+use crate::export::catalog_entries;
+
+/// Paths translated to the same string in a language
+///
+/// Strings are the same if they are identical or only differ in case,
+/// whitespace and trailing punctuation, such as `Save` and `save.`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    /// Language the paths are translated to
+    language: Language,
+    /// Paths and their translations, sorted by path
+    translations: Vec<(String, String)>,
+}
+
+impl DuplicateGroup {
+    /// Gets the language the paths are translated to
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Gets the paths and their translations, sorted by path
+    pub fn translations(&self) -> &[(String, String)] {
+        &self.translations
+    }
+
+    /// Gets the paths, sorted
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.translations.iter().map(|(path, _)| path.as_str())
+    }
+
+    /// Gets the path suggested to keep, the first one
+    pub fn canonical(&self) -> &str {
+        &self.translations[0].0
+    }
+
+    /// Checks whether the translations are identical, not only similar
+    pub fn is_identical(&self) -> bool {
+        self.translations.iter().all(|(_, translation)| *translation == self.translations[0].1)
+    }
+}
+
+/// Writes the paths and a suggestion to consolidate them
+impl Display for DuplicateGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let kind = if self.is_identical() { "identical" } else { "similar" };
+        let paths = self.paths().collect::<Vec<_>>().join(", ");
+
+        write!(
+            f,
+            "{} {kind} translations in {}: {paths}, consider reusing '{}'",
+            self.translations.len(),
+            self.language.code(),
+            self.canonical()
+        )
+    }
+}
+
+/// Finds the paths translated to the same string, per language
+///
+/// Paths are compared by the translation they are looked up to, the first
+/// file containing a path winning.
+///
+/// # Returns
+/// The groups of two or more paths, sorted by language code and path
+///
+/// # Example
+/// ```
+/// use translatable::{Language, MockTranslations, find_duplicates};
+///
+/// let catalog = MockTranslations::new()
+///     .translation("checkout.save", Language::EN, "Save")
+///     .translation("profile.save", Language::EN, "save.")
+///     .build();
+///
+/// let duplicates = find_duplicates(&catalog);
+/// assert!(duplicates[0].canonical() == "checkout.save");
+/// ```
+pub fn find_duplicates(collection: &TranslationNodeCollection) -> Vec<DuplicateGroup> {
+    let mut groups = BTreeMap::<(&str, String), DuplicateGroup>::new();
+
+    for (path, entry) in catalog_entries(collection) {
+        for (language, translation) in entry.translations {
+            groups
+                .entry((language.code(), normalize(translation)))
+                .or_insert_with(|| DuplicateGroup {
+                    language: language.clone(),
+                    translations: Vec::new(),
+                })
+                .translations
+                .push((path.clone(), translation.clone()));
+        }
+    }
+
+    let mut groups =
+        groups.into_values().filter(|group| group.translations.len() > 1).collect::<Vec<_>>();
+
+    groups.sort_by(|first, second| {
+        (first.language.code(), first.canonical())
+            .cmp(&(second.language.code(), second.canonical()))
+    });
+
+    groups
+}
+
+/// Reduces a translation to the form similar translations share
+fn normalize(translation: &str) -> String {
+    translation
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(|character: char| character.is_ascii_punctuation() && character != '}')
+        .to_lowercase()
+}
@@ -0,0 +1,143 @@
+//! egui helpers resolving translations for the shared locale
+//!
+//! This module binds a [`Locale`] to an egui context and provides the
+//! [`Translate`] extension, whose `ui.t("path")` lookups are cached so
+//! immediate-mode interfaces don't resolve nor allocate their strings again
+//! on every frame.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use ::egui::{Context, Id, RichText, Ui};
+use translatable_shared::Language;
+
+use crate::{Locale, Translator};
+
+/// Texts resolved for a single language, keyed by dot-separated path
+#[derive(Clone, Default)]
+struct TranslationCache {
+    /// Language the texts were resolved for
+    language: Option<Language>,
+    /// Resolved texts, shared with the widgets showing them
+    texts: HashMap<String, Arc<RichText>>,
+}
+
+/// Binds a locale to an egui context, usually once at startup
+///
+/// Switching its language with [`Locale::set_language`] clears the cached
+/// texts on the next lookup.
+pub fn set_locale(ctx: &Context, locale: Locale) {
+    ctx.data_mut(|data| data.insert_temp(Id::NULL, locale));
+}
+
+/// Gets the locale bound to an egui context
+pub fn locale(ctx: &Context) -> Option<Locale> {
+    ctx.data(|data| data.get_temp(Id::NULL))
+}
+
+/// Clears the cached texts of an egui context, e.g. after replacing the
+/// overrides
+pub fn clear_translation_cache(ctx: &Context) {
+    ctx.data_mut(|data| data.remove::<TranslationCache>(Id::NULL));
+}
+
+/// Resolves a cached text for the locale bound to an egui context
+fn translate(ctx: &Context, path: &str) -> Arc<RichText> {
+    let Some(language) = locale(ctx).map(|locale| locale.language()) else {
+        return Arc::new(RichText::new(path));
+    };
+
+    let cached = ctx.data_mut(|data| {
+        let cache = data.get_temp_mut_or_default::<TranslationCache>(Id::NULL);
+
+        if cache.language.as_ref() != Some(&language) {
+            cache.language = Some(language.clone());
+            cache.texts.clear();
+        }
+
+        cache.texts.get(path).cloned()
+    });
+
+    if let Some(text) = cached {
+        return text;
+    }
+
+    // Resolved outside of the context lock, the missing translation hook may
+    // use the context
+    let translator = Translator::new(language.clone());
+    let text = Arc::new(RichText::new(translator.get(path).unwrap_or(Cow::Borrowed(path))));
+
+    ctx.data_mut(|data| {
+        let cache = data.get_temp_mut_or_default::<TranslationCache>(Id::NULL);
+
+        if cache.language.as_ref() == Some(&language) {
+            cache.texts.insert(path.to_string(), Arc::clone(&text));
+        }
+    });
+
+    text
+}
+
+/// Translation lookups for the locale bound with [`set_locale`]
+///
+/// Compile-time validated lookups take [`Translate::language`], either with
+/// the typed accessors or with the `translation!` macro and a static path.
+///
+/// # Example
+/// ```ignore
+/// use translatable::egui::Translate;
+///
+/// ui.heading(ui.t("menu.title"));
+/// ui.label(ui.t_with("common.greeting", &[("name", &name)]));
+/// ```
+pub trait Translate {
+    /// Resolves a dot-separated path, cached until the language changes
+    ///
+    /// The path itself is returned if the translation is not available or
+    /// no locale is bound, showing it instead of an empty widget.
+    fn t(&self, path: &str) -> Arc<RichText>;
+
+    /// Resolves a dot-separated path replacing its templates, not cached as
+    /// the arguments may change between frames
+    ///
+    /// The path itself is returned like with [`Translate::t`].
+    fn t_with(&self, path: &str, arguments: &[(&str, &dyn Display)]) -> String;
+
+    /// Gets the language of the bound locale
+    fn language(&self) -> Option<Language>;
+}
+
+impl Translate for Context {
+    fn t(&self, path: &str) -> Arc<RichText> {
+        translate(self, path)
+    }
+
+    fn t_with(&self, path: &str, arguments: &[(&str, &dyn Display)]) -> String {
+        match self.language() {
+            Some(language) => Translator::new(language)
+                .get_with(path, arguments)
+                .map_or_else(|_| path.to_string(), Cow::into_owned),
+            None => path.to_string(),
+        }
+    }
+
+    fn language(&self) -> Option<Language> {
+        locale(self).map(|locale| locale.language())
+    }
+}
+
+impl Translate for Ui {
+    fn t(&self, path: &str) -> Arc<RichText> {
+        self.ctx().t(path)
+    }
+
+    fn t_with(&self, path: &str, arguments: &[(&str, &dyn Display)]) -> String {
+        self.ctx().t_with(path, arguments)
+    }
+
+    fn language(&self) -> Option<Language> {
+        self.ctx().language()
+    }
+}
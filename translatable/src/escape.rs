@@ -0,0 +1,56 @@
+//! HTML escaping of argument values
+//!
+//! This module escapes the values replaced in translations destined for web
+//! pages, so user supplied values such as names can't inject markup, while
+//! the markup of the translations themselves is kept as it is.
+
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+/// Value written with the HTML special characters escaped
+///
+/// `&`, `<`, `>`, `"` and `'` are written as character references, the
+/// alternate flag is forwarded to the value.
+///
+/// # Example
+/// ```
+/// use translatable::HtmlEscaped;
+///
+/// let name = "<b>john</b>";
+/// assert_eq!(HtmlEscaped(name).to_string(), "&lt;b&gt;john&lt;/b&gt;");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HtmlEscaped<T>(pub T);
+
+impl<T: Display> Display for HtmlEscaped<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(HtmlWriter(f), "{:#}", self.0)
+        } else {
+            write!(HtmlWriter(f), "{}", self.0)
+        }
+    }
+}
+
+/// Writer escaping the HTML special characters of the text written
+struct HtmlWriter<'a, 'b>(&'a mut Formatter<'b>);
+
+impl Write for HtmlWriter<'_, '_> {
+    fn write_str(&mut self, text: &str) -> FmtResult {
+        let mut rest = text;
+
+        while let Some(index) = rest.find(['&', '<', '>', '"', '\'']) {
+            self.0.write_str(&rest[..index])?;
+            self.0.write_str(match rest.as_bytes()[index] {
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'"' => "&quot;",
+                _ => "&#x27;",
+            })?;
+
+            rest = &rest[index + 1..];
+        }
+
+        self.0.write_str(rest)
+    }
+}
@@ -0,0 +1,152 @@
+//! Android string resources
+//!
+//! Every path is written as a resource named after it, with its dots
+//! replaced by underscores, and templates become positional format
+//! arguments, so `common.greeting` is read with
+//! `getString(R.string.common_greeting, name)`.
+
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{Fragment, Language, TranslationNodeCollection, plural_variants};
+
+use super::{catalog_entries, catalog_languages, positional_arguments, rewrite_templates};
+
+/// Writes the `strings.xml` resources of a language
+///
+/// Templates are numbered by the alphabetical order of the arguments, the
+/// one the plurals are selected by is an integer (`%1$d`) and the rest
+/// strings (`%2$s`). Translations with a single plural block are written as
+/// `plurals` resources, with the text around the block in every quantity,
+/// exact value arms such as `=0` have no Android quantity and are left out.
+/// Other blocks are written as they are.
+///
+/// Paths without a translation in the language are left out, so Android
+/// falls back to the default resources.
+pub fn write_android_strings(
+    collection: &TranslationNodeCollection,
+    language: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(writer, "<resources>")?;
+
+    for (path, entry) in catalog_entries(collection) {
+        let Some(translation) = entry.translations.get(language) else {
+            continue;
+        };
+
+        let arguments = positional_arguments(entry.translations);
+        let name = resource_name(&path);
+
+        match plural_variants(translation) {
+            Some((plural, arms)) => {
+                writeln!(writer, r#"    <plurals name="{name}">"#)?;
+
+                for (quantity, fragments) in arms.iter().filter(|(arm, _)| !arm.starts_with('=')) {
+                    let text = resource_text(fragments, &arguments, Some(plural));
+                    writeln!(writer, r#"        <item quantity="{quantity}">{text}</item>"#)?;
+                }
+
+                writeln!(writer, "    </plurals>")?;
+            },
+            None => {
+                let text = resource_text(&[Fragment::Text(translation)], &arguments, None);
+                writeln!(writer, r#"    <string name="{name}">{text}</string>"#)?;
+            },
+        }
+    }
+
+    writeln!(writer, "</resources>")
+}
+
+/// Writes the string resources of a collection into an Android `res`
+/// directory
+///
+/// The resources of the default language are written to
+/// `values/strings.xml`, the ones of every other language in use to
+/// `values-<code>/strings.xml`, such as `values-es/strings.xml`. The
+/// directories are created if they don't exist.
+pub fn export_android(
+    collection: &TranslationNodeCollection,
+    default: &Language,
+    directory: impl AsRef<Path>,
+) -> IoResult<()> {
+    for language in catalog_languages(&catalog_entries(collection)) {
+        let values = match language == *default {
+            true => directory.as_ref().join("values"),
+            false => directory.as_ref().join(format!("values-{}", language.code())),
+        };
+
+        create_dir_all(&values)?;
+
+        let mut resources = BufWriter::new(File::create(values.join("strings.xml"))?);
+        write_android_strings(collection, &language, &mut resources)?;
+        resources.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Gets the resource name of a path, with every character not valid in a
+/// resource name replaced by an underscore
+fn resource_name(path: &str) -> String {
+    path.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' }).collect()
+}
+
+/// Writes fragments as resource text, with their templates as positional
+/// format arguments
+fn resource_text(fragments: &[Fragment<'_>], arguments: &[String], plural: Option<&str>) -> String {
+    let formatted = !arguments.is_empty();
+    let mut text = String::new();
+
+    let specifier = |argument: &str, _: Option<&str>, text: &mut String| match arguments
+        .iter()
+        .position(|name| name == argument)
+    {
+        Some(index) => {
+            let kind = if Some(argument) == plural { 'd' } else { 's' };
+            text.push_str(&format!("%{}${kind}", index + 1));
+        },
+        None => escape(&format!("{{{argument}}}"), formatted, text),
+    };
+
+    for fragment in fragments {
+        match fragment {
+            Fragment::Text(fragment) => rewrite_templates(
+                fragment,
+                &mut text,
+                |literal, text| escape(literal, formatted, text),
+                &specifier,
+            ),
+            Fragment::Argument(argument) => specifier(argument, None, &mut text),
+        }
+    }
+
+    // Resources starting with these characters are references otherwise
+    if text.starts_with(['@', '?']) {
+        text.insert(0, '\\');
+    }
+
+    text
+}
+
+/// Escapes literal text for a string resource, `%` included if the
+/// resource has format arguments
+fn escape(literal: &str, formatted: bool, text: &mut String) {
+    for character in literal.chars() {
+        match character {
+            '\\' => text.push_str("\\\\"),
+            '\'' => text.push_str("\\'"),
+            '"' => text.push_str("\\\""),
+            '\n' => text.push_str("\\n"),
+            '\t' => text.push_str("\\t"),
+            '&' => text.push_str("&amp;"),
+            '<' => text.push_str("&lt;"),
+            '>' => text.push_str("&gt;"),
+            '%' if formatted => text.push_str("%%"),
+            character => text.push(character),
+        }
+    }
+}
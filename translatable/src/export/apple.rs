@@ -0,0 +1,210 @@
+//! Apple `.strings` and `.stringsdict` files
+//!
+//! Every path is written as the key of its string, and templates become
+//! positional format arguments, so `common.greeting` is read with
+//! `String(format: NSLocalizedString("common.greeting", comment: ""), name)`.
+
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{Fragment, Language, TranslationNodeCollection, plural_variants};
+
+use super::{catalog_entries, catalog_languages, positional_arguments, rewrite_templates};
+
+/// Writes the `Localizable.strings` file of a language
+///
+/// Templates are numbered by the alphabetical order of the arguments, as
+/// objects (`%1$@`). Translations with a single plural block are left out,
+/// they are written to the `.stringsdict` file by
+/// [`write_apple_stringsdict`]. Other blocks are written as they are.
+///
+/// Paths without a translation in the language are left out, so the
+/// development language is used instead.
+pub fn write_apple_strings(
+    collection: &TranslationNodeCollection,
+    language: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    for (path, entry) in catalog_entries(collection) {
+        let Some(translation) = entry.translations.get(language) else {
+            continue;
+        };
+
+        if plural_variants(translation).is_some() {
+            continue;
+        }
+
+        let arguments = positional_arguments(entry.translations);
+        let formatted = !arguments.is_empty();
+        let text =
+            format_text(&[Fragment::Text(translation)], &arguments, None, |literal, text| {
+                escape_strings(literal, formatted, text)
+            });
+
+        let mut key = String::with_capacity(path.len());
+        escape_strings(&path, false, &mut key);
+
+        writeln!(writer, "\"{key}\" = \"{text}\";")?;
+    }
+
+    Ok(())
+}
+
+/// Writes the `Localizable.stringsdict` file of a language, with the
+/// translations that have a single plural block
+///
+/// The argument the plurals are selected by is an integer (`%1$d`), the
+/// text around the block is written in every plural category, and `=0`
+/// arms are the `zero` category, other exact value arms are left out.
+pub fn write_apple_stringsdict(
+    collection: &TranslationNodeCollection,
+    language: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">"#
+    )?;
+    writeln!(writer, r#"<plist version="1.0">"#)?;
+    writeln!(writer, "<dict>")?;
+
+    for (path, entry) in catalog_entries(collection) {
+        let Some((plural, arms)) =
+            entry.translations.get(language).and_then(|translation| plural_variants(translation))
+        else {
+            continue;
+        };
+
+        let arguments = positional_arguments(entry.translations);
+        let position = arguments.iter().position(|argument| argument == plural).unwrap_or(0);
+
+        writeln!(writer, "    <key>{}</key>", escape_plist(&path))?;
+        writeln!(writer, "    <dict>")?;
+        writeln!(writer, "        <key>NSStringLocalizedFormatKey</key>")?;
+        writeln!(writer, "        <string>%{}$#@{}@</string>", position + 1, escape_plist(plural))?;
+        writeln!(writer, "        <key>{}</key>", escape_plist(plural))?;
+        writeln!(writer, "        <dict>")?;
+        writeln!(writer, "            <key>NSStringFormatSpecTypeKey</key>")?;
+        writeln!(writer, "            <string>NSStringPluralRuleType</string>")?;
+        writeln!(writer, "            <key>NSStringFormatValueTypeKey</key>")?;
+        writeln!(writer, "            <string>d</string>")?;
+
+        for (selector, fragments) in &arms {
+            let category = match *selector {
+                "=0" => "zero",
+                selector if selector.starts_with('=') => continue,
+                selector => selector,
+            };
+
+            let text = format_text(fragments, &arguments, Some(plural), |literal, text| {
+                escape_plist_into(&literal.replace('%', "%%"), text)
+            });
+
+            writeln!(writer, "            <key>{category}</key>")?;
+            writeln!(writer, "            <string>{text}</string>")?;
+        }
+
+        writeln!(writer, "        </dict>")?;
+        writeln!(writer, "    </dict>")?;
+    }
+
+    writeln!(writer, "</dict>")?;
+    writeln!(writer, "</plist>")
+}
+
+/// Writes the `.strings` and `.stringsdict` files of a collection into a
+/// directory
+///
+/// The files of every language in use are written to
+/// `<code>.lproj/Localizable.strings` and
+/// `<code>.lproj/Localizable.stringsdict`, such as `es.lproj`. The
+/// directories are created if they don't exist.
+pub fn export_apple(
+    collection: &TranslationNodeCollection,
+    directory: impl AsRef<Path>,
+) -> IoResult<()> {
+    for language in catalog_languages(&catalog_entries(collection)) {
+        let bundle = directory.as_ref().join(format!("{}.lproj", language.code()));
+        create_dir_all(&bundle)?;
+
+        let mut strings = BufWriter::new(File::create(bundle.join("Localizable.strings"))?);
+        write_apple_strings(collection, &language, &mut strings)?;
+        strings.flush()?;
+
+        let mut stringsdict = BufWriter::new(File::create(bundle.join("Localizable.stringsdict"))?);
+        write_apple_stringsdict(collection, &language, &mut stringsdict)?;
+        stringsdict.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Writes fragments with their templates as positional format arguments
+///
+/// # Arguments
+/// * `plural` - Argument the plurals are selected by, an integer
+/// * `escape` - Writes literal text, escaped for the file
+fn format_text(
+    fragments: &[Fragment<'_>],
+    arguments: &[String],
+    plural: Option<&str>,
+    escape: impl Fn(&str, &mut String),
+) -> String {
+    let mut text = String::new();
+
+    let specifier = |argument: &str, _: Option<&str>, text: &mut String| match arguments
+        .iter()
+        .position(|name| name == argument)
+    {
+        Some(index) => {
+            let kind = if Some(argument) == plural { 'd' } else { '@' };
+            text.push_str(&format!("%{}${kind}", index + 1));
+        },
+        None => escape(&format!("{{{argument}}}"), text),
+    };
+
+    for fragment in fragments {
+        match fragment {
+            Fragment::Text(fragment) => rewrite_templates(fragment, &mut text, &escape, &specifier),
+            Fragment::Argument(argument) => specifier(argument, None, &mut text),
+        }
+    }
+
+    text
+}
+
+/// Escapes literal text for a `.strings` file, `%` included if the string
+/// has format arguments
+fn escape_strings(literal: &str, formatted: bool, text: &mut String) {
+    for character in literal.chars() {
+        match character {
+            '\\' => text.push_str("\\\\"),
+            '"' => text.push_str("\\\""),
+            '\n' => text.push_str("\\n"),
+            '\t' => text.push_str("\\t"),
+            '%' if formatted => text.push_str("%%"),
+            character => text.push(character),
+        }
+    }
+}
+
+/// Escapes text for a property list string
+fn escape_plist(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    escape_plist_into(value, &mut escaped);
+    escaped
+}
+
+/// Escapes text for a property list string into a buffer
+fn escape_plist_into(value: &str, text: &mut String) {
+    for character in value.chars() {
+        match character {
+            '&' => text.push_str("&amp;"),
+            '<' => text.push_str("&lt;"),
+            '>' => text.push_str("&gt;"),
+            character => text.push(character),
+        }
+    }
+}
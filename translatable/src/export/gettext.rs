@@ -0,0 +1,152 @@
+//! gettext POT and PO catalogs
+//!
+//! Every path is written as the `msgctxt` of its entry, so paths sharing
+//! the same source text stay apart and translated PO files can be mapped
+//! back to the translation files.
+
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{Language, TranslationNodeCollection};
+
+use super::{CatalogEntry, catalog_entries, catalog_languages};
+
+/// Writes the POT template of a collection, with the translations in the
+/// source language as `msgid`
+///
+/// Paths without a source translation use the path itself as `msgid`.
+pub fn write_pot(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    write_header(&mut writer, None)?;
+
+    for (path, entry) in catalog_entries(collection) {
+        write_entry(&mut writer, &path, &entry, source, None)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the PO catalog of a language, with the translations in the source
+/// language as `msgid` and the ones in the language as `msgstr`
+///
+/// Untranslated paths have an empty `msgstr`, which gettext tooling lists
+/// as pending.
+pub fn write_po(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    language: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    write_header(&mut writer, Some(language))?;
+
+    for (path, entry) in catalog_entries(collection) {
+        write_entry(&mut writer, &path, &entry, source, Some(language))?;
+    }
+
+    Ok(())
+}
+
+/// Writes the POT template and PO catalogs of a collection into a directory
+///
+/// The template is written as `messages.pot`, the catalog of every language
+/// in use as `<code>.po`, such as `es.po`. The directory is created if it
+/// doesn't exist.
+///
+/// # Example
+/// ```ignore
+/// translatable::export::export_gettext(&translatable::embed_translations!(), &Language::EN, "po")?;
+/// ```
+pub fn export_gettext(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    directory: impl AsRef<Path>,
+) -> IoResult<()> {
+    let directory = directory.as_ref();
+    create_dir_all(directory)?;
+
+    let mut template = BufWriter::new(File::create(directory.join("messages.pot"))?);
+    write_pot(collection, source, &mut template)?;
+    template.flush()?;
+
+    for language in catalog_languages(&catalog_entries(collection)) {
+        let path = directory.join(format!("{}.po", language.code()));
+        let mut catalog = BufWriter::new(File::create(path)?);
+
+        write_po(collection, source, &language, &mut catalog)?;
+        catalog.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Writes the header entry, with the language of PO catalogs
+fn write_header(writer: &mut impl Write, language: Option<&Language>) -> IoResult<()> {
+    writeln!(writer, "msgid \"\"")?;
+    writeln!(writer, "msgstr \"\"")?;
+
+    if let Some(language) = language {
+        writeln!(writer, "\"Language: {}\\n\"", language.code())?;
+    }
+
+    writeln!(writer, "\"MIME-Version: 1.0\\n\"")?;
+    writeln!(writer, "\"Content-Type: text/plain; charset=UTF-8\\n\"")?;
+    writeln!(writer, "\"Content-Transfer-Encoding: 8bit\\n\"")
+}
+
+/// Writes the entry of a path, with an empty `msgstr` for templates
+fn write_entry(
+    writer: &mut impl Write,
+    path: &str,
+    entry: &CatalogEntry<'_>,
+    source: &Language,
+    language: Option<&Language>,
+) -> IoResult<()> {
+    let msgid = entry.translations.get(source).map_or(path, String::as_str);
+    let msgstr = language.and_then(|language| entry.translations.get(language));
+
+    writeln!(writer)?;
+    writeln!(writer, "#: {}", entry.file)?;
+    write_string(writer, "msgctxt", path)?;
+    write_string(writer, "msgid", msgid)?;
+    write_string(writer, "msgstr", msgstr.map_or("", String::as_str))
+}
+
+/// Writes a keyword with its quoted string, split in a line per line break
+/// like gettext tooling does
+fn write_string(writer: &mut impl Write, keyword: &str, value: &str) -> IoResult<()> {
+    let lines = value.split_inclusive('\n').collect::<Vec<_>>();
+
+    if lines.len() <= 1 {
+        return writeln!(writer, "{keyword} \"{}\"", escape(value));
+    }
+
+    writeln!(writer, "{keyword} \"\"")?;
+
+    for line in lines {
+        writeln!(writer, "\"{}\"", escape(line))?;
+    }
+
+    Ok(())
+}
+
+/// Escapes a string for a PO quoted string
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,199 @@
+//! i18next JSON resources
+//!
+//! Every path is written nested like in the translation files, and
+//! templates become i18next interpolations, so `common.greeting` is read
+//! with `t("common.greeting", { name })`.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{
+    Fragment, Language, TranslationNodeCollection, choice_arguments, filter_names, plural_variants,
+    select_fragments,
+};
+
+use super::{catalog_entries, catalog_languages, rewrite_templates};
+
+/// Filters written as the i18next formatter of the same name
+const FORMATTERS: [&str; 2] = ["list", "number"];
+
+/// Nested object of a resource file
+#[derive(Default)]
+struct Resources(BTreeMap<String, Resource>);
+
+/// Value of a resource file
+enum Resource {
+    /// Translated text
+    Text(String),
+    /// Nested object
+    Object(Resources),
+}
+
+impl Resources {
+    /// Inserts a text at a path, keeping the first one inserted
+    fn insert(&mut self, path: &[&str], text: String) {
+        let Some((key, rest)) = path.split_first() else { return };
+
+        if rest.is_empty() {
+            self.0.entry(key.to_string()).or_insert(Resource::Text(text));
+            return;
+        }
+
+        let nested =
+            self.0.entry(key.to_string()).or_insert_with(|| Resource::Object(Resources::default()));
+
+        if let Resource::Object(nested) = nested {
+            nested.insert(rest, text);
+        }
+    }
+
+    /// Writes the object as indented JSON
+    fn write(&self, writer: &mut impl Write, indent: usize) -> IoResult<()> {
+        writeln!(writer, "{{")?;
+
+        for (index, (key, resource)) in self.0.iter().enumerate() {
+            write!(writer, "{:width$}\"{}\": ", "", escape(key), width = indent + 2)?;
+
+            match resource {
+                Resource::Text(text) => write!(writer, "\"{}\"", escape(text))?,
+                Resource::Object(nested) => nested.write(writer, indent + 2)?,
+            }
+
+            writeln!(writer, "{}", if index + 1 < self.0.len() { "," } else { "" })?;
+        }
+
+        write!(writer, "{:indent$}}}", "")
+    }
+}
+
+/// Writes the i18next JSON resources of a language
+///
+/// Templates are written as `{{name}}` interpolations, the `list` and
+/// `number` filters as the i18next formatters of the same name and other
+/// filters are left out. Translations with a single plural block are
+/// written as a key per plural suffix, such as `items_one` and
+/// `items_other`, `=0` arms being `_zero`, i18next selects them by the
+/// `count` option. Other blocks are written with their `other` arms.
+///
+/// Paths without a translation in the language are left out, so i18next
+/// falls back to its fallback language.
+pub fn write_i18next(
+    collection: &TranslationNodeCollection,
+    language: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    let mut resources = Resources::default();
+
+    for (path, entry) in catalog_entries(collection) {
+        let Some(translation) = entry.translations.get(language) else {
+            continue;
+        };
+
+        let segments = path.split('.').collect::<Vec<_>>();
+        let (last, parents) = segments.split_last().expect("paths are never empty");
+
+        match plural_variants(translation) {
+            Some((_, arms)) => {
+                for (selector, fragments) in &arms {
+                    let suffix = match *selector {
+                        "=0" => "zero",
+                        selector if selector.starts_with('=') => continue,
+                        selector => selector,
+                    };
+
+                    let key = format!("{last}_{suffix}");
+                    resources.insert(&[parents, &[key.as_str()]].concat(), interpolate(fragments));
+                }
+            },
+            None => {
+                // Blocks without an argument value select their `other` arm
+                let arguments = choice_arguments(translation);
+                let values = arguments
+                    .iter()
+                    .map(|argument| (argument.as_str(), &"" as &dyn Display))
+                    .collect::<Vec<_>>();
+
+                let fragments = select_fragments(None, translation, &values);
+                resources.insert(&segments, interpolate(&fragments));
+            },
+        }
+    }
+
+    resources.write(&mut writer, 0)?;
+    writeln!(writer)
+}
+
+/// Writes the i18next JSON resources of a collection into a directory
+///
+/// The resources of every language in use are written to
+/// `<code>/translation.json`, the default namespace in the layout the
+/// i18next backends load, such as `es/translation.json`. The directories
+/// are created if they don't exist.
+pub fn export_i18next(
+    collection: &TranslationNodeCollection,
+    directory: impl AsRef<Path>,
+) -> IoResult<()> {
+    for language in catalog_languages(&catalog_entries(collection)) {
+        let locale = directory.as_ref().join(language.code());
+        create_dir_all(&locale)?;
+
+        let mut resources = BufWriter::new(File::create(locale.join("translation.json"))?);
+        write_i18next(collection, &language, &mut resources)?;
+        resources.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Writes fragments with their templates as i18next interpolations
+fn interpolate(fragments: &[Fragment<'_>]) -> String {
+    let mut text = String::new();
+
+    for fragment in fragments {
+        match fragment {
+            Fragment::Text(fragment) => rewrite_templates(
+                fragment,
+                &mut text,
+                |literal, text| text.push_str(literal),
+                |name, filters, text| {
+                    let formatter = filters
+                        .into_iter()
+                        .flat_map(filter_names)
+                        .find(|filter| FORMATTERS.contains(filter));
+
+                    match formatter {
+                        Some(formatter) => text.push_str(&format!("{{{{{name}, {formatter}}}}}")),
+                        None => text.push_str(&format!("{{{{{name}}}}}")),
+                    }
+                },
+            ),
+            Fragment::Argument(argument) => text.push_str(&format!("{{{{{argument}}}}}")),
+        }
+    }
+
+    text
+}
+
+/// Escapes a string for a JSON string
+pub(super) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            },
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
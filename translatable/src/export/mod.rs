@@ -0,0 +1,140 @@
+//! Catalog exports to the formats of translation tooling
+//!
+//! This module writes a [`TranslationNodeCollection`] out in the formats
+//! standard translator tooling reads, keeping the dot-separated paths so the
+//! translated catalogs can be mapped back to the translation files.
+
+use std::collections::{BTreeMap, HashMap};
+
+use translatable_shared::{
+    Language, TranslationNode, TranslationNodeCollection, split_filters, templates,
+};
+
+pub use self::android::{export_android, write_android_strings};
+pub use self::apple::{export_apple, write_apple_strings, write_apple_stringsdict};
+pub use self::gettext::{export_gettext, write_po, write_pot};
+pub use self::i18next::{export_i18next, write_i18next};
+pub use self::schema::{export_schema, write_schema};
+pub use self::xliff::{export_xliff, write_xliff};
+
+mod android;
+mod apple;
+mod gettext;
+mod i18next;
+mod schema;
+mod xliff;
+
+/// Translation object of a path, with the file it was found in
+pub(crate) struct CatalogEntry<'a> {
+    /// Translation file the path was found in
+    pub(crate) file: &'a str,
+    /// Translations of the path per language
+    pub(crate) translations: &'a HashMap<Language, String>,
+}
+
+/// Collects the translation objects of a collection by path
+///
+/// The first file containing a path wins, like with lookups.
+pub(crate) fn catalog_entries(
+    collection: &TranslationNodeCollection,
+) -> BTreeMap<String, CatalogEntry<'_>> {
+    let mut entries = BTreeMap::new();
+
+    for association in collection.iter() {
+        collect_entries(
+            association.translation_table(),
+            "",
+            association.original_path(),
+            &mut entries,
+        );
+    }
+
+    entries
+}
+
+/// Adds the translation objects of a node missing from the entries
+fn collect_entries<'a>(
+    node: &'a TranslationNode,
+    path: &str,
+    file: &'a str,
+    entries: &mut BTreeMap<String, CatalogEntry<'a>>,
+) {
+    match node {
+        TranslationNode::Object(nesting) => {
+            for (key, node) in nesting {
+                let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                collect_entries(node, &path, file, entries);
+            }
+        },
+        TranslationNode::Translation(translations) => {
+            entries.entry(path.to_string()).or_insert(CatalogEntry { file, translations });
+        },
+    }
+}
+
+/// Collects the languages found in any entry, sorted by code
+fn catalog_languages(entries: &BTreeMap<String, CatalogEntry<'_>>) -> Vec<Language> {
+    let mut languages = BTreeMap::new();
+
+    for entry in entries.values() {
+        for language in entry.translations.keys() {
+            languages.entry(language.code()).or_insert_with(|| language.clone());
+        }
+    }
+
+    languages.into_values().collect()
+}
+
+/// Orders the arguments of a translation object, so every language refers
+/// to them by the same position
+///
+/// The arguments are the templates of every language, sorted alphabetically
+/// like the parameters of the typed accessors.
+fn positional_arguments(translations: &HashMap<Language, String>) -> Vec<String> {
+    let mut arguments =
+        translations.values().flat_map(|translation| templates(translation)).collect::<Vec<_>>();
+
+    arguments.sort();
+    arguments.dedup();
+    arguments
+}
+
+/// Rewrites the templates of a translation text, escaped templates
+/// (`{{name}}`) being literal text
+///
+/// # Arguments
+/// * `literal` - Writes literal text, escaped for the format
+/// * `template` - Writes the template of an argument, with its filters
+fn rewrite_templates(
+    mut rest: &str,
+    output: &mut String,
+    mut literal: impl FnMut(&str, &mut String),
+    mut template: impl FnMut(&str, Option<&str>, &mut String),
+) {
+    while let Some(start) = rest.find('{') {
+        literal(&rest[..start], output);
+        rest = &rest[start..];
+
+        if let Some(escaped) = rest.strip_prefix("{{") {
+            match escaped.find("}}") {
+                Some(end) => {
+                    literal(&format!("{{{}}}", &escaped[..end]), output);
+                    rest = &escaped[end + 2..];
+                },
+                None => {
+                    literal("{{", output);
+                    rest = escaped;
+                },
+            }
+
+            continue;
+        }
+
+        let Some(end) = rest.find('}') else { break };
+        let (name, filters) = split_filters(&rest[1..end]);
+        template(name, filters, output);
+        rest = &rest[end + 1..];
+    }
+
+    literal(rest, output);
+}
@@ -0,0 +1,403 @@
+//! JSON Schema of the translation files
+//!
+//! The schema describes the catalog structure, so editors such as VS Code
+//! with Taplo complete the paths, languages and reserved keys and validate
+//! the placeholders while translators edit the TOML files.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{
+    CFG_KEY, DEPRECATED_KEY, Language, PRIORITY_KEY, TRIM_KEY, TranslationNodeCollection,
+    VALUES_KEY, VERSION_KEY, templates,
+};
+
+use super::i18next::escape;
+use super::{CatalogEntry, catalog_entries, catalog_languages};
+
+/// JSON value of the schema
+enum Json {
+    /// Object, with its properties in insertion order
+    Object(Vec<(String, Json)>),
+    /// Array
+    Array(Vec<Json>),
+    /// String
+    String(String),
+    /// Boolean
+    Bool(bool),
+    /// Integer
+    Integer(usize),
+}
+
+impl Json {
+    /// Creates an object from its properties
+    fn object<const N: usize>(properties: [(&str, Json); N]) -> Self {
+        Self::Object(properties.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    /// Creates a string
+    fn string(value: impl Into<String>) -> Self {
+        Self::String(value.into())
+    }
+
+    /// Creates a reference to a definition of the schema
+    fn reference(definition: &str) -> Self {
+        Self::object([("$ref", Self::string(format!("#/definitions/{definition}")))])
+    }
+
+    /// Writes the value as indented JSON
+    fn write(&self, writer: &mut impl Write, indent: usize) -> IoResult<()> {
+        match self {
+            Self::Object(properties) if properties.is_empty() => write!(writer, "{{}}"),
+            Self::Object(properties) => {
+                writeln!(writer, "{{")?;
+
+                for (index, (key, value)) in properties.iter().enumerate() {
+                    write!(writer, "{:width$}\"{}\": ", "", escape(key), width = indent + 2)?;
+                    value.write(writer, indent + 2)?;
+                    writeln!(writer, "{}", if index + 1 < properties.len() { "," } else { "" })?;
+                }
+
+                write!(writer, "{:indent$}}}", "")
+            },
+            Self::Array(values) => {
+                write!(writer, "[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    value.write(writer, indent)?;
+                    write!(writer, "{}", if index + 1 < values.len() { ", " } else { "" })?;
+                }
+
+                write!(writer, "]")
+            },
+            Self::String(value) => write!(writer, "\"{}\"", escape(value)),
+            Self::Bool(value) => write!(writer, "{value}"),
+            Self::Integer(value) => write!(writer, "{value}"),
+        }
+    }
+}
+
+/// Nesting of the catalog paths, as written in the translation files
+#[derive(Default)]
+struct Nesting<'a> {
+    /// Nested nestings by key
+    nestings: BTreeMap<&'a str, Nesting<'a>>,
+    /// Translation objects by key
+    objects: BTreeMap<&'a str, &'a CatalogEntry<'a>>,
+}
+
+impl<'a> Nesting<'a> {
+    /// Inserts a translation object at a path
+    fn insert(&mut self, path: &[&'a str], entry: &'a CatalogEntry<'a>) {
+        match path {
+            [] => {},
+            [key] => {
+                self.objects.insert(key, entry);
+            },
+            [key, rest @ ..] => self.nestings.entry(key).or_default().insert(rest, entry),
+        }
+    }
+
+    /// Describes the known keys of the nesting, along with the metadata
+    /// keys any table can hold
+    fn properties(&self, languages: &[Language]) -> Vec<(String, Json)> {
+        let mut properties = table_metadata();
+
+        for (key, nesting) in &self.nestings {
+            let nested = nesting.properties(languages);
+
+            properties.push((
+                key.to_string(),
+                Json::object([
+                    ("type", Json::string("object")),
+                    ("properties", Json::Object(nested)),
+                    ("additionalProperties", Json::reference("node")),
+                ]),
+            ));
+        }
+
+        for (key, entry) in &self.objects {
+            properties.push((key.to_string(), translation_object(entry, languages)));
+        }
+
+        properties
+    }
+}
+
+/// Describes the metadata keys any table of a translation file can hold
+fn table_metadata() -> Vec<(String, Json)> {
+    [CFG_KEY, TRIM_KEY, DEPRECATED_KEY]
+        .into_iter()
+        .map(|key| (key.to_string(), Json::reference(&key[1..])))
+        .collect()
+}
+
+/// Describes the language keys of a table, with the placeholders their
+/// translations must keep
+fn language_properties(languages: &[Language], placeholders: &[String]) -> Vec<(String, Json)> {
+    let listed = placeholders.iter().map(|name| format!("{{{name}}}")).collect::<Vec<_>>();
+
+    languages
+        .iter()
+        .map(|language| {
+            let description = match listed.is_empty() {
+                true => format!("{language:#} ({language:?})"),
+                false => format!("{language:#} ({language:?}), with {}", listed.join(", ")),
+            };
+
+            let mut property = vec![
+                ("$ref".to_string(), Json::string("#/definitions/translation")),
+                ("description".to_string(), Json::string(description)),
+            ];
+
+            if !placeholders.is_empty() {
+                let patterns = placeholders
+                    .iter()
+                    .map(|name| Json::object([("pattern", Json::string(placeholder(name)))]))
+                    .collect();
+
+                property.push(("allOf".to_string(), Json::Array(patterns)));
+            }
+
+            (language.code().to_string(), Json::Object(property))
+        })
+        .collect()
+}
+
+/// Describes a translation object of the catalog
+///
+/// Every language must keep the placeholders all the languages of the
+/// catalog have, and other keys than the languages and metadata are
+/// reported, such as misspelled language codes.
+fn translation_object(entry: &CatalogEntry<'_>, languages: &[Language]) -> Json {
+    let mut written = entry.translations.iter().collect::<Vec<_>>();
+    written.sort_by_key(|(language, _)| language.code());
+
+    // Placeholders every language has
+    let placeholders = written
+        .iter()
+        .map(|(_, translation)| templates(translation).into_iter().collect::<BTreeSet<_>>())
+        .reduce(|common, placeholders| common.intersection(&placeholders).cloned().collect())
+        .unwrap_or_default()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut properties = table_metadata();
+    properties.extend(language_properties(languages, &placeholders));
+
+    let init_keys = written.iter().map(|(language, _)| Json::string(language.code())).collect();
+
+    Json::object([
+        ("type", Json::string("object")),
+        ("description", Json::string(format!("Translation object defined in {}", entry.file))),
+        ("properties", Json::Object(properties)),
+        ("additionalProperties", Json::Bool(false)),
+        ("minProperties", Json::Integer(1)),
+        ("x-taplo", Json::object([("initKeys", Json::Array(init_keys))])),
+    ])
+}
+
+/// Builds the pattern of a placeholder, with or without filter pipes, or
+/// of the argument of a plural or select block
+fn placeholder(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|character| match character {
+            character if character.is_alphanumeric() || character == '_' => character.to_string(),
+            character => format!("\\{character}"),
+        })
+        .collect::<String>();
+
+    format!("\\{{\\s*{name}\\s*[|,}}]")
+}
+
+/// Writes the JSON Schema of the translation files of a collection
+///
+/// The known paths are described with the languages they can be written
+/// in and the placeholders every language must keep, the ones of the first
+/// file containing a path winning like with lookups. Unknown tables are
+/// validated as translation objects or nestings. Reserved keys, such as
+/// `_priority` and `_cfg`, are completed in every table they are valid in.
+///
+/// # Arguments
+/// * `collection` - Catalog to describe, without a namespace
+/// * `languages` - Languages the translations can be written in besides the
+///   ones of the catalog, such as the configured `embed_languages`
+/// * `writer` - Target of the schema
+pub fn write_schema(
+    collection: &TranslationNodeCollection,
+    languages: &[Language],
+    mut writer: impl Write,
+) -> IoResult<()> {
+    let entries = catalog_entries(collection);
+
+    let mut all_languages = catalog_languages(&entries);
+    all_languages.extend(languages.iter().cloned());
+    all_languages.sort_by_key(|language| language.code());
+    all_languages.dedup();
+
+    // Typed values hold other values than translations
+    let mut root = Nesting::default();
+    for (path, entry) in &entries {
+        let segments = path.split('.').collect::<Vec<_>>();
+
+        if segments.first() != Some(&VALUES_KEY) {
+            root.insert(&segments, entry);
+        }
+    }
+
+    let mut properties = vec![
+        (PRIORITY_KEY.to_string(), Json::reference("priority")),
+        (VERSION_KEY.to_string(), Json::reference("version")),
+        (VALUES_KEY.to_string(), Json::reference("values")),
+    ];
+    properties.extend(root.properties(&all_languages));
+
+    let mut generic = table_metadata();
+    generic.extend(language_properties(&all_languages, &[]));
+
+    let schema = Json::object([
+        ("$schema", Json::string("http://json-schema.org/draft-07/schema#")),
+        ("title", Json::string("Translation file")),
+        (
+            "description",
+            Json::string("Translation file of a translatable catalog, nesting translation objects"),
+        ),
+        ("type", Json::string("object")),
+        ("properties", Json::Object(properties)),
+        ("additionalProperties", Json::reference("node")),
+        (
+            "definitions",
+            Json::object([
+                (
+                    "node",
+                    Json::object([
+                        ("description", Json::string("Translation object or nesting")),
+                        ("type", Json::string("object")),
+                        ("properties", Json::Object(generic)),
+                        ("additionalProperties", Json::reference("node")),
+                        ("minProperties", Json::Integer(1)),
+                    ]),
+                ),
+                (
+                    "translation",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string(
+                                "Translation, or random entries picked at every resolution",
+                            ),
+                        ),
+                        (
+                            "anyOf",
+                            Json::Array(vec![
+                                Json::object([("type", Json::string("string"))]),
+                                Json::object([
+                                    ("type", Json::string("array")),
+                                    ("items", Json::object([("type", Json::string("string"))])),
+                                    ("minItems", Json::Integer(1)),
+                                ]),
+                            ]),
+                        ),
+                    ]),
+                ),
+                (
+                    "priority",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string(
+                                "Lookup priority of the file, files with a higher one are looked \
+                                 up first",
+                            ),
+                        ),
+                        ("type", Json::string("integer")),
+                    ]),
+                ),
+                (
+                    "version",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string(
+                                "Catalog version of the file, checked against the \
+                                 `min_catalog_version` of the consumers",
+                            ),
+                        ),
+                        ("type", Json::string("integer")),
+                        ("minimum", Json::Integer(0)),
+                    ]),
+                ),
+                (
+                    "cfg",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string(
+                                "Feature predicate the table is kept under, such as `feature = \
+                                 \"premium\"`",
+                            ),
+                        ),
+                        ("type", Json::string("string")),
+                    ]),
+                ),
+                (
+                    "trim",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string(
+                                "Whether the translations of the table are dedented and trimmed",
+                            ),
+                        ),
+                        ("type", Json::string("boolean")),
+                    ]),
+                ),
+                (
+                    "deprecated",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string("Note shown at the call sites of the deprecated paths"),
+                        ),
+                        ("type", Json::string("string")),
+                    ]),
+                ),
+                (
+                    "values",
+                    Json::object([
+                        (
+                            "description",
+                            Json::string("Typed values per language, such as numbers or arrays"),
+                        ),
+                        ("type", Json::string("object")),
+                    ]),
+                ),
+            ]),
+        ),
+    ]);
+
+    schema.write(&mut writer, 0)?;
+    writeln!(writer)
+}
+
+/// Writes the JSON Schema of the translation files of a collection to a
+/// file, creating its directory if it doesn't exist
+///
+/// Taplo applies it to the translation files with a `#:schema` directive
+/// at their top or a `[[rule]]` of its configuration.
+pub fn export_schema(
+    collection: &TranslationNodeCollection,
+    languages: &[Language],
+    path: impl AsRef<Path>,
+) -> IoResult<()> {
+    if let Some(directory) = path.as_ref().parent() {
+        create_dir_all(directory)?;
+    }
+
+    let mut schema = BufWriter::new(File::create(path)?);
+    write_schema(collection, languages, &mut schema)?;
+    schema.flush()
+}
@@ -0,0 +1,113 @@
+//! XLIFF 2.0 documents
+//!
+//! Every path is written as the `id` of its unit, grouped in a `file`
+//! element per translation file, so the returned documents can be dropped
+//! into the translation directory with the `xliff` feature.
+
+use std::collections::BTreeMap;
+use std::fs::{File, create_dir_all};
+use std::io::{BufWriter, Result as IoResult, Write};
+use std::path::Path;
+
+use translatable_shared::{Language, TranslationNodeCollection};
+
+use super::{CatalogEntry, catalog_entries, catalog_languages};
+
+/// Writes the XLIFF 2.0 document translating a collection from a source
+/// language to a target one
+///
+/// Units without a source translation use the path itself as source, units
+/// without a target translation have no `target` and the `initial` state.
+pub fn write_xliff(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    target: &Language,
+    mut writer: impl Write,
+) -> IoResult<()> {
+    let mut files = BTreeMap::<&str, Vec<(String, CatalogEntry<'_>)>>::new();
+
+    for (path, entry) in catalog_entries(collection) {
+        files.entry(entry.file).or_default().push((path, entry));
+    }
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="{}" trgLang="{}">"#,
+        source.code(),
+        target.code()
+    )?;
+
+    for (index, (file, entries)) in files.iter().enumerate() {
+        writeln!(writer, r#"  <file id="f{}" original="{}">"#, index + 1, escape(file))?;
+
+        for (path, entry) in entries {
+            let source_text = entry.translations.get(source).map_or(path.as_str(), String::as_str);
+            let target_text = entry.translations.get(target);
+
+            writeln!(writer, r#"    <unit id="{}">"#, escape(path))?;
+            writeln!(
+                writer,
+                r#"      <segment state="{}">"#,
+                if target_text.is_some() { "translated" } else { "initial" }
+            )?;
+            writeln!(writer, "        <source>{}</source>", escape(source_text))?;
+
+            if let Some(target_text) = target_text {
+                writeln!(writer, "        <target>{}</target>", escape(target_text))?;
+            }
+
+            writeln!(writer, "      </segment>")?;
+            writeln!(writer, "    </unit>")?;
+        }
+
+        writeln!(writer, "  </file>")?;
+    }
+
+    writeln!(writer, "</xliff>")
+}
+
+/// Writes the XLIFF 2.0 documents of a collection into a directory
+///
+/// A document is written for every language in use but the source one, as
+/// `<code>.xlf`, such as `es.xlf`. The directory is created if it doesn't
+/// exist.
+pub fn export_xliff(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    directory: impl AsRef<Path>,
+) -> IoResult<()> {
+    let directory = directory.as_ref();
+    create_dir_all(directory)?;
+
+    for language in catalog_languages(&catalog_entries(collection)) {
+        if language == *source {
+            continue;
+        }
+
+        let path = directory.join(format!("{}.xlf", language.code()));
+        let mut document = BufWriter::new(File::create(path)?);
+
+        write_xliff(collection, source, &language, &mut document)?;
+        document.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Escapes a string for XML text and attribute values
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,320 @@
+//! Translation path extraction from sources
+//!
+//! This module scans Rust sources for the translation macro invocations with
+//! static paths, so the paths missing from the translation files can be added
+//! as skeleton entries from a build script before the macros report them.
+
+use std::collections::BTreeMap;
+use std::fs::{OpenOptions, create_dir_all, read_dir, read_to_string};
+use std::io::{BufWriter, Error as IoError, Write};
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Delimiter, LexError, Spacing, TokenStream, TokenTree};
+use thiserror::Error;
+use toml::de::Error as TomlError;
+use toml::{Table, Value};
+use translatable_shared::Language;
+
+/// Translation macros taking static paths, `t` being the usual alias
+const MACROS: [&str; 6] = [
+    "translation",
+    "try_translation",
+    "translation_or_key",
+    "translation_write",
+    "translations",
+    "t",
+];
+
+/// Attribute prefixing the paths inside an item
+const PREFIX_ATTRIBUTE: &str = "translation_prefix";
+
+/// Error type for path extraction failures
+///
+/// Every variant holds the path of the file the error was found in.
+#[derive(Error, Debug)]
+pub enum ExtractError {
+    /// A source or translation file couldn't be read or written
+    #[error("Couldn't access {1}: {0:#}")]
+    Io(IoError, String),
+
+    /// A source file isn't valid Rust tokens
+    #[error("Couldn't tokenize the source file {1}: {0}")]
+    ParseSource(LexError, String),
+
+    /// A translation file isn't valid TOML
+    #[error("Toml parse error in {path}: {message}", path = .1, message = .0.message())]
+    ParseToml(TomlError, String),
+}
+
+/// Extracts the static translation paths of a source file
+///
+/// Every `static` path of the translation macros is extracted, `t` included,
+/// with the prefixes of the `translation_prefix` attributes around it. Paths
+/// with a runtime segment and dynamic paths can't be known from the sources
+/// and are left out.
+///
+/// # Arguments
+/// * `content` - Rust source code
+/// * `source` - Path the source code was read from, for errors
+///
+/// # Returns
+/// The dot-separated paths in the order they are found
+pub fn extract_source(content: &str, source: &str) -> Result<Vec<String>, ExtractError> {
+    let tokens = content
+        .parse::<TokenStream>()
+        .map_err(|err| ExtractError::ParseSource(err, source.to_string()))?;
+
+    let mut paths = Vec::new();
+    scan_tokens(tokens, &[], &mut paths);
+
+    Ok(paths)
+}
+
+/// Extracts the static translation paths of every `.rs` file in a directory
+///
+/// The directory is walked recursively, see [`extract_source`].
+///
+/// # Returns
+/// The dot-separated paths, with the first source file each was found in
+pub fn extract_paths(
+    directory: impl AsRef<Path>,
+) -> Result<BTreeMap<String, PathBuf>, ExtractError> {
+    let mut paths = BTreeMap::new();
+
+    for file in files_with_extension(directory.as_ref(), "rs")? {
+        let content = read_to_string(&file)
+            .map_err(|err| ExtractError::Io(err, file.display().to_string()))?;
+
+        for path in extract_source(&content, &file.display().to_string())? {
+            paths.entry(path).or_insert_with(|| file.clone());
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Adds skeleton entries for the paths used in the sources and missing from
+/// the translation files
+///
+/// Every missing path is appended to the skeleton file as an empty
+/// translation per language, marked with a `TODO` comment and the source file
+/// it was found in. The skeleton file is created if it doesn't exist, and left
+/// untouched if no path is missing, so it can be placed in the translations
+/// directory and be filled in over time.
+///
+/// # Arguments
+/// * `sources` - Directory with the Rust sources, such as `src`
+/// * `translations` - Directory with the TOML translation files
+/// * `skeleton` - TOML file the skeleton entries are appended to
+/// * `languages` - Languages of the skeleton entries
+///
+/// # Returns
+/// The paths added to the skeleton file
+///
+/// # Example
+/// ```no_run
+/// // build.rs
+/// use translatable::Language;
+///
+/// println!("cargo:rerun-if-changed=src");
+///
+/// let added = translatable::extract::update_skeleton(
+///     "src",
+///     "translations",
+///     "translations/untranslated.toml",
+///     &[Language::EN, Language::ES],
+/// )
+/// .unwrap();
+///
+/// for path in added {
+///     println!("cargo:warning=The translation path '{path}' was added untranslated");
+/// }
+/// ```
+pub fn update_skeleton(
+    sources: impl AsRef<Path>,
+    translations: impl AsRef<Path>,
+    skeleton: impl AsRef<Path>,
+    languages: &[Language],
+) -> Result<Vec<String>, ExtractError> {
+    let skeleton = skeleton.as_ref();
+    let io_error = |err| ExtractError::Io(err, skeleton.display().to_string());
+
+    let mut files = files_with_extension(translations.as_ref(), "toml")?;
+    if skeleton.is_file() {
+        files.push(skeleton.to_path_buf());
+    }
+
+    let mut tables = Vec::new();
+    for file in files {
+        let content = read_to_string(&file)
+            .map_err(|err| ExtractError::Io(err, file.display().to_string()))?;
+
+        tables.push(
+            content
+                .parse::<Table>()
+                .map_err(|err| ExtractError::ParseToml(err, file.display().to_string()))?,
+        );
+    }
+
+    let missing = extract_paths(sources)?
+        .into_iter()
+        .filter(|(path, _)| !tables.iter().any(|table| contains_path(table, path)))
+        .collect::<Vec<_>>();
+
+    if missing.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if let Some(parent) = skeleton.parent() {
+        create_dir_all(parent).map_err(io_error)?;
+    }
+
+    let file = OpenOptions::new().create(true).append(true).open(skeleton).map_err(io_error)?;
+    let mut writer = BufWriter::new(file);
+
+    for (path, source) in &missing {
+        writeln!(writer, "\n[{path}]").map_err(io_error)?;
+        writeln!(writer, "# Found in {}", source.display()).map_err(io_error)?;
+
+        for language in languages {
+            writeln!(writer, "{} = \"\" # TODO", language.code()).map_err(io_error)?;
+        }
+    }
+
+    writer.flush().map_err(io_error)?;
+
+    Ok(missing.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Collects the static translation paths of a token stream
+///
+/// Macro invocations are looked for at every nesting level, as they are
+/// usually found inside other macros, and the body of an item annotated with
+/// `translation_prefix` is scanned with its prefix.
+fn scan_tokens(tokens: TokenStream, prefix: &[String], paths: &mut Vec<String>) {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut item_prefix = None;
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Ident(name) if MACROS.contains(&name.to_string().as_str()) => {
+                if let [TokenTree::Punct(bang), TokenTree::Group(arguments), ..] =
+                    &tokens[index + 1..]
+                    && bang.as_char() == '!'
+                {
+                    static_paths(arguments.stream(), prefix, paths);
+                }
+            },
+            TokenTree::Punct(pound) if pound.as_char() == '#' => {
+                if let Some(TokenTree::Group(attribute)) = tokens.get(index + 1)
+                    && let Some(nested) = prefix_attribute(attribute.stream())
+                {
+                    item_prefix = Some(nested);
+                }
+            },
+            // Items without a body, such as `mod name;`, end here
+            TokenTree::Punct(semicolon) if semicolon.as_char() == ';' => item_prefix = None,
+            TokenTree::Group(group) => match item_prefix.take() {
+                Some(nested) if group.delimiter() == Delimiter::Brace => {
+                    let prefix = [prefix, &nested].concat();
+                    scan_tokens(group.stream(), &prefix, paths);
+                },
+                other => {
+                    item_prefix = other;
+                    scan_tokens(group.stream(), prefix, paths);
+                },
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Collects the `static` paths of the arguments of a translation macro
+///
+/// Only the top level of the arguments is looked at, like the macros do.
+fn static_paths(arguments: TokenStream, prefix: &[String], paths: &mut Vec<String>) {
+    let mut tokens = arguments.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        if !matches!(&token, TokenTree::Ident(ident) if ident == "static") {
+            continue;
+        }
+
+        let mut segments = prefix.to_vec();
+        let mut runtime = false;
+
+        loop {
+            match tokens.next() {
+                Some(TokenTree::Ident(segment)) => segments.push(segment.to_string()),
+                Some(TokenTree::Group(_)) => runtime = true,
+                _ => break,
+            }
+
+            match tokens.peek() {
+                Some(TokenTree::Punct(colon))
+                    if colon.as_char() == ':' && colon.spacing() == Spacing::Joint =>
+                {
+                    tokens.next();
+                    tokens.next();
+                },
+                _ => break,
+            }
+        }
+
+        if !runtime && segments.len() > prefix.len() {
+            paths.push(segments.join("."));
+        }
+    }
+}
+
+/// Gets the prefix segments of a `translation_prefix` attribute
+fn prefix_attribute(attribute: TokenStream) -> Option<Vec<String>> {
+    let tokens = attribute.into_iter().collect::<Vec<_>>();
+
+    let [.., TokenTree::Ident(name), TokenTree::Group(arguments)] = tokens.as_slice() else {
+        return None;
+    };
+
+    if name != PREFIX_ATTRIBUTE {
+        return None;
+    }
+
+    let literal = arguments.stream().to_string();
+    let prefix = literal.strip_prefix('"')?.strip_suffix('"')?;
+
+    Some(prefix.split('.').map(str::to_string).collect())
+}
+
+/// Checks whether a translation file table has a nesting at a path
+fn contains_path(table: &Table, path: &str) -> bool {
+    let mut nesting = table;
+
+    for segment in path.split('.') {
+        match nesting.get(segment) {
+            Some(Value::Table(nested)) => nesting = nested,
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// Collects the files with an extension in a directory, recursively and
+/// sorted by path
+fn files_with_extension(directory: &Path, extension: &str) -> Result<Vec<PathBuf>, ExtractError> {
+    let io_error = |err| ExtractError::Io(err, directory.display().to_string());
+    let mut files = Vec::new();
+
+    for entry in read_dir(directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+
+        if path.is_dir() {
+            files.extend(files_with_extension(&path, extension)?);
+        } else if path.extension().is_some_and(|found| found == extension) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
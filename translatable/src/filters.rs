@@ -0,0 +1,150 @@
+//! Template filters applied to argument values
+//!
+//! This module resolves the filter pipes of templates such as `{name|upper}`,
+//! either built-in or registered by the application at runtime, so
+//! presentation tweaks live in the translation files instead of code.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+use translatable_shared::{Language, filter_names, split_filters};
+
+use crate::lists::{LIST_SEPARATOR, format_list};
+
+/// Filter transforming an argument value, may depend on the language
+pub type TemplateFilter = Box<dyn Fn(&str, Option<&Language>) -> String + Send + Sync>;
+
+/// Filters registered with [`register_filter`]
+static FILTERS: RwLock<BTreeMap<String, TemplateFilter>> = RwLock::new(BTreeMap::new());
+
+/// Registers a filter usable in templates as `{name|filter}`
+///
+/// Registered filters take precedence over the built-in `upper`, `lower`,
+/// `capitalize`, `list` and `list_no_oxford` filters, and `number` with the
+/// `num-format` feature, registering a name again replaces its filter. The
+/// items of a [`List`](crate::List) argument are separated by `'\u{1F}'`
+/// until a `list` filter joins them. The language is `None` if the translation
+/// is formatted without one, such
+/// as with [`Formatted::new`](crate::Formatted::new).
+///
+/// # Example
+/// ```
+/// translatable::register_filter("shout", |value, _| format!("{}!", value.to_uppercase()));
+/// ```
+pub fn register_filter(
+    name: impl Into<String>,
+    filter: impl Fn(&str, Option<&Language>) -> String + Send + Sync + 'static,
+) {
+    FILTERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.into(), Box::new(filter));
+}
+
+/// Applies a single filter, unknown filters leave the value as is
+fn apply_filter(filter: &str, value: String, language: Option<&Language>) -> String {
+    if let Some(filter) =
+        FILTERS.read().unwrap_or_else(|poisoned| poisoned.into_inner()).get(filter)
+    {
+        return filter(&value, language);
+    }
+
+    match filter {
+        "upper" => value.to_uppercase(),
+        "lower" => value.to_lowercase(),
+        "list" | "list_no_oxford" => format_list(
+            language.unwrap_or(&Language::EN),
+            value.split(LIST_SEPARATOR),
+            filter == "list",
+        ),
+        #[cfg(feature = "num-format")]
+        "number" => match language {
+            Some(language) => crate::numbers::localize(language, &value).unwrap_or(value),
+            None => value,
+        },
+        "capitalize" => {
+            let mut characters = value.chars();
+
+            match characters.next() {
+                Some(first) => {
+                    first.to_uppercase().chain(characters.flat_map(char::to_lowercase)).collect()
+                },
+                None => value,
+            }
+        },
+        _ => value,
+    }
+}
+
+/// Applies some filter pipes to a value, in order
+///
+/// # Arguments
+/// * `filters` - Filter pipes, such as `"lower|capitalize"`
+/// * `value` - Value written with its alternate `Display` output
+/// * `language` - Language the translation is formatted for
+pub fn apply_filters(filters: &str, value: &dyn Display, language: Option<&Language>) -> String {
+    // The fill character doesn't change the output without a width, but
+    // lets a `List` keep its items apart for the `list` filter
+    filter_names(filters)
+        .fold(format!("{value:\u{1F}<#}"), |value, filter| apply_filter(filter, value, language))
+        .replace(LIST_SEPARATOR, ", ")
+}
+
+/// Replaces the templates with filters of a translation
+///
+/// Templates without filters are left for the regular replacements, the
+/// translation is only copied if a template with filters has an argument.
+pub fn format_filters<'a>(
+    language: &Language,
+    translation: Cow<'a, str>,
+    arguments: &[(&str, &dyn Display)],
+) -> Cow<'a, str> {
+    let mut formatted = String::new();
+    let mut rest = translation.as_ref();
+    let mut replaced = false;
+
+    while let Some(start) = rest.find('{') {
+        formatted.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Escaped templates are kept as they are
+        if rest.starts_with("{{") {
+            let end = rest[2..].find("}}").map_or(2, |end| end + 4);
+            formatted.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some(end) = rest.find('}') else { break };
+        let template = &rest[1..end];
+
+        match split_filters(template) {
+            (name, Some(filters)) if !template.contains('{') => {
+                match arguments.iter().find(|(key, _)| *key == name) {
+                    Some((_, value)) => {
+                        formatted.push_str(&apply_filters(filters, *value, Some(language)));
+                        replaced = true;
+                    },
+                    None => formatted.push_str(&rest[..=end]),
+                }
+
+                rest = &rest[end + 1..];
+            },
+
+            // Anything else is rescanned after the brace
+            _ => {
+                formatted.push('{');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    if !replaced {
+        return translation;
+    }
+
+    formatted.push_str(rest);
+    Cow::Owned(formatted)
+}
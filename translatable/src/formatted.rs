@@ -0,0 +1,147 @@
+//! Streaming template formatting
+//!
+//! This module provides the [`Formatted`] wrapper, which writes a translation
+//! with its templates replaced straight into a formatter, without building an
+//! intermediate `String`.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use translatable_shared::{Fragment, Language, select_fragments, split_filters};
+
+use crate::bidi::{FSI, PDI, isolates};
+use crate::filters::apply_filters;
+
+/// Translation with its template arguments, replaced while formatting
+///
+/// Templates with an argument are replaced by its alternate `Display` output
+/// and escaped templates (`{{name}}`) with an argument are written as
+/// `{name}`, the same as the translation macro. Templates without an argument
+/// are written as they are. Filter pipes such as `{name|upper}` are applied
+/// to the values.
+///
+/// Plural and select blocks are selected by their arguments too, with the
+/// plural rules of the language set with [`Formatted::with_language`], or
+/// only by exact values and `other` arms otherwise.
+///
+/// Values are wrapped in bidi isolation characters if the language is
+/// right-to-left and [`Formatted::with_bidi_isolation`] is enabled.
+///
+/// # Example
+/// ```
+/// use std::fmt::Write;
+///
+/// use translatable::Formatted;
+///
+/// let mut output = String::new();
+/// write!(output, "{}", Formatted::new("Hello {name}!", &[("name", &"john")])).unwrap();
+///
+/// assert_eq!(output, "Hello john!");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Formatted<'a> {
+    /// Translation containing the templates
+    translation: &'a str,
+    /// Template names and their values
+    arguments: &'a [(&'a str, &'a dyn Display)],
+    /// Language of the plural rules
+    language: Option<&'a Language>,
+    /// Whether values are isolated for right-to-left languages
+    bidi_isolation: bool,
+}
+
+impl<'a> Formatted<'a> {
+    /// Binds a translation to its template arguments
+    pub fn new(translation: &'a str, arguments: &'a [(&'a str, &'a dyn Display)]) -> Self {
+        Self {
+            translation,
+            arguments,
+            language: None,
+            bidi_isolation: false,
+        }
+    }
+
+    /// Sets the language whose plural rules select the plural arms
+    pub fn with_language(mut self, language: &'a Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets whether values are wrapped in bidi isolation characters when the
+    /// language set with [`Formatted::with_language`] is right-to-left
+    pub fn with_bidi_isolation(mut self, bidi_isolation: bool) -> Self {
+        self.bidi_isolation = bidi_isolation;
+        self
+    }
+
+    /// Finds the value of a template argument
+    fn argument(&self, key: &str) -> Option<&'a dyn Display> {
+        self.arguments.iter().find(|(name, _)| *name == key).map(|(_, value)| *value)
+    }
+}
+
+impl Formatted<'_> {
+    /// Writes a value, isolated if the language is right-to-left
+    fn write_value(&self, f: &mut Formatter<'_>, value: &dyn Display) -> FmtResult {
+        if self.bidi_isolation && self.language.is_some_and(isolates) {
+            write!(f, "{FSI}{value}{PDI}")
+        } else {
+            write!(f, "{value}")
+        }
+    }
+
+    /// Writes a text fragment replacing its templates
+    fn write_text(&self, f: &mut Formatter<'_>, mut rest: &str) -> FmtResult {
+        while let Some(start) = rest.find('{') {
+            f.write_str(&rest[..start])?;
+            rest = &rest[start..];
+
+            if let Some(escaped) = rest.strip_prefix("{{") {
+                // Escaped templates are only unescaped if they have an argument
+                match escaped.find("}}") {
+                    Some(end) if self.argument(&escaped[..end]).is_some() => {
+                        write!(f, "{{{}}}", &escaped[..end])?;
+                        rest = &escaped[end + 2..];
+                    },
+                    _ => {
+                        f.write_str("{{")?;
+                        rest = escaped;
+                    },
+                }
+
+                continue;
+            }
+
+            let Some(end) = rest.find('}') else { break };
+            let (name, filters) = split_filters(&rest[1..end]);
+
+            match (self.argument(name), filters) {
+                (Some(value), Some(filters)) => {
+                    self.write_value(f, &apply_filters(filters, value, self.language))?
+                },
+                (Some(value), None) => self.write_value(f, &format_args!("{value:#}"))?,
+                (None, _) => f.write_str(&rest[..=end])?,
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        f.write_str(rest)
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for fragment in select_fragments(self.language, self.translation, self.arguments) {
+            match fragment {
+                Fragment::Text(text) => self.write_text(f, text)?,
+                Fragment::Argument(argument) => {
+                    if let Some(value) = self.argument(argument) {
+                        write!(f, "{value:#}")?;
+                    }
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! Process-wide hook for failed runtime lookups
+//!
+//! This module lets applications observe every failed dynamic translation,
+//! such as to log it or count it in metrics, without wrapping each call site.
+
+use std::sync::OnceLock;
+
+use crate::{Error, instrument};
+
+/// Callback invoked with every failed runtime lookup
+pub type MissingTranslationHook = Box<dyn Fn(&Error) + Send + Sync>;
+
+/// Process-wide hook set with [`set_missing_translation_hook`]
+static MISSING_TRANSLATION_HOOK: OnceLock<MissingTranslationHook> = OnceLock::new();
+
+/// Sets the process-wide hook invoked whenever a runtime lookup fails
+///
+/// The hook receives the error, which carries the requested language, the
+/// path and a stable [`code`](Error::code). It's invoked by the translation
+/// macros, the typed accessors and [`Translator`](crate::Translator), even if
+/// the failure is replaced by a `default` value.
+///
+/// # Returns
+/// - `Ok(())` if the hook was set
+/// - `Err(hook)` if a hook was already set
+pub fn set_missing_translation_hook(
+    hook: impl Fn(&Error) + Send + Sync + 'static,
+) -> Result<(), MissingTranslationHook> {
+    MISSING_TRANSLATION_HOOK.set(Box::new(hook))
+}
+
+/// Invokes the hook with a failed lookup error, returning it back
+///
+/// The failure is also recorded as a `tracing` event.
+pub fn missing_translation(error: Error) -> Error {
+    instrument::missing(&error);
+
+    #[cfg(feature = "test-support")]
+    if let Some(hook) = crate::test_support::missing_translation_hook_override() {
+        hook(&error);
+        return error;
+    }
+
+    if let Some(hook) = MISSING_TRANSLATION_HOOK.get() {
+        hook(&error);
+    }
+
+    error
+}
@@ -0,0 +1,40 @@
+//! `tracing` instrumentation for runtime lookups
+//!
+//! These functions are called by the generated code and the [`Translator`],
+//! they emit events only with the `tracing` feature, otherwise they are
+//! no-ops.
+//!
+//! [`Translator`]: crate::Translator
+
+use crate::Error;
+
+/// Records a runtime lookup at the `TRACE` level
+#[inline]
+pub fn lookup(language: &str, path: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::trace!(language, path, "translation lookup");
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (language, path);
+}
+
+/// Records a failed lookup replaced by its `default` value at the `DEBUG`
+/// level
+#[inline]
+pub fn fallback(error: &Error) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(code = error.code(), %error, "translation fallback used");
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = error;
+}
+
+/// Records a failed lookup at the `WARN` level
+#[inline]
+pub fn missing(error: &Error) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(code = error.code(), %error, "translation missing");
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = error;
+}
@@ -0,0 +1,86 @@
+//! Leptos bindings of the shared locale
+//!
+//! This module provides the [`Locale`] to Leptos components through their
+//! context, as a signal the views reading it track, so switching the language
+//! re-renders them.
+
+use ::leptos::prelude::{Get, RwSignal, Set, Signal, expect_context, provide_context};
+use translatable_shared::Language;
+
+use crate::{Locale, Translator};
+
+/// Context provided by [`provide_locale`]
+#[derive(Clone)]
+struct LocaleContext {
+    /// Handle shared with the rest of the application
+    locale: Locale,
+    /// Current language, updated by the handle subscription
+    language: RwSignal<Language>,
+}
+
+/// Provides a locale to the current component and its descendants
+///
+/// The returned signal follows [`Locale::set_language`], wherever it's
+/// called from, until the component is disposed.
+///
+/// # Example
+/// ```ignore
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_locale(Locale::new(Language::EN));
+///
+///     view! { <Greeting /> }
+/// }
+/// ```
+pub fn provide_locale(locale: Locale) -> Signal<Language> {
+    let language = RwSignal::new(locale.language());
+
+    // A disposed signal rejects the value instead of panicking
+    locale.subscribe(move |changed| {
+        let _ = language.try_set(changed.clone());
+    });
+
+    provide_context(LocaleContext { locale, language });
+    language.into()
+}
+
+/// Context provided by an ancestor, panics without a [`provide_locale`]
+fn locale_context() -> LocaleContext {
+    expect_context::<LocaleContext>()
+}
+
+/// Gets the locale provided by an ancestor, to switch the language
+///
+/// # Panics
+/// If no ancestor called [`provide_locale`].
+pub fn use_locale() -> Locale {
+    locale_context().locale
+}
+
+/// Gets the current language as a signal
+///
+/// Compile-time validated lookups read it in a reactive closure, either with
+/// the typed accessors or with the `translation!` macro and a static path.
+///
+/// ```ignore
+/// let language = use_language();
+///
+/// view! { <p>{move || common::greeting(language.get(), "john")}</p> }
+/// ```
+///
+/// # Panics
+/// If no ancestor called [`provide_locale`].
+pub fn use_language() -> Signal<Language> {
+    locale_context().language.into()
+}
+
+/// Gets a translator for the current language as a signal, for lookups of
+/// dynamic paths
+///
+/// # Panics
+/// If no ancestor called [`provide_locale`].
+pub fn use_translation() -> Signal<Translator<'static>> {
+    let language = locale_context().language;
+
+    Signal::derive(move || Translator::new(language.get()))
+}
@@ -1,27 +1,221 @@
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub use browser::{browser_language, browser_languages};
+pub use config::{TranslatableConfig, TranslatableConfigBuilder};
+pub use diff::{CatalogChange, CatalogDiff};
+pub use duplicates::{DuplicateGroup, find_duplicates};
+pub use escape::HtmlEscaped;
+pub use filters::{TemplateFilter, register_filter};
+pub use formatted::Formatted;
+pub use hook::{MissingTranslationHook, set_missing_translation_hook};
+pub use lists::{List, format_list};
+pub use loading::LoadError;
+pub use locale::Locale;
+pub use localized::error_catalog;
+#[cfg(feature = "libretranslate")]
+pub use machine::LibreTranslateProvider;
+pub use machine::{FillReport, MACHINE_TRANSLATED, MtProvider, fill_missing};
+pub use mock::MockTranslations;
+pub use negotiation::negotiate_language;
+#[cfg(feature = "num-format")]
+pub use numbers::format_number;
+#[cfg(feature = "tokio")]
+pub use overrides::load_overrides_async;
+pub use overrides::{clear_overrides, load_overrides, refresh_overrides, set_overrides};
+#[cfg(feature = "http")]
+pub use providers::HttpProvider;
+#[cfg(feature = "sqlx")]
+pub use providers::SqlxProvider;
+pub use providers::{AsyncTranslationProvider, TranslationProvider};
+pub use random::set_random_seed;
+pub use slugs::Slugs;
+pub use stats::{LanguageStats, TranslationStats};
 use thiserror::Error;
-/// Re-export the procedural macro for crate users
-pub use translatable_proc::translation;
+/// Re-export the Markdown translation macro for crate users
+#[cfg(feature = "markdown")]
+pub use translatable_proc::translation_markdown;
+/// Re-export the procedural macros for crate users
+pub use translatable_proc::{
+    assert_translations_complete, embed_translations, include_translation_file, translation,
+    translation_accessors, translation_inline, translation_or_key, translation_prefix,
+    translation_slugs, translation_stats, translation_write, translations, try_translation,
+};
+/// Re-export the Markdown rendering of runtime translations for crate users
+#[cfg(feature = "markdown")]
+pub use translatable_shared::render_markdown;
+/// Re-export the shared translation structures for crate users
+pub use translatable_shared::{
+    AssociatedTranslation, KeyKind, Precedence, SourceLocation, TranslationNode,
+    TranslationNodeCollection, TranslationObject,
+};
+/// Re-export the configuration values for crate users
+pub use translatable_shared::{Escape, MissingArguments, SeekMode, Severity, TranslationOverlap};
+/// Re-export the shared language types for crate users
+pub use translatable_shared::{Language, TextDirection};
+/// Re-export the catalog lints for crate users
+pub use translatable_shared::{Lint, LintFinding, lint_catalog};
+#[cfg(feature = "tokio")]
+pub use translator::init_translations;
+pub use translator::{Translator, set_translations};
+pub use values::TranslationValue;
+
+#[cfg(feature = "tauri")]
+pub use crate::tauri::{get_catalog, get_translation, set_locale};
+
+mod arguments;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+mod bidi;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod browser;
+mod config;
+mod diff;
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+mod duplicates;
+#[cfg(feature = "egui")]
+pub mod egui;
+mod escape;
+pub mod export;
+#[cfg(feature = "extract")]
+pub mod extract;
+mod filters;
+mod formatted;
+mod hook;
+mod instrument;
+#[cfg(feature = "leptos")]
+pub mod leptos;
+mod lists;
+mod loading;
+mod locale;
+mod localized;
+mod machine;
+mod mock;
+mod negotiation;
+#[cfg(feature = "num-format")]
+mod numbers;
+mod overrides;
+mod providers;
+mod random;
+mod slugs;
+mod stats;
+#[cfg(feature = "tauri")]
+pub mod tauri;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+#[cfg(feature = "tonic")]
+pub mod tonic;
+#[cfg(feature = "tower")]
+pub mod tower;
+mod translator;
+mod values;
+#[cfg(feature = "yew")]
+pub mod yew;
 
 /// Error type for translation resolution failures
 ///
 /// Returned by the translation macro when dynamic resolution fails.
 /// For static resolution failures, errors are reported at compile time.
-#[derive(Error, Debug)]
+///
+/// New variants may be added in minor releases, match on [`Error::code`] to
+/// map failures to statuses or metrics instead of the `Display` output.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// Invalid ISO 639-1 language code provided
-    #[error("The language '{0}' is invalid.")]
-    InvalidLanguage(String),
+    #[error("The language '{language}' is invalid.{}", similar_languages(.suggestions))]
+    InvalidLanguage {
+        /// Requested language, after resolving aliases
+        language: String,
+        /// Languages the requested one may be a typo of, closest first, see
+        /// [`Language::suggestions`]
+        suggestions: Vec<Language>,
+    },
 
     /// Translation exists but not available for specified language
-    #[error("The langauge '{0}' is not available for the path '{1}'")]
-    LanguageNotAvailable(String, String),
+    #[error(
+        "The language '{}' is not available for the path '{path}'.{}",
+        .language.code(),
+        available_languages(.available)
+    )]
+    LanguageNotAvailable {
+        /// Requested language
+        language: Language,
+        /// Dot-separated translation path
+        path: String,
+        /// Languages the translation is available in, sorted by code
+        available: Vec<Language>,
+    },
 
     /// Requested translation path doesn't exist in any translation files
-    #[error("The path '{0}' was not found in any of the translations files.")]
-    PathNotFound(String),
+    #[error("The path '{path}' was not found in any of the translations files.")]
+    PathNotFound {
+        /// Dot-separated translation path
+        path: String,
+    },
+
+    /// Translation template left without an argument, only returned when
+    /// `missing_arguments = "error"` is configured
+    #[error("The template '{{{template}}}' has no argument in the '{path}' translation.")]
+    MissingArgument {
+        /// Template name, without braces
+        template: String,
+        /// Dot-separated translation path
+        path: String,
+    },
+
+    /// Value of the `_values` namespace that can't be read as the requested
+    /// type
+    #[error("The value of '{path}' for '{}' can't be read as the requested type.", .language.code())]
+    InvalidValue {
+        /// Dot-separated path of the value, inside the `_values` namespace
+        path: String,
+        /// Requested language
+        language: Language,
+    },
 }
 
 impl Error {
+    /// Returns a stable code identifying the error variant
+    ///
+    /// Codes never change between releases, unlike the `Display` output.
+    ///
+    /// | Variant                | Code                       |
+    /// |------------------------|----------------------------|
+    /// | `InvalidLanguage`      | `"invalid_language"`       |
+    /// | `LanguageNotAvailable` | `"language_not_available"` |
+    /// | `PathNotFound`         | `"path_not_found"`         |
+    /// | `MissingArgument`      | `"missing_argument"`       |
+    /// | `InvalidValue`         | `"invalid_value"`          |
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidLanguage { .. } => "invalid_language",
+            Self::LanguageNotAvailable { .. } => "language_not_available",
+            Self::PathNotFound { .. } => "path_not_found",
+            Self::MissingArgument { .. } => "missing_argument",
+            Self::InvalidValue { .. } => "invalid_value",
+        }
+    }
+
+    /// Renders the error message in a language, for failures shown to the
+    /// users of a localized interface
+    ///
+    /// The messages come from the built-in [`error_catalog`], languages it
+    /// doesn't have fall back to the English `Display` output.
+    ///
+    /// # Example
+    /// ```
+    /// use translatable::{Error, Language};
+    ///
+    /// let error = Error::PathNotFound { path: "home.title".into() };
+    /// assert_eq!(
+    ///     error.localized(&Language::ES),
+    ///     "La ruta 'home.title' no se encontró en ninguno de los archivos de traducciones."
+    /// );
+    /// ```
+    pub fn localized(&self, language: &Language) -> String {
+        localized::localized(self, language).unwrap_or_else(|| self.to_string())
+    }
+
     /// Returns formatted error message as a String
     ///
     /// Useful for error reporting and logging. Marked `#[cold]` to hint to the
@@ -34,39 +228,106 @@ impl Error {
     }
 }
 
+/// Lists the languages a translation is available in, if any
+fn available_languages(available: &[Language]) -> String {
+    if available.is_empty() {
+        return String::new();
+    }
+
+    let available = available.iter().map(Language::code).collect::<Vec<_>>().join(", ");
+
+    format!(" It's available in {available}.")
+}
+
+/// Lists the suggestions of an invalid language error, if any
+fn similar_languages(suggestions: &[Language]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+
+    let suggestions = suggestions
+        .iter()
+        .map(|language| format!("{language:#} ({language:?})"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(" Similar languages are {suggestions}.")
+}
+
 /// Internal implementation details for translation resolution
 #[doc(hidden)]
 pub mod internal {
-    use std::collections::HashMap;
+    /// Perfect hash maps for the generated runtime lookup tables
+    #[doc(hidden)]
+    pub use phf;
+    /// Selects the plural and select arms of a translation
+    #[doc(hidden)]
+    pub use translatable_shared::format_choices;
+    /// Collects the template names in a translation
+    #[doc(hidden)]
+    pub use translatable_shared::templates;
+    /// Converts the language expressions of runtime resolutions
+    #[doc(hidden)]
+    pub use translatable_shared::{IntoLanguageArgument, LanguageArgument};
+
+    use crate::Language;
+    /// Checks the format argument values where they are written
+    #[doc(hidden)]
+    pub use crate::arguments::{FormatArgument, format_argument};
+    /// Wraps the templates of right-to-left translations in isolation
+    /// characters
+    #[doc(hidden)]
+    pub use crate::bidi::isolate_templates;
+    /// Replaces the templates with filters of a translation
+    #[doc(hidden)]
+    pub use crate::filters::format_filters;
+    /// Reports failed lookups to the missing translation hook
+    #[doc(hidden)]
+    pub use crate::hook::missing_translation;
+    /// Emits `tracing` events for runtime lookups
+    #[doc(hidden)]
+    pub use crate::instrument::{fallback as trace_fallback, lookup as trace_lookup};
+    /// Picks one of the random entries of a translation
+    #[doc(hidden)]
+    pub use crate::random::pick_entry;
 
-    /// Represents nested translation structures
+    /// Builds the error of an invalid language, with up to 10 suggestions
     #[doc(hidden)]
-    pub enum NestingType {
-        /// Intermediate node containing nested translation objects
-        Object(HashMap<String, NestingType>),
-        /// Leaf node containing actual translations for different languages
-        Translation(HashMap<String, String>),
+    pub fn invalid_language(language: String) -> crate::Error {
+        let suggestions = Language::suggestions(&language, 10);
+
+        crate::Error::InvalidLanguage { language, suggestions }
     }
 
-    impl NestingType {
-        /// Resolves a translation path through nested structures
-        ///
-        /// # Arguments
-        /// * `path` - Slice of path segments to resolve
-        ///
-        /// # Returns
-        /// - `Some(&HashMap)` if path resolves to translations
-        /// - `None` if path is invalid
-        #[doc(hidden)]
-        pub fn get_path(&self, path: Vec<&str>) -> Option<&HashMap<String, String>> {
-            match self {
-                Self::Object(nested) => {
-                    let (first, rest) = path.split_first()?;
-                    nested.get(*first)?.get_path(rest.to_vec())
-                },
-
-                Self::Translation(translation) => path.is_empty().then_some(translation),
-            }
-        }
+    /// Builds the error of an invalid language, with up to 10 suggestions
+    /// among the configured languages
+    #[doc(hidden)]
+    pub fn invalid_language_among(language: String, languages: &[Language]) -> crate::Error {
+        let suggestions = Language::suggestions_among(&language, 10, languages);
+
+        crate::Error::InvalidLanguage { language, suggestions }
+    }
+
+    /// Unwraps the collection decoded by `embed_translations!`, decoded
+    /// once per expansion
+    #[doc(hidden)]
+    pub fn embedded_translations(
+        decoded: Option<crate::TranslationNodeCollection>,
+    ) -> crate::TranslationNodeCollection {
+        #[cfg(feature = "test-support")]
+        crate::test_support::count_embedded_decode();
+
+        decoded.expect("embedded translations are always valid")
+    }
+
+    /// Collects the languages of a generated lookup table, sorted by code
+    #[doc(hidden)]
+    pub fn available_languages(
+        translation: &phf::Map<&'static str, &'static str>,
+    ) -> Vec<Language> {
+        let mut available =
+            translation.keys().filter_map(|code| code.parse().ok()).collect::<Vec<Language>>();
+        available.sort_by_key(|language| language.code());
+        available
     }
 }
@@ -0,0 +1,153 @@
+//! Locale-aware list formatting
+//!
+//! This module joins list items with the separators and conjunction of a
+//! language, such as `"a, b y c"` in Spanish, either directly with
+//! [`format_list`] or through the `list` template filter on a [`List`]
+//! argument.
+
+use std::fmt::{self, Display, Formatter};
+
+use translatable_shared::Language;
+
+/// Fill character the filters format values with, which a [`List`]
+/// separates its items with so the `list` filter can split them again
+pub(crate) const LIST_SEPARATOR: char = '\u{1F}';
+
+/// Argument value holding list items
+///
+/// Templates without filters write the items separated by commas, the
+/// `list` filter joins them for the language of the translation.
+///
+/// # Example
+/// ```
+/// use translatable::{Language, List, MockTranslations};
+///
+/// let translator = MockTranslations::new()
+///     .translation("guests", Language::ES, "Invitados: {names|list}")
+///     .translator(Language::ES);
+///
+/// let names = ["Ana", "Luis", "Marta"];
+/// assert_eq!(
+///     translator.get_with("guests", &[("names", &List(&names))]).unwrap(),
+///     "Invitados: Ana, Luis y Marta"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct List<I>(pub I);
+
+impl<I> Display for List<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let separator = if f.fill() == LIST_SEPARATOR { "\u{1F}" } else { ", " };
+
+        for (index, item) in self.0.clone().into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+
+            write!(f, "{item:#}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the separators of a language's lists
+///
+/// # Returns
+/// The separators between two items, between the items of longer lists and
+/// before the last item of longer lists
+fn separators(
+    language: &Language,
+    oxford_comma: bool,
+) -> (&'static str, &'static str, &'static str) {
+    match language.code() {
+        "en" if oxford_comma => (" and ", ", ", ", and "),
+        "hi" => (" और ", ", ", ", और "),
+        "es" => (" y ", ", ", " y "),
+        "fr" => (" et ", ", ", " et "),
+        "de" => (" und ", ", ", " und "),
+        "it" | "pt" => (" e ", ", ", " e "),
+        "nl" => (" en ", ", ", " en "),
+        "ca" | "pl" => (" i ", ", ", " i "),
+        "cs" | "sk" => (" a ", ", ", " a "),
+        "ro" => (" și ", ", ", " și "),
+        "ru" => (" и ", ", ", " и "),
+        "uk" => (" і ", ", ", " і "),
+        "sv" => (" och ", ", ", " och "),
+        "da" | "nb" | "nn" | "no" => (" og ", ", ", " og "),
+        "fi" => (" ja ", ", ", " ja "),
+        "tr" => (" ve ", ", ", " ve "),
+        "hu" => (" és ", ", ", " és "),
+        "el" => (" και ", ", ", " και "),
+        "ar" => (" و", " و", " و"),
+        "he" => (" ו", ", ", " ו"),
+        "ja" => ("、", "、", "、"),
+        "zh" => ("和", "、", "和"),
+        "ko" => (" 및 ", ", ", " 및 "),
+        _ => (" and ", ", ", " and "),
+    }
+}
+
+/// Returns whether a Spanish word starts with the `i` sound, before which
+/// `y` is written `e`
+fn spanish_i_sound(word: &str) -> bool {
+    let word = word.to_lowercase();
+    let word = word.strip_prefix('h').unwrap_or(&word);
+
+    let mut characters = word.chars();
+    matches!(characters.next(), Some('i' | 'í'))
+        && !matches!(characters.next(), Some('a' | 'e' | 'o' | 'u' | 'á' | 'é' | 'ó' | 'ú'))
+}
+
+/// Joins list items for a language
+///
+/// Only the conjunctions of the most common languages are implemented,
+/// other languages use the English ones.
+///
+/// # Arguments
+/// * `language` - Language the items are joined for
+/// * `items` - Items written with their alternate `Display` output
+/// * `oxford_comma` - Whether English lists of three or more items have a comma
+///   before the conjunction, ignored by the other languages
+///
+/// # Example
+/// ```
+/// use translatable::{Language, format_list};
+///
+/// assert_eq!(format_list(&Language::EN, ["a", "b", "c"], true), "a, b, and c");
+/// assert_eq!(format_list(&Language::EN, ["a", "b", "c"], false), "a, b and c");
+/// assert_eq!(format_list(&Language::ES, ["a", "b", "c"], true), "a, b y c");
+/// ```
+pub fn format_list<I>(language: &Language, items: I, oxford_comma: bool) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let items = items.into_iter().map(|item| format!("{item:#}")).collect::<Vec<_>>();
+    let (pair, separator, last) = separators(language, oxford_comma);
+
+    let mut formatted = String::new();
+    for (index, item) in items.iter().enumerate() {
+        let conjunction = match index {
+            0 => "",
+            _ if index + 1 < items.len() => separator,
+            1 => pair,
+            _ => last,
+        };
+
+        if *language == Language::ES && conjunction.ends_with(" y ") && spanish_i_sound(item) {
+            formatted.push_str(conjunction.trim_end_matches("y "));
+            formatted.push_str("e ");
+        } else {
+            formatted.push_str(conjunction);
+        }
+
+        formatted.push_str(item);
+    }
+
+    formatted
+}
@@ -0,0 +1,163 @@
+//! Runtime translation file loading
+//!
+//! This module parses translation files loaded after the build, such as
+//! override files or catalogs fetched by a provider, validating them the same
+//! way the macros validate the translation files.
+
+use std::fs::{read_dir, read_to_string};
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use toml::de::Error as TomlError;
+#[cfg(feature = "sqlx")]
+use toml::{Table, Value};
+use translatable_shared::{
+    AssociatedTranslation, TransformError, TranslationNode, TranslationNodeCollection,
+    apply_conditions, apply_named_variants, apply_target_variants, apply_trim, apply_values,
+    parse_with_locations, sort_by_priority, take_deprecations, take_priority, take_version,
+};
+
+/// Error type for runtime translation loading failures
+///
+/// Every variant holds the path or URL the translations were loaded from.
+#[derive(Error, Debug)]
+pub enum LoadError {
+    /// The translation file couldn't be read
+    #[error("Couldn't read the translation file {1}: {0:#}")]
+    Io(IoError, String),
+
+    /// The translations couldn't be fetched
+    #[error("Couldn't fetch the translations from {1}: {0}")]
+    Http(String, String),
+
+    /// The translations couldn't be queried
+    #[error("Couldn't query the translations with '{1}': {0}")]
+    Database(String, String),
+
+    /// The translations aren't valid TOML
+    #[error("Toml parse error in {path}: {message}", path = .1, message = .0.message())]
+    ParseToml(TomlError, String),
+
+    /// The translations aren't a valid translation file
+    #[error("Invalid TOML structure in {1}: {0}")]
+    InvalidTomlFormat(TransformError, String),
+}
+
+/// Parses the content of a translation file
+///
+/// # Arguments
+/// * `content` - TOML content of the translation file
+/// * `source` - Path or URL the content was loaded from
+pub(crate) fn parse_translations(
+    content: &str,
+    source: &str,
+) -> Result<TranslationNodeCollection, LoadError> {
+    let invalid = |err| LoadError::InvalidTomlFormat(err, source.to_string());
+
+    let (mut table, locations) = parse_with_locations(content)
+        .map_err(|err| LoadError::ParseToml(err, source.to_string()))?;
+
+    let priority = take_priority(&mut table).map_err(invalid)?;
+    let version = take_version(&mut table).map_err(invalid)?;
+
+    // Application features are unknown at runtime, so every table is kept
+    apply_conditions(&mut table, None).map_err(invalid)?;
+    let deprecations = take_deprecations(&mut table).map_err(invalid)?;
+    apply_trim(&mut table).map_err(invalid)?;
+    apply_values(&mut table).map_err(invalid)?;
+    apply_target_variants(&mut table, std::env::consts::OS);
+    apply_named_variants(&mut table);
+
+    let translation_table = TranslationNode::try_from(table).map_err(invalid)?;
+
+    Ok(TranslationNodeCollection::new(vec![
+        AssociatedTranslation::new(source, translation_table)
+            .with_priority(priority)
+            .with_version(version)
+            .with_deprecations(deprecations)
+            .with_locations(locations),
+    ]))
+}
+
+/// Loads every translation file in a directory, recursively
+///
+/// The files are sorted by path and `_priority` like the macros sort the
+/// translations directory, so the first file containing a path wins likewise.
+pub(crate) fn load_directory(directory: &Path) -> Result<TranslationNodeCollection, LoadError> {
+    /// Collects the TOML files of a directory and its subdirectories
+    fn collect(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), LoadError> {
+        let io_error = |err| LoadError::Io(err, directory.display().to_string());
+
+        for entry in read_dir(directory).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+
+            if path.is_dir() {
+                collect(&path, files)?;
+            } else if path.extension().is_some_and(|extension| extension == "toml") {
+                files.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    collect(directory, &mut files)?;
+    files.sort_by_key(|path| path.to_string_lossy().to_lowercase());
+
+    let mut translations = Vec::new();
+
+    for file in files {
+        let source = file.display().to_string();
+        let content = read_to_string(&file).map_err(|err| LoadError::Io(err, source.clone()))?;
+
+        translations.extend(parse_translations(&content, &source)?.iter().cloned());
+    }
+
+    sort_by_priority(&mut translations);
+
+    Ok(TranslationNodeCollection::new(translations))
+}
+
+/// Parses translation rows, such as the ones of a database table
+///
+/// The rows are nested into a translation file, which is validated the same
+/// way as the ones in the translations directory.
+///
+/// # Arguments
+/// * `rows` - Dot-separated path, language and translation of every row
+/// * `source` - Query or table the rows were loaded from
+#[cfg(feature = "sqlx")]
+pub(crate) fn parse_rows(
+    rows: impl IntoIterator<Item = (String, String, String)>,
+    source: &str,
+) -> Result<TranslationNodeCollection, LoadError> {
+    let invalid_nesting =
+        || LoadError::InvalidTomlFormat(TransformError::InvalidNesting, source.to_string());
+
+    let mut table = Table::new();
+
+    for (path, language, translation) in rows {
+        let mut nesting = &mut table;
+
+        for segment in path.split('.') {
+            nesting = match nesting.entry(segment).or_insert_with(|| Value::Table(Table::new())) {
+                Value::Table(nested) => nested,
+                _ => return Err(invalid_nesting()),
+            };
+        }
+
+        if nesting
+            .insert(language, Value::String(translation))
+            .is_some_and(|value| value.is_table())
+        {
+            return Err(invalid_nesting());
+        }
+    }
+
+    let translation_table = TranslationNode::try_from(table)
+        .map_err(|err| LoadError::InvalidTomlFormat(err, source.to_string()))?;
+
+    Ok(TranslationNodeCollection::new(vec![AssociatedTranslation::new(source, translation_table)]))
+}
@@ -0,0 +1,110 @@
+//! Shared current language of an application
+//!
+//! This module provides the [`Locale`] handle, which UI integrations share
+//! between their components so switching the language in one place updates
+//! every view bound to it.
+
+use std::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex, RwLock};
+
+use translatable_shared::Language;
+
+use crate::Translator;
+
+/// Callback notified with the new language of a [`Locale`]
+type Subscriber = Box<dyn Fn(&Language) + Send + Sync>;
+
+/// State shared by the clones of a [`Locale`]
+struct LocaleState {
+    /// Current language
+    language: RwLock<Language>,
+    /// Callbacks registered with [`Locale::subscribe`]
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+/// Cloneable handle to the current language, notifying its subscribers when
+/// it changes
+///
+/// Clones share the same language, and compare equal to each other only.
+///
+/// # Example
+/// ```
+/// use translatable::{Language, Locale};
+///
+/// let locale = Locale::new(Language::EN);
+/// locale.subscribe(|language| println!("switched to {}", language.code()));
+///
+/// locale.clone().set_language(Language::ES);
+/// assert_eq!(locale.language(), Language::ES);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "bevy", derive(::bevy::ecs::resource::Resource))]
+pub struct Locale(Arc<LocaleState>);
+
+impl Locale {
+    /// Creates a handle with an initial language
+    pub fn new(language: Language) -> Self {
+        Self(Arc::new(LocaleState {
+            language: RwLock::new(language),
+            subscribers: Mutex::new(Vec::new()),
+        }))
+    }
+
+    /// Gets the current language
+    pub fn language(&self) -> Language {
+        self.0.language.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Replaces the current language
+    ///
+    /// The subscribers are notified in registration order, only if the
+    /// language changed.
+    pub fn set_language(&self, language: Language) {
+        {
+            let mut current =
+                self.0.language.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            if *current == language {
+                return;
+            }
+
+            *current = language.clone();
+        }
+
+        for subscriber in
+            self.0.subscribers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).iter()
+        {
+            subscriber(&language);
+        }
+    }
+
+    /// Registers a callback notified with every new language
+    ///
+    /// Subscribers live as long as the handle, and must not subscribe to the
+    /// same handle from the callback.
+    pub fn subscribe(&self, subscriber: impl Fn(&Language) + Send + Sync + 'static) {
+        self.0
+            .subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(Box::new(subscriber));
+    }
+
+    /// Creates a translator for the current language, resolving against the
+    /// process-wide translations like [`Translator::new`]
+    pub fn translator(&self) -> Translator<'static> {
+        Translator::new(self.language())
+    }
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Debug for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Locale").field(&self.language()).finish()
+    }
+}
@@ -0,0 +1,98 @@
+//! Localized error messages
+//!
+//! This module renders the [`Error`] messages in other languages than
+//! English, from a catalog embedded in the crate and resolved like any other
+//! translation file, so failures shown in a localized user interface don't
+//! fall back to English.
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use translatable_shared::{Language, TranslationNodeCollection};
+
+use crate::loading::parse_translations;
+use crate::{Error, Formatted};
+
+/// Translation file of the built-in error messages
+const CATALOG: &str = include_str!("../locales/errors.toml");
+
+/// Gets the built-in catalog of the [`Error`] messages
+///
+/// Every path is an [`Error::code`], the `invalid_language` and
+/// `language_not_available` ones being split into `message` and the
+/// `suggestions` or `available` details. The catalog can be exported along
+/// with the application translations, or inherited to add languages.
+pub fn error_catalog() -> &'static TranslationNodeCollection {
+    static TRANSLATIONS: OnceLock<TranslationNodeCollection> = OnceLock::new();
+
+    TRANSLATIONS.get_or_init(|| {
+        parse_translations(CATALOG, "translatable/locales/errors.toml")
+            .expect("the built-in error catalog is a valid translation file")
+    })
+}
+
+/// Renders an error message in a language of the built-in catalog
+///
+/// # Returns
+/// `None` if the catalog doesn't have the language
+pub(crate) fn localized(error: &Error, language: &Language) -> Option<String> {
+    let catalog = error_catalog();
+    let render = |path: &str, arguments: &[(&str, &dyn Display)]| {
+        let translation = catalog.find_path(path)?.get(language)?;
+
+        Some(Formatted::new(translation, arguments).with_language(language).to_string())
+    };
+
+    // Details are appended to the message if there is anything to list
+    let detailed = |message: Option<String>, detail: Option<String>| {
+        Some(match detail {
+            Some(detail) => format!("{} {detail}", message?),
+            None => message?,
+        })
+    };
+
+    match error {
+        Error::InvalidLanguage { language, suggestions } => {
+            let suggestions = (!suggestions.is_empty()).then(|| {
+                // Names are written as their autonyms, not in English
+                suggestions
+                    .iter()
+                    .map(|language| format!("{} ({language:?})", language.native_name()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            });
+
+            detailed(
+                render("invalid_language.message", &[("language", language)]),
+                suggestions.and_then(|suggestions| {
+                    render("invalid_language.suggestions", &[("suggestions", &suggestions)])
+                }),
+            )
+        },
+
+        Error::LanguageNotAvailable { language, path, available } => {
+            let available = (!available.is_empty())
+                .then(|| available.iter().map(Language::code).collect::<Vec<_>>().join(", "));
+
+            detailed(
+                render(
+                    "language_not_available.message",
+                    &[("language", &language.code()), ("path", path)],
+                ),
+                available.and_then(|available| {
+                    render("language_not_available.available", &[("available", &available)])
+                }),
+            )
+        },
+
+        Error::PathNotFound { path } => render("path_not_found", &[("path", path)]),
+
+        Error::MissingArgument { template, path } => {
+            render("missing_argument", &[("template", &format!("{{{template}}}")), ("path", path)])
+        },
+
+        Error::InvalidValue { path, language } => {
+            render("invalid_value", &[("path", path), ("language", &language.code())])
+        },
+    }
+}
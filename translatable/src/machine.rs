@@ -0,0 +1,292 @@
+//! Machine translation of missing translations
+//!
+//! This module defines the [`MtProvider`] trait, implemented by machine
+//! translation services, and [`fill_missing`], translating the paths a
+//! catalog is missing to bootstrap new languages before they are reviewed.
+//!
+//! The [`LibreTranslateProvider`] reference implementation is available with
+//! the `libretranslate` feature.
+
+use toml_edit::{DocumentMut, Item};
+use translatable_shared::{
+    TransformError, TranslationNode, has_choices, has_random_entries, templates,
+};
+
+use crate::Language;
+
+/// Comment marking the machine translated values of a translation file
+pub const MACHINE_TRANSLATED: &str = "machine-translated";
+
+/// Machine translation service
+///
+/// # Example
+/// ```
+/// use std::convert::Infallible;
+///
+/// use translatable::{Language, MtProvider};
+///
+/// struct Shouting;
+///
+/// impl MtProvider for Shouting {
+///     type Error = Infallible;
+///
+///     fn translate(
+///         &self,
+///         texts: &[&str],
+///         _source: &Language,
+///         _target: &Language,
+///     ) -> Result<Vec<String>, Infallible> {
+///         Ok(texts.iter().map(|text| text.to_uppercase()).collect())
+///     }
+/// }
+/// ```
+pub trait MtProvider {
+    /// Error returned when the texts can't be translated
+    type Error;
+
+    /// Translates texts from a language to another, blocking until they are
+    /// translated
+    ///
+    /// # Returns
+    /// The translations, in the same order as the texts
+    fn translate(
+        &self,
+        texts: &[&str],
+        source: &Language,
+        target: &Language,
+    ) -> Result<Vec<String>, Self::Error>;
+}
+
+/// Paths [`fill_missing`] translated and left untranslated
+#[derive(Clone, Debug, Default)]
+pub struct FillReport {
+    /// Language and path pairs machine translated, sorted by path
+    filled: Vec<(Language, String)>,
+    /// Language and path pairs that couldn't be machine translated, sorted by
+    /// path
+    skipped: Vec<(Language, String)>,
+}
+
+impl FillReport {
+    /// Gets the language and path pairs machine translated, sorted by path
+    pub fn filled(&self) -> &[(Language, String)] {
+        &self.filled
+    }
+
+    /// Gets the language and path pairs that couldn't be machine translated,
+    /// sorted by path
+    ///
+    /// These are the plural and select messages and the translations with
+    /// random entries, whose syntax translation services don't keep, and the
+    /// translations that lost their templates.
+    pub fn skipped(&self) -> &[(Language, String)] {
+        &self.skipped
+    }
+
+    /// Marks the machine translated values of a translation file with a
+    /// `# machine-translated` comment
+    ///
+    /// Meant for the document written by
+    /// [`TranslationNode::update_toml`], the comments are kept by later
+    /// updates until a reviewer removes them.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the marked document
+    /// - `Err(TransformError)` if the document is not valid TOML
+    pub fn mark_toml(&self, document: &str) -> Result<String, TransformError> {
+        let mut document = document
+            .parse::<DocumentMut>()
+            .map_err(|err| TransformError::InvalidDocument(err.to_string()))?;
+
+        for (language, path) in &self.filled {
+            let item = path
+                .split('.')
+                .chain([language.code()])
+                .try_fold(document.as_item_mut(), |item, segment| item.get_mut(segment));
+
+            if let Some(Item::Value(value)) = item {
+                value.decor_mut().set_suffix(format!(" # {MACHINE_TRANSLATED}"));
+            }
+        }
+
+        Ok(document.to_string())
+    }
+}
+
+/// Machine translates the paths of a catalog missing a language
+///
+/// Every translation object with a `source` translation and without a target
+/// one gets the target translation from the provider, a batch per target
+/// language. Translations must keep the templates of their source, the ones
+/// that don't, the plural and select messages and the translations with
+/// random entries are left untranslated and reported as skipped.
+///
+/// # Returns
+/// - `Ok(FillReport)` with the language and path pairs translated
+/// - `Err(P::Error)` if the provider failed, the catalog keeps the translations
+///   of the languages translated before
+pub fn fill_missing<P: MtProvider>(
+    catalog: &mut TranslationNode,
+    source: &Language,
+    targets: &[Language],
+    provider: &P,
+) -> Result<FillReport, P::Error> {
+    let mut report = FillReport::default();
+
+    for target in targets.iter().filter(|target| *target != source) {
+        let mut pending = Vec::new();
+        collect_missing(catalog, source, target, &mut String::new(), &mut pending);
+
+        let (translatable, skipped) = pending
+            .into_iter()
+            .partition::<Vec<_>, _>(|(_, text)| !has_choices(text) && !has_random_entries(text));
+
+        report.skipped.extend(skipped.into_iter().map(|(path, _)| (target.clone(), path)));
+
+        if translatable.is_empty() {
+            continue;
+        }
+
+        let texts = translatable.iter().map(|(_, text)| text.as_str()).collect::<Vec<_>>();
+        let translations = provider.translate(&texts, source, target)?;
+
+        for ((path, text), translation) in translatable.into_iter().zip(translations) {
+            let segments = path.split('.').collect::<Vec<_>>();
+
+            let mut expected = templates(&text);
+            let mut found = templates(&translation);
+            expected.sort();
+            found.sort();
+
+            match expected == found && catalog.set(segments, target.clone(), translation).is_ok() {
+                true => report.filled.push((target.clone(), path)),
+                false => report.skipped.push((target.clone(), path)),
+            }
+        }
+    }
+
+    report.filled.sort_by(|(_, first), (_, second)| first.cmp(second));
+    report.skipped.sort_by(|(_, first), (_, second)| first.cmp(second));
+
+    Ok(report)
+}
+
+/// Collects the paths with a source translation and without a target one
+fn collect_missing(
+    node: &TranslationNode,
+    source: &Language,
+    target: &Language,
+    path: &mut String,
+    missing: &mut Vec<(String, String)>,
+) {
+    match node {
+        TranslationNode::Object(nested) => {
+            for (key, node) in nested {
+                let length = path.len();
+
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+
+                collect_missing(node, source, target, path, missing);
+                path.truncate(length);
+            }
+        },
+        TranslationNode::Translation(translations) => {
+            if let (Some(text), None) = (translations.get(source), translations.get(target)) {
+                missing.push((path.clone(), text.clone()));
+            }
+        },
+    }
+}
+
+#[cfg(feature = "libretranslate")]
+pub use libretranslate::LibreTranslateProvider;
+
+/// Reference provider translating through a LibreTranslate server
+#[cfg(feature = "libretranslate")]
+mod libretranslate {
+    use serde_json::{Value, json};
+
+    use super::MtProvider;
+    use crate::Language;
+    use crate::loading::LoadError;
+
+    /// Provider translating through a LibreTranslate server, such as a self
+    /// hosted one
+    ///
+    /// Failed requests and unexpected responses return `LoadError::Http`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use translatable::{Language, LibreTranslateProvider, fill_missing};
+    ///
+    /// let provider = LibreTranslateProvider::new("http://localhost:5000").api_key("key");
+    /// let report = fill_missing(&mut catalog, &Language::EN, &[Language::FR], &provider)?;
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct LibreTranslateProvider {
+        /// URL of the server
+        url: String,
+        /// API key sent with the requests, if the server requires one
+        api_key: Option<String>,
+    }
+
+    impl LibreTranslateProvider {
+        /// Creates a provider translating through a server URL
+        pub fn new(url: impl Into<String>) -> Self {
+            Self { url: url.into(), api_key: None }
+        }
+
+        /// Sets the API key sent with the requests
+        pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+            self.api_key = Some(api_key.into());
+            self
+        }
+    }
+
+    impl MtProvider for LibreTranslateProvider {
+        type Error = LoadError;
+
+        fn translate(
+            &self,
+            texts: &[&str],
+            source: &Language,
+            target: &Language,
+        ) -> Result<Vec<String>, LoadError> {
+            let url = format!("{}/translate", self.url.trim_end_matches('/'));
+            let http_error = |err: String| LoadError::Http(err, url.clone());
+
+            let mut body = json!({
+                "q": texts,
+                "source": source.code(),
+                "target": target.code(),
+                "format": "text",
+            });
+
+            if let Some(api_key) = &self.api_key {
+                body["api_key"] = Value::from(api_key.as_str());
+            }
+
+            let response = ureq::post(&url)
+                .header("Content-Type", "application/json")
+                .send(body.to_string())
+                .map_err(|err| http_error(err.to_string()))?
+                .body_mut()
+                .read_to_string()
+                .map_err(|err| http_error(err.to_string()))?;
+
+            let response = serde_json::from_str::<Value>(&response)
+                .map_err(|err| http_error(err.to_string()))?;
+
+            response["translatedText"]
+                .as_array()
+                .filter(|translations| translations.len() == texts.len())
+                .and_then(|translations| {
+                    translations.iter().map(|text| text.as_str().map(str::to_string)).collect()
+                })
+                .ok_or_else(|| http_error("Unexpected response".to_string()))
+        }
+    }
+}
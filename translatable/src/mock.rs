@@ -0,0 +1,120 @@
+//! In-memory translations for unit tests
+//!
+//! This module provides the [`MockTranslations`] builder, which registers
+//! translations programmatically so code using [`Translator`] can be tested
+//! without translation files.
+
+use std::collections::HashMap;
+
+use translatable_shared::{
+    AssociatedTranslation, Language, TranslationNode, TranslationNodeCollection,
+};
+
+use crate::Translator;
+
+/// Source path reported for the mocked translations
+const MOCK_PATH: &str = "<mock>";
+
+/// Builder for a translation collection registered in code
+///
+/// # Example
+/// ```
+/// use translatable::{Language, MockTranslations, Translator};
+///
+/// let translations = MockTranslations::new()
+///     .translation("common.greeting", Language::EN, "Hello {name}!")
+///     .translation("common.greeting", Language::ES, "¡Hola {name}!")
+///     .build();
+///
+/// let translator = Translator::with_translations(Language::ES, &translations);
+/// assert_eq!(translator.get_with("common.greeting", &[("name", &"john")]).unwrap(), "¡Hola john!");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockTranslations {
+    /// Root nesting of the registered translations
+    root: HashMap<String, TranslationNode>,
+}
+
+impl MockTranslations {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a translation for a dot-separated path and language
+    ///
+    /// Registering the same path and language again replaces the
+    /// translation, a path nested under an existing translation replaces it
+    /// with a nesting and the other way around.
+    pub fn translation(
+        mut self,
+        path: &str,
+        language: Language,
+        translation: impl Into<String>,
+    ) -> Self {
+        let mut segments = path.split('.').collect::<Vec<_>>();
+        let last = segments.pop().map(str::to_string).unwrap_or_default();
+
+        let mut nesting = &mut self.root;
+        for segment in segments {
+            let node = nesting
+                .entry(segment.to_string())
+                .or_insert_with(|| TranslationNode::Object(HashMap::new()));
+
+            if let TranslationNode::Translation(_) = node {
+                *node = TranslationNode::Object(HashMap::new());
+            }
+
+            let TranslationNode::Object(nested) = node else { unreachable!() };
+            nesting = nested;
+        }
+
+        let node =
+            nesting.entry(last).or_insert_with(|| TranslationNode::Translation(HashMap::new()));
+
+        if let TranslationNode::Object(_) = node {
+            *node = TranslationNode::Translation(HashMap::new());
+        }
+
+        if let TranslationNode::Translation(translations) = node {
+            translations.insert(language, translation.into());
+        }
+
+        self
+    }
+
+    /// Registers the translations of a dot-separated path in every language
+    pub fn translations<'a>(
+        self,
+        path: &str,
+        translations: impl IntoIterator<Item = (Language, &'a str)>,
+    ) -> Self {
+        translations.into_iter().fold(self, |mock, (language, translation)| {
+            mock.translation(path, language, translation)
+        })
+    }
+
+    /// Builds the collection, usable wherever embedded translations are
+    pub fn build(self) -> TranslationNodeCollection {
+        self.into()
+    }
+
+    /// Builds a translator for a language, resolving against the registered
+    /// translations only
+    ///
+    /// The collection is leaked so the translator is `'static` like the ones
+    /// created with [`Translator::new`], building translators is meant for
+    /// tests only.
+    pub fn translator(self, language: Language) -> Translator<'static> {
+        Translator::with_translations(language, Box::leak(Box::new(self.build())))
+    }
+}
+
+impl From<MockTranslations> for TranslationNodeCollection {
+    fn from(mock: MockTranslations) -> Self {
+        TranslationNodeCollection::new(vec![AssociatedTranslation::new(
+            MOCK_PATH,
+            TranslationNode::Object(mock.root),
+        )])
+    }
+}
@@ -0,0 +1,54 @@
+//! Language negotiation of `Accept-Language` headers
+//!
+//! This module picks the language a request is answered in from the ones an
+//! application is translated to, so every server integration shares the same
+//! negotiation.
+
+use translatable_shared::Language;
+
+/// Negotiates the language of an `Accept-Language` header
+///
+/// The ranges are tried by descending quality and in order for the same
+/// quality, reduced to their primary language so `es-AR` matches
+/// `Language::ES`. `*` matches the first available language, ranges with a
+/// zero quality are never matched.
+///
+/// # Arguments
+/// * `accept_language` - Value of the header, such as
+///   `"es-AR,es;q=0.9,*;q=0.1"`
+/// * `available` - Languages the application is translated to, by preference
+///
+/// # Returns
+/// The negotiated language, or `None` if no range is available.
+///
+/// # Example
+/// ```
+/// use translatable::{Language, negotiate_language};
+///
+/// let language = negotiate_language("fr-CH, de;q=0.9, es;q=0.8", &[Language::EN, Language::ES]);
+/// assert_eq!(language, Some(Language::ES));
+/// ```
+pub fn negotiate_language(accept_language: &str, available: &[Language]) -> Option<Language> {
+    let mut ranges = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let mut parameters = range.split(';').map(str::trim);
+            let tag = parameters.next().filter(|tag| !tag.is_empty())?;
+
+            let quality = parameters
+                .find_map(|parameter| parameter.strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+
+            Some((tag, quality))
+        })
+        .filter(|(_, quality)| *quality > 0.0)
+        .collect::<Vec<_>>();
+
+    // The sort is stable, ranges of the same quality keep their order
+    ranges.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+
+    ranges.into_iter().find_map(|(tag, _)| match tag {
+        "*" => available.first().cloned(),
+        tag => Language::from_locale(tag).filter(|language| available.contains(language)),
+    })
+}
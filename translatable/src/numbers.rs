@@ -0,0 +1,86 @@
+//! Locale-aware number formatting
+//!
+//! This module writes numeric argument values with the separators of a
+//! language, such as `1,234.5` in English or `1.234,5` in Spanish, from the
+//! CLDR data of the `num-format` crate.
+
+use std::fmt::Display;
+
+use num_format::{Grouping, Locale};
+use translatable_shared::Language;
+
+/// Formats a number with the separators of a language
+///
+/// The number is written with its `Display` output, which must be a decimal
+/// number such as `-1234.5`. Anything else is returned as it is, as are the
+/// numbers of languages without number data.
+///
+/// # Example
+/// ```
+/// use translatable::{Language, format_number};
+///
+/// assert_eq!(format_number(&Language::EN, &1234567.5), "1,234,567.5");
+/// assert_eq!(format_number(&Language::ES, &1234567.5), "1.234.567,5");
+/// ```
+pub fn format_number(language: &Language, number: &dyn Display) -> String {
+    let number = number.to_string();
+
+    localize(language, &number).unwrap_or(number)
+}
+
+/// Writes a decimal number with the separators of a language
+///
+/// # Returns
+/// `None` if the value isn't a decimal number or the language has no
+/// number data
+pub(crate) fn localize(language: &Language, number: &str) -> Option<String> {
+    let locale = Locale::from_name(language.code()).ok()?;
+
+    let (negative, number) = match number.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    let digits =
+        |digits: &str| !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit());
+    if !digits(integer) || !fraction.is_none_or(digits) {
+        return None;
+    }
+
+    let mut localized = String::new();
+
+    if negative {
+        localized.push_str(locale.minus_sign());
+    }
+
+    // Digit groups, from the most significant one
+    let mut groups = Vec::new();
+    let mut rest = integer;
+    let mut size = 3;
+
+    while rest.len() > size && locale.grouping() != Grouping::Posix {
+        let (head, group) = rest.split_at(rest.len() - size);
+        groups.push(group);
+        rest = head;
+
+        if locale.grouping() == Grouping::Indian {
+            size = 2;
+        }
+    }
+
+    groups.push(rest);
+    groups.reverse();
+    localized.push_str(&groups.join(locale.separator()));
+
+    if let Some(fraction) = fraction {
+        localized.push_str(locale.decimal());
+        localized.push_str(fraction);
+    }
+
+    Some(localized)
+}
@@ -0,0 +1,88 @@
+//! Runtime translation overrides
+//!
+//! This module loads an extra translation file at runtime, such as one
+//! mounted from a config map, whose translations take precedence over the
+//! process-wide ones. Copy can be fixed this way without a new build.
+
+use std::fs::read_to_string;
+use std::path::Path;
+use std::sync::RwLock;
+
+use translatable_shared::TranslationNodeCollection;
+
+use crate::AsyncTranslationProvider;
+use crate::loading::{LoadError, parse_translations};
+
+/// Translations looked up before the process-wide ones
+static OVERRIDES: RwLock<Option<&'static TranslationNodeCollection>> = RwLock::new(None);
+
+/// Loads a translation file whose translations take precedence over the
+/// process-wide ones
+///
+/// The file follows the same format as the translation files and is
+/// validated the same way, it replaces any override loaded before. Only
+/// [`Translator::new`](crate::Translator::new) translators look overrides
+/// up, per path and language, the macros always evaluate to the translations
+/// they were compiled with.
+///
+/// # Returns
+/// - `Ok(())` if the overrides were replaced
+/// - `Err(LoadError)` if the file is not valid, the current overrides are kept
+pub fn load_overrides(path: impl AsRef<Path>) -> Result<(), LoadError> {
+    let path = path.as_ref().to_string_lossy().to_string();
+    let content = read_to_string(&path).map_err(|err| LoadError::Io(err, path.clone()))?;
+
+    set_overrides(parse_translations(&content, &path)?);
+
+    Ok(())
+}
+
+/// Loads a translation file whose translations take precedence over the
+/// process-wide ones, like [`load_overrides`], without blocking
+///
+/// Only available with the `tokio` feature.
+#[cfg(feature = "tokio")]
+pub async fn load_overrides_async(path: impl AsRef<Path>) -> Result<(), LoadError> {
+    let path = path.as_ref().to_string_lossy().to_string();
+    let content =
+        tokio::fs::read_to_string(&path).await.map_err(|err| LoadError::Io(err, path.clone()))?;
+
+    set_overrides(parse_translations(&content, &path)?);
+
+    Ok(())
+}
+
+/// Replaces the overrides with the translations fetched by a provider
+///
+/// Meant to be called periodically, such as from a background task, so
+/// copy edited in the provider source is picked up without restarting.
+///
+/// # Returns
+/// - `Ok(())` if the overrides were replaced
+/// - `Err(error)` if the translations couldn't be fetched, the current
+///   overrides are kept
+pub async fn refresh_overrides<P: AsyncTranslationProvider>(provider: &P) -> Result<(), P::Error> {
+    set_overrides(provider.fetch().await?);
+
+    Ok(())
+}
+
+/// Sets translations taking precedence over the process-wide ones, like
+/// [`load_overrides`]
+///
+/// The translations are leaked so translators borrowing them stay valid
+/// after they are replaced, overrides are meant to be replaced rarely.
+pub fn set_overrides(translations: TranslationNodeCollection) {
+    *OVERRIDES.write().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Box::leak(Box::new(translations)));
+}
+
+/// Removes the overrides, looking up the process-wide translations only
+pub fn clear_overrides() {
+    *OVERRIDES.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Returns the overrides set with [`load_overrides`] or [`set_overrides`]
+pub(crate) fn overrides() -> Option<&'static TranslationNodeCollection> {
+    *OVERRIDES.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,279 @@
+//! Translation providers fetching catalogs at runtime
+//!
+//! This module defines the [`TranslationProvider`] and
+//! [`AsyncTranslationProvider`] traits, implemented by sources such as a CDN
+//! or a translation management system. The fetched collection is merged
+//! with the embedded translations before they are set, or set as overrides,
+//! while the macros keep their compile-time checks.
+//!
+//! The [`HttpProvider`] reference implementation is available with the
+//! `http` feature, and the [`SqlxProvider`] database one with the `sqlx`
+//! feature.
+
+use std::future::Future;
+
+use translatable_shared::TranslationNodeCollection;
+
+/// Source of translations fetched at runtime
+///
+/// # Example
+/// ```
+/// use std::convert::Infallible;
+///
+/// use translatable::{MockTranslations, TranslationNodeCollection, TranslationProvider};
+///
+/// struct Fixed;
+///
+/// impl TranslationProvider for Fixed {
+///     type Error = Infallible;
+///
+///     fn fetch(&self) -> Result<TranslationNodeCollection, Infallible> {
+///         Ok(MockTranslations::new().translation("title", translatable::Language::EN, "Home").build())
+///     }
+/// }
+///
+/// assert!(Fixed.fetch().unwrap().find_path("title").is_some());
+/// ```
+pub trait TranslationProvider {
+    /// Error returned when the translations can't be fetched
+    type Error;
+
+    /// Fetches the translations, blocking until they are available
+    fn fetch(&self) -> Result<TranslationNodeCollection, Self::Error>;
+}
+
+/// Source of translations fetched at runtime without blocking
+///
+/// The future must not block the executor thread polling it.
+pub trait AsyncTranslationProvider {
+    /// Error returned when the translations can't be fetched
+    type Error;
+
+    /// Fetches the translations
+    fn fetch(&self) -> impl Future<Output = Result<TranslationNodeCollection, Self::Error>> + Send;
+}
+
+#[cfg(feature = "http")]
+pub use http::HttpProvider;
+#[cfg(feature = "sqlx")]
+pub use sqlx::SqlxProvider;
+
+/// Reference provider fetching a translation file over HTTP
+#[cfg(feature = "http")]
+mod http {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+
+    use translatable_shared::TranslationNodeCollection;
+
+    use super::{AsyncTranslationProvider, TranslationProvider};
+    use crate::loading::{LoadError, parse_translations};
+
+    /// Provider fetching a translation file over HTTP
+    ///
+    /// The response body must be a translation file, validated the same way
+    /// as the ones in the translations directory. Failed requests and
+    /// non-success statuses return `LoadError::Http`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use translatable::{HttpProvider, Precedence, TranslationProvider, embed_translations};
+    ///
+    /// let remote = HttpProvider::new("https://cdn.example.com/translations/app.toml")
+    ///     .header("Authorization", "Bearer token")
+    ///     .fetch()?;
+    ///
+    /// translatable::set_translations(embed_translations!().inherit(remote, Precedence::Dependency));
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct HttpProvider {
+        /// URL of the translation file
+        url: String,
+        /// Headers sent with the request
+        headers: Vec<(String, String)>,
+    }
+
+    impl HttpProvider {
+        /// Creates a provider fetching a translation file URL
+        pub fn new(url: impl Into<String>) -> Self {
+            Self { url: url.into(), headers: Vec::new() }
+        }
+
+        /// Adds a header sent with the request, such as an authorization token
+        pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+            self.headers.push((name.into(), value.into()));
+            self
+        }
+    }
+
+    impl TranslationProvider for HttpProvider {
+        type Error = LoadError;
+
+        fn fetch(&self) -> Result<TranslationNodeCollection, LoadError> {
+            let http_error = |err: ureq::Error| LoadError::Http(err.to_string(), self.url.clone());
+
+            let request = self
+                .headers
+                .iter()
+                .fold(ureq::get(&self.url), |request, (name, value)| request.header(name, value));
+
+            let content = request
+                .call()
+                .map_err(http_error)?
+                .body_mut()
+                .read_to_string()
+                .map_err(http_error)?;
+
+            parse_translations(&content, &self.url)
+        }
+    }
+
+    /// The request runs on its own thread, so the future doesn't depend on
+    /// a specific async runtime
+    impl AsyncTranslationProvider for HttpProvider {
+        type Error = LoadError;
+
+        fn fetch(
+            &self,
+        ) -> impl Future<Output = Result<TranslationNodeCollection, LoadError>> + Send {
+            let provider = self.clone();
+
+            Blocking::new(move || TranslationProvider::fetch(&provider))
+        }
+    }
+
+    /// Result of a [`Blocking`] task and the waker of its future
+    type Shared<T> = Arc<Mutex<(Option<T>, Option<Waker>)>>;
+
+    /// Future running a blocking task on its own thread once polled
+    struct Blocking<T> {
+        /// Task to spawn, `None` once spawned
+        task: Option<Box<dyn FnOnce() -> T + Send>>,
+        /// Result of the task and the waker of the last poll
+        shared: Shared<T>,
+    }
+
+    impl<T> Blocking<T> {
+        /// Wraps a blocking task
+        fn new(task: impl FnOnce() -> T + Send + 'static) -> Self {
+            Self {
+                task: Some(Box::new(task)),
+                shared: Arc::new(Mutex::new((None, None))),
+            }
+        }
+    }
+
+    impl<T: Send + 'static> Future for Blocking<T> {
+        type Output = T;
+
+        fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<T> {
+            let this = self.get_mut();
+
+            {
+                let mut shared =
+                    this.shared.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                if let Some(result) = shared.0.take() {
+                    return Poll::Ready(result);
+                }
+
+                shared.1 = Some(context.waker().clone());
+            }
+
+            if let Some(task) = this.task.take() {
+                let shared = Arc::clone(&this.shared);
+
+                thread::spawn(move || {
+                    let result = task();
+                    let mut shared = shared.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                    shared.0 = Some(result);
+                    if let Some(waker) = shared.1.take() {
+                        waker.wake();
+                    }
+                });
+            }
+
+            Poll::Pending
+        }
+    }
+}
+
+/// Provider querying translation rows from a database
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use sqlx::AnyPool;
+    use translatable_shared::TranslationNodeCollection;
+
+    use super::AsyncTranslationProvider;
+    use crate::loading::{LoadError, parse_rows};
+
+    /// Query of the default translations table
+    const DEFAULT_QUERY: &str = "SELECT path, language, translation FROM translations";
+
+    /// Provider querying translation rows from a database, such as the ones
+    /// edited through an admin interface
+    ///
+    /// Every row has the dot-separated path, the language and the
+    /// translation as text columns, in this order. By default they are read
+    /// from the `path`, `language` and `translation` columns of the
+    /// `translations` table, and the rows are validated the same way as the
+    /// translation files.
+    ///
+    /// The pool is an [`AnyPool`], so the application enables the sqlx
+    /// driver and runtime features of its database.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use translatable::{AsyncTranslationProvider, SqlxProvider};
+    ///
+    /// sqlx::any::install_default_drivers();
+    /// let pool = sqlx::AnyPool::connect("postgres://localhost/app").await?;
+    ///
+    /// let edited = SqlxProvider::new(pool)
+    ///     .query("SELECT key, lang, copy FROM marketing_copy WHERE published")
+    ///     .fetch()
+    ///     .await?;
+    ///
+    /// translatable::set_overrides(edited);
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct SqlxProvider {
+        /// Pool the rows are queried from
+        pool: AnyPool,
+        /// Query selecting the path, language and translation columns
+        query: String,
+    }
+
+    impl SqlxProvider {
+        /// Creates a provider reading the default translations table
+        pub fn new(pool: AnyPool) -> Self {
+            Self { pool, query: DEFAULT_QUERY.to_string() }
+        }
+
+        /// Sets the query selecting the path, language and translation
+        /// columns
+        ///
+        /// The query is sent as it is, it must not contain user input.
+        pub fn query(mut self, query: impl Into<String>) -> Self {
+            self.query = query.into();
+            self
+        }
+    }
+
+    impl AsyncTranslationProvider for SqlxProvider {
+        type Error = LoadError;
+
+        async fn fetch(&self) -> Result<TranslationNodeCollection, LoadError> {
+            let rows = sqlx::query_as::<_, (String, String, String)>(&self.query)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|err| LoadError::Database(err.to_string(), self.query.clone()))?;
+
+            parse_rows(rows, &self.query)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Random entry selection
+//!
+//! This module picks one of the entries of array-valued translations, such as
+//! the greetings of a game character, with a per thread generator that tests
+//! can seed to get the same entries on every run.
+
+use std::cell::Cell;
+use std::hash::{BuildHasher, RandomState};
+
+use translatable_shared::{has_random_entries, random_entries};
+
+thread_local! {
+    /// State of the generator of the thread, seeded on the first pick
+    static STATE: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Seeds the generator picking the random entries of translations on the
+/// current thread
+///
+/// The same seed picks the same entries in the same order, so tests can
+/// assert on translations with random entries.
+///
+/// # Example
+/// ```
+/// translatable::set_random_seed(42);
+/// ```
+pub fn set_random_seed(seed: u64) {
+    STATE.with(|state| state.set(Some(seed)));
+}
+
+/// Picks one of the entries of a translation at random
+///
+/// # Returns
+/// One of the entries, or the translation itself if it has none
+pub fn pick_entry(translation: &str) -> &str {
+    if !has_random_entries(translation) {
+        return translation;
+    }
+
+    let entries = random_entries(translation);
+    let index = next_random() % entries.clone().count() as u64;
+
+    entries.clone().nth(index as usize).unwrap_or(translation)
+}
+
+/// Advances the generator of the current thread, a SplitMix64 one
+fn next_random() -> u64 {
+    STATE.with(|state| {
+        let seed = state.get().unwrap_or_else(|| RandomState::new().hash_one(0));
+        let next = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state.set(Some(next));
+
+        let mut value = next;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    })
+}
@@ -0,0 +1,117 @@
+//! Localized URL slugs
+//!
+//! This module provides the type
+//! [`translation_slugs!`](crate::translation_slugs) evaluates to, mapping
+//! route keys to their slug in every language so localized websites can
+//! resolve and build their paths, such as `/es/ajustes` and `/en/settings`.
+
+use std::borrow::Cow;
+
+use translatable_shared::Language;
+
+/// Slugs of the routes declared in the translation files, checked to be
+/// unique per language at compile time
+///
+/// Localized paths start with the language code followed by a slug per
+/// segment, segments that aren't slugs, such as identifiers, are kept as
+/// they are.
+///
+/// # Example
+/// ```ignore
+/// static SLUGS: Slugs = translatable::translation_slugs!("routes");
+///
+/// assert_eq!(SLUGS.translate("/es/ajustes", &Language::EN).unwrap(), "/en/settings");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Slugs {
+    /// Route keys with their slug per language, sorted by key
+    routes: &'static [(&'static str, &'static [(Language, &'static str)])],
+}
+
+impl Slugs {
+    /// Creates the slugs, called by the generated code
+    #[doc(hidden)]
+    pub const fn new(
+        routes: &'static [(&'static str, &'static [(Language, &'static str)])],
+    ) -> Self {
+        Self { routes }
+    }
+
+    /// Gets the slug of a route key in a language
+    pub fn slug(&self, key: &str, language: &Language) -> Option<&'static str> {
+        self.routes
+            .iter()
+            .find(|(route, _)| *route == key)?
+            .1
+            .iter()
+            .find_map(|(slug_language, slug)| (slug_language == language).then_some(*slug))
+    }
+
+    /// Finds the route key of a slug in a language
+    pub fn key(&self, slug: &str, language: &Language) -> Option<&'static str> {
+        self.routes.iter().find_map(|(route, slugs)| {
+            slugs
+                .iter()
+                .any(|(slug_language, route_slug)| slug_language == language && *route_slug == slug)
+                .then_some(*route)
+        })
+    }
+
+    /// Builds the localized path of some route segments
+    ///
+    /// Segments that aren't route keys are written as they are.
+    ///
+    /// # Returns
+    /// `None` if a route key has no slug in the language.
+    pub fn path<'a>(
+        &self,
+        language: &Language,
+        segments: impl IntoIterator<Item = &'a str>,
+    ) -> Option<String> {
+        let mut path = format!("/{}", language.code());
+
+        for segment in segments {
+            let slug = match self.routes.iter().any(|(route, _)| *route == segment) {
+                true => self.slug(segment, language)?,
+                false => segment,
+            };
+
+            path.push('/');
+            path.push_str(slug);
+        }
+
+        Some(path)
+    }
+
+    /// Resolves a localized path into its language and route segments
+    ///
+    /// Slugs are replaced with their route keys, other segments are kept as
+    /// they are.
+    ///
+    /// # Returns
+    /// `None` if the path doesn't start with a language code.
+    pub fn resolve<'a>(&self, path: &'a str) -> Option<(Language, Vec<&'a str>)> {
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+        let language = segments.next()?.parse::<Language>().ok()?;
+
+        let segments =
+            segments.map(|segment| self.key(segment, &language).unwrap_or(segment)).collect();
+
+        Some((language, segments))
+    }
+
+    /// Translates a localized path into another language
+    ///
+    /// # Returns
+    /// `None` if the path doesn't start with a language code, or a route has
+    /// no slug in the language.
+    pub fn translate<'a>(&self, path: &'a str, language: &Language) -> Option<Cow<'a, str>> {
+        let (current, segments) = self.resolve(path)?;
+
+        if current == *language {
+            return Some(Cow::Borrowed(path));
+        }
+
+        self.path(language, segments).map(Cow::Owned)
+    }
+}
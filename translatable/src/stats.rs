@@ -0,0 +1,105 @@
+//! Translation coverage statistics
+//!
+//! This module provides the types
+//! [`translation_stats!`](crate::translation_stats) evaluates to, computed at
+//! compile time from the translation files.
+
+use crate::Language;
+
+/// Coverage statistics of the translation files
+///
+/// # Example
+/// ```ignore
+/// let stats = translatable::translation_stats!();
+///
+/// for language in stats.languages() {
+///     println!("{}: {:.1}%", language.language().code(), language.completion());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranslationStats {
+    /// Number of translation paths
+    total_keys: usize,
+    /// Languages in use, sorted by code
+    languages: &'static [LanguageStats],
+    /// Missing language and path pairs, sorted by path
+    missing: &'static [(Language, &'static str)],
+}
+
+impl TranslationStats {
+    /// Creates the statistics, called by the generated code
+    #[doc(hidden)]
+    pub const fn new(
+        total_keys: usize,
+        languages: &'static [LanguageStats],
+        missing: &'static [(Language, &'static str)],
+    ) -> Self {
+        Self { total_keys, languages, missing }
+    }
+
+    /// Gets the number of translation paths
+    pub fn total_keys(&self) -> usize {
+        self.total_keys
+    }
+
+    /// Gets the statistics of every language found in any translation,
+    /// sorted by code
+    pub fn languages(&self) -> &'static [LanguageStats] {
+        self.languages
+    }
+
+    /// Gets the statistics of a single language
+    pub fn language(&self, language: &Language) -> Option<&'static LanguageStats> {
+        self.languages.iter().find(|stats| stats.language == *language)
+    }
+
+    /// Gets the paths missing one of the languages in use, sorted by path
+    pub fn missing(&self) -> &'static [(Language, &'static str)] {
+        self.missing
+    }
+
+    /// Gets the percentage of translated language and path pairs
+    pub fn completion(&self) -> f64 {
+        let total = self.total_keys * self.languages.len();
+        percentage(total - self.missing.len(), total)
+    }
+}
+
+/// Coverage statistics of a single language
+#[derive(Clone, Debug)]
+pub struct LanguageStats {
+    /// Language the statistics are for
+    language: Language,
+    /// Number of paths translated to the language
+    translated: usize,
+    /// Number of translation paths
+    total_keys: usize,
+}
+
+impl LanguageStats {
+    /// Creates the language statistics, called by the generated code
+    #[doc(hidden)]
+    pub const fn new(language: Language, translated: usize, total_keys: usize) -> Self {
+        Self { language, translated, total_keys }
+    }
+
+    /// Gets the language the statistics are for
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Gets the number of paths translated to the language
+    pub fn translated(&self) -> usize {
+        self.translated
+    }
+
+    /// Gets the percentage of paths translated to the language
+    pub fn completion(&self) -> f64 {
+        percentage(self.translated, self.total_keys)
+    }
+}
+
+/// Computes a percentage, an empty total counts as complete
+fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 { 100.0 } else { part as f64 / total as f64 * 100.0 }
+}
@@ -0,0 +1,107 @@
+//! Tauri commands serving the translations to the webview
+//!
+//! This module provides commands resolving against the process-wide
+//! translations for a [`Locale`] managed by the app, so the webview is served
+//! the same catalog the macros validate instead of a copy of it.
+//!
+//! The commands are re-exported at the crate root, where
+//! `tauri::generate_handler!` finds the wrappers they generate.
+//!
+//! ```ignore
+//! tauri::Builder::default()
+//!     .setup(|app| {
+//!         translatable::tauri::manage_locale(app.handle(), Locale::new(Language::EN));
+//!         Ok(())
+//!     })
+//!     .invoke_handler(tauri::generate_handler![
+//!         translatable::get_translation,
+//!         translatable::get_catalog,
+//!         translatable::set_locale,
+//!     ])
+//! ```
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use ::tauri::{AppHandle, Emitter, Manager, Runtime, State, command};
+use translatable_shared::Language;
+
+use crate::internal::invalid_language;
+use crate::{Locale, Translator};
+
+/// Event emitted to the webviews with the code of every new language of the
+/// managed locale
+pub const LOCALE_CHANGED: &str = "translatable://locale-changed";
+
+/// Manages the locale the commands resolve for
+///
+/// Every [`Locale::set_language`], through [`set_locale`] or the app itself,
+/// emits [`LOCALE_CHANGED`] so the webviews can fetch their strings again.
+///
+/// # Returns
+/// `false` if a locale was already managed, which is kept.
+pub fn manage_locale<R: Runtime>(app: &AppHandle<R>, locale: Locale) -> bool {
+    let handle = app.clone();
+
+    // Emitting only fails without webviews to emit to
+    locale.subscribe(move |language| {
+        let _ = handle.emit(LOCALE_CHANGED, language.code());
+    });
+
+    app.manage(locale)
+}
+
+/// Parses a language like `FromStr`, the configured aliases are not resolved
+fn parse_language(language: String) -> Result<Language, String> {
+    language.parse().map_err(|_| invalid_language(language).to_string())
+}
+
+/// Resolves a dot-separated path for the managed locale, replacing its
+/// templates with the given arguments
+///
+/// # Returns
+/// - `Ok(translation)` with the templates replaced
+/// - `Err(message)` with the `Display` output of the [`Error`]
+///
+/// [`Error`]: crate::Error
+#[command]
+pub fn get_translation(
+    locale: State<'_, Locale>,
+    path: String,
+    arguments: Option<HashMap<String, String>>,
+) -> Result<String, String> {
+    let arguments = arguments.unwrap_or_default();
+    let arguments = arguments
+        .iter()
+        .map(|(name, value)| (name.as_str(), value as &dyn Display))
+        .collect::<Vec<_>>();
+
+    locale
+        .translator()
+        .get_with(&path, &arguments)
+        .map(Cow::into_owned)
+        .map_err(|error| error.to_string())
+}
+
+/// Collects the raw translations of a language, keyed by dot-separated path,
+/// see [`Translator::catalog`]
+///
+/// # Returns
+/// - `Ok(catalog)` with the translations of the language
+/// - `Err(message)` if the language code is invalid
+#[command]
+pub fn get_catalog(language: String) -> Result<BTreeMap<String, &'static str>, String> {
+    Ok(Translator::new(parse_language(language)?).catalog())
+}
+
+/// Switches the language of the managed locale
+///
+/// # Returns
+/// - `Ok(())` if the language was switched
+/// - `Err(message)` if the language code is invalid
+#[command]
+pub fn set_locale(locale: State<'_, Locale>, language: String) -> Result<(), String> {
+    locale.set_language(parse_language(language)?);
+    Ok(())
+}
@@ -0,0 +1,258 @@
+//! Test helpers for translation macro diagnostics and runtime state
+//!
+//! This module wraps [`trybuild`] compile-fail checks, running them against
+//! fixture translation directories and temporary `translatable.toml`
+//! configurations instead of the ones of the crate under test.
+//!
+//! It also overrides the process-wide translations and missing translation
+//! hook, which can only be set once otherwise, and resets them back.
+//!
+//! Only available with the `test-support` feature.
+
+use std::env::{self, temp_dir};
+use std::ffi::OsString;
+use std::fs::{create_dir_all, write};
+use std::path::{Path, PathBuf, absolute};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock};
+
+use translatable_shared::TranslationNodeCollection;
+
+use crate::{Error, MissingTranslationHook};
+
+/// Translations preferred over the ones set with
+/// [`set_translations`](crate::set_translations)
+static TRANSLATIONS_OVERRIDE: RwLock<Option<&'static TranslationNodeCollection>> =
+    RwLock::new(None);
+
+/// Hook preferred over the one set with
+/// [`set_missing_translation_hook`](crate::set_missing_translation_hook)
+static MISSING_TRANSLATION_HOOK_OVERRIDE: RwLock<Option<&'static MissingTranslationHook>> =
+    RwLock::new(None);
+
+/// Overrides the process-wide translations used by
+/// [`Translator::new`](crate::Translator::new)
+///
+/// The translations are leaked so translators borrowing them stay valid
+/// after a [`reset`], overriding is meant for tests only.
+pub fn override_translations(translations: TranslationNodeCollection) {
+    *TRANSLATIONS_OVERRIDE.write().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Box::leak(Box::new(translations)));
+}
+
+/// Overrides the process-wide missing translation hook
+///
+/// The hook is leaked like [`override_translations`] translations, the one
+/// set with [`set_missing_translation_hook`](crate::set_missing_translation_hook)
+/// isn't invoked while overridden.
+pub fn override_missing_translation_hook(hook: impl Fn(&Error) + Send + Sync + 'static) {
+    let hook: MissingTranslationHook = Box::new(hook);
+
+    *MISSING_TRANSLATION_HOOK_OVERRIDE.write().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Box::leak(Box::new(hook)));
+}
+
+/// Removes the overrides, restoring the translations and hook set once for
+/// the process, along with the ones loaded with
+/// [`load_overrides`](crate::load_overrides)
+///
+/// The overrides are process-wide, tests relying on them must not run
+/// concurrently with each other.
+pub fn reset() {
+    *TRANSLATIONS_OVERRIDE.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    *MISSING_TRANSLATION_HOOK_OVERRIDE.write().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        None;
+    crate::clear_overrides();
+}
+
+/// Returns the translations set with [`override_translations`]
+pub(crate) fn translations_override() -> Option<&'static TranslationNodeCollection> {
+    *TRANSLATIONS_OVERRIDE.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Returns the hook set with [`override_missing_translation_hook`]
+pub(crate) fn missing_translation_hook_override() -> Option<&'static MissingTranslationHook> {
+    *MISSING_TRANSLATION_HOOK_OVERRIDE.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Number of embedded collections decoded by the process
+static EMBEDDED_DECODES: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts a decode of the translations embedded by
+/// [`embed_translations!`](crate::embed_translations)
+pub(crate) fn count_embedded_decode() {
+    EMBEDDED_DECODES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns the number of times the process decoded the translations
+/// embedded by [`embed_translations!`](crate::embed_translations)
+///
+/// Every expansion decodes them once, however many times it's evaluated.
+pub fn embedded_decodes() -> usize {
+    EMBEDDED_DECODES.load(Ordering::Relaxed)
+}
+
+/// Serializes the runs, as the configuration is passed through environment
+/// variables
+static RUN_LOCK: Mutex<()> = Mutex::new(());
+
+/// Counter for unique temporary configuration directories
+static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Environment variables set for the macros while the checks run
+const ENVIRONMENT: [&str; 2] = ["TRANSLATABLE_CONFIG", "TRANSLATABLE_LOCALES_PATH"];
+
+/// Compile-fail checks for the translation macros
+///
+/// Every case is a file that must fail to compile with the diagnostics in
+/// the `.stderr` file next to it, as with
+/// [`trybuild::TestCases::compile_fail`]. Pass cases must compile and run
+/// successfully instead, as with [`trybuild::TestCases::pass`], to check the
+/// runtime behavior under a configuration.
+///
+/// # Example
+/// ```ignore
+/// #[test]
+/// fn diagnostics() {
+///     // No other test of this binary accesses the environment
+///     unsafe {
+///         CompileFail::new()
+///             .translations("tests/fixtures/translations")
+///             .config(r#"missing_arguments = "error""#)
+///             .case("tests/ui/*.rs")
+///             .run();
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct CompileFail {
+    /// Case file paths or globs
+    cases: Vec<PathBuf>,
+    /// Pass case file paths or globs
+    pass_cases: Vec<PathBuf>,
+    /// Fixture translation directory
+    translations: Option<PathBuf>,
+    /// Temporary `translatable.toml` content
+    config: Option<String>,
+}
+
+impl CompileFail {
+    /// Creates the checks, using an empty configuration and the default
+    /// translation directory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a case file path, globs are supported
+    pub fn case(mut self, path: impl AsRef<Path>) -> Self {
+        self.cases.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds a pass case file path, globs are supported
+    pub fn pass(mut self, path: impl AsRef<Path>) -> Self {
+        self.pass_cases.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the translation directory, overriding the configured `path`
+    pub fn translations(mut self, path: impl AsRef<Path>) -> Self {
+        self.translations = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the `translatable.toml` content
+    pub fn config(mut self, config: impl Into<String>) -> Self {
+        self.config = Some(config.into());
+        self
+    }
+
+    /// Runs the checks
+    ///
+    /// The configuration is passed to the macros through environment
+    /// variables, which are set while the checks run.
+    ///
+    /// # Safety
+    /// Runs are serialized with each other, but no other thread of the
+    /// process may read or write the environment while the checks run, as
+    /// with [`env::set_var`].
+    ///
+    /// # Panics
+    /// If any case compiles or its diagnostics don't match, any pass case
+    /// doesn't compile or fails, or the temporary configuration can't be
+    /// written.
+    pub unsafe fn run(self) {
+        let _lock = RUN_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let directory = temp_dir().join(format!(
+            "translatable-test-support-{}-{}",
+            process::id(),
+            RUN_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let config = directory.join("translatable.toml");
+
+        create_dir_all(&directory).expect("Couldn't create the temporary configuration directory");
+        write(&config, self.config.unwrap_or_default())
+            .expect("Couldn't write the temporary configuration");
+
+        let translations = self.translations.map(|translations| {
+            absolute(translations).expect("Couldn't resolve the translations directory")
+        });
+
+        // Restored even if the checks fail
+        let _environment = EnvironmentGuard::set([
+            Some(config.into_os_string()),
+            translations.map(PathBuf::into_os_string),
+        ]);
+
+        let cases = trybuild::TestCases::new();
+        for case in &self.cases {
+            cases.compile_fail(case);
+        }
+
+        for case in &self.pass_cases {
+            cases.pass(case);
+        }
+
+        // The cases run when dropped
+        drop(cases);
+    }
+}
+
+/// Sets the macro environment variables, restoring them when dropped
+struct EnvironmentGuard {
+    /// Previous values of [`ENVIRONMENT`]
+    previous: [Option<OsString>; 2],
+}
+
+impl EnvironmentGuard {
+    /// Sets the environment variables, `None` values are removed
+    fn set(values: [Option<OsString>; 2]) -> Self {
+        let previous = ENVIRONMENT.map(env::var_os);
+        Self::apply(values);
+
+        Self { previous }
+    }
+
+    /// Sets or removes the values of [`ENVIRONMENT`]
+    fn apply(values: [Option<OsString>; 2]) {
+        for (key, value) in ENVIRONMENT.iter().zip(values) {
+            // SAFETY: runs are serialized by `RUN_LOCK`, and the caller of
+            // `CompileFail::run` guarantees no other thread accesses the
+            // environment meanwhile.
+            unsafe {
+                match value {
+                    Some(value) => env::set_var(key, value),
+                    None => env::remove_var(key),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for EnvironmentGuard {
+    fn drop(&mut self) {
+        Self::apply(std::mem::take(&mut self.previous));
+    }
+}
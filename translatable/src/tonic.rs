@@ -0,0 +1,118 @@
+//! gRPC locale extraction for tonic services
+//!
+//! This module provides the [`LanguageScopeLayer`], which negotiates the
+//! language of every gRPC request from its metadata like
+//! [`LocaleLayer`](crate::tower::LocaleLayer), and installs it as a task-local
+//! for the whole request, so error details and messages deep in a service can
+//! be localized without threading the language through every call.
+
+use std::future::Future;
+use std::task::{Context, Poll};
+
+use ::http::Request;
+use tokio::task::futures::TaskLocalFuture;
+use tower_layer::Layer;
+use tower_service::Service;
+use translatable_shared::Language;
+
+use crate::tower::LocaleLayer;
+
+tokio::task_local! {
+    /// Language negotiated for the request being served
+    static REQUEST_LANGUAGE: Language;
+}
+
+/// Gets the language negotiated for the request being served
+///
+/// # Returns
+/// `None` outside of a [`LanguageScopeLayer`] or [`scope_language`] future.
+///
+/// # Example
+/// ```ignore
+/// let language = current_language().unwrap_or(Language::EN);
+/// Err(Status::not_found(errors::user_not_found(language, id).to_string()))
+/// ```
+pub fn current_language() -> Option<Language> {
+    REQUEST_LANGUAGE.try_with(Clone::clone).ok()
+}
+
+/// Runs a future with a language as the one of the request being served,
+/// e.g. for tasks spawned by a service
+pub async fn scope_language<F: Future>(language: Language, future: F) -> F::Output {
+    REQUEST_LANGUAGE.scope(language, future).await
+}
+
+/// Gets the language negotiated for a gRPC request, from its extensions or
+/// the task-local language
+pub fn request_language<T>(request: &::tonic::Request<T>) -> Option<Language> {
+    request.extensions().get::<Language>().cloned().or_else(current_language)
+}
+
+/// Layer negotiating the language of every request from its metadata,
+/// inserting it into the request extensions and installing it as the
+/// task-local language while the request is served
+///
+/// The metadata is negotiated like HTTP headers, either from the
+/// `accept-language` key or from a custom one set with
+/// [`LocaleLayer::header`].
+///
+/// # Example
+/// ```ignore
+/// let locale = LocaleLayer::new(Language::EN, [Language::EN, Language::ES])
+///     .header(HeaderName::from_static("x-language"));
+///
+/// Server::builder()
+///     .layer(LanguageScopeLayer::from(locale))
+///     .add_service(GreeterServer::new(greeter))
+///     .serve(address)
+///     .await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct LanguageScopeLayer {
+    /// Negotiation of the request language
+    layer: LocaleLayer,
+}
+
+impl From<LocaleLayer> for LanguageScopeLayer {
+    fn from(layer: LocaleLayer) -> Self {
+        Self { layer }
+    }
+}
+
+impl<S> Layer<S> for LanguageScopeLayer {
+    type Service = LanguageScopeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LanguageScopeService { inner, layer: self.layer.clone() }
+    }
+}
+
+/// Service installing the negotiated language while its requests are
+/// served, created by [`LanguageScopeLayer`]
+#[derive(Clone, Debug)]
+pub struct LanguageScopeService<S> {
+    /// Wrapped service
+    inner: S,
+    /// Negotiation of the request language
+    layer: LocaleLayer,
+}
+
+impl<S, B> Service<Request<B>> for LanguageScopeService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = TaskLocalFuture<Language, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        let language = self.layer.negotiate(request.headers());
+        request.extensions_mut().insert(language.clone());
+
+        REQUEST_LANGUAGE.scope(language, self.inner.call(request))
+    }
+}
@@ -0,0 +1,134 @@
+//! Tower layer negotiating the language of HTTP requests
+//!
+//! This module provides the [`LocaleLayer`], which negotiates the language of
+//! every request from its headers and inserts it into the request
+//! extensions, so any stack built on `tower` and `http`, such as axum, warp,
+//! tonic or plain hyper, localizes its responses the same way.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ::http::header::ACCEPT_LANGUAGE;
+use ::http::{HeaderMap, HeaderName, Request};
+use tower_layer::Layer;
+use tower_service::Service;
+use translatable_shared::Language;
+
+use crate::negotiate_language;
+
+/// Negotiation settings shared by the layer and its services
+#[derive(Clone, Debug)]
+struct Negotiation {
+    /// Language used if none is negotiated
+    default: Language,
+    /// Languages the application is translated to, by preference
+    available: Vec<Language>,
+    /// Header naming the language explicitly, consulted first
+    header: Option<HeaderName>,
+}
+
+/// Layer inserting the negotiated [`Language`] into the extensions of every
+/// request
+///
+/// # Example
+/// ```ignore
+/// let app = Router::new()
+///     .route("/", get(handler))
+///     .layer(LocaleLayer::new(Language::EN, [Language::EN, Language::ES]));
+///
+/// async fn handler(Extension(language): Extension<Language>) -> String {
+///     common::greeting(language, "john").to_string()
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LocaleLayer {
+    /// Negotiation settings, shared with the services
+    negotiation: Arc<Negotiation>,
+}
+
+impl LocaleLayer {
+    /// Creates a layer negotiating among the available languages, by
+    /// preference, falling back to a default one
+    pub fn new(default: Language, available: impl IntoIterator<Item = Language>) -> Self {
+        Self {
+            negotiation: Arc::new(Negotiation {
+                default,
+                available: available.into_iter().collect(),
+                header: None,
+            }),
+        }
+    }
+
+    /// Sets a header naming the language explicitly, e.g. `x-language: es`,
+    /// which takes precedence over `Accept-Language` if available
+    pub fn header(mut self, header: HeaderName) -> Self {
+        Arc::make_mut(&mut self.negotiation).header = Some(header);
+        self
+    }
+
+    /// Negotiates the language of some request headers
+    ///
+    /// The explicit header is consulted first, then every `Accept-Language`
+    /// header, see [`negotiate_language`].
+    pub fn negotiate(&self, headers: &HeaderMap) -> Language {
+        let Negotiation { default, available, header } = &*self.negotiation;
+
+        let explicit = header
+            .as_ref()
+            .and_then(|header| headers.get(header))
+            .and_then(|value| value.to_str().ok())
+            .and_then(Language::from_locale)
+            .filter(|language| available.contains(language));
+
+        explicit
+            .or_else(|| {
+                let accept_language = headers
+                    .get_all(ACCEPT_LANGUAGE)
+                    .iter()
+                    .filter_map(|value| value.to_str().ok())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                negotiate_language(&accept_language, available)
+            })
+            .unwrap_or_else(|| default.clone())
+    }
+}
+
+impl<S> Layer<S> for LocaleLayer {
+    type Service = LocaleService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LocaleService { inner, layer: self.clone() }
+    }
+}
+
+/// Service inserting the negotiated [`Language`] into the request
+/// extensions, created by [`LocaleLayer`]
+#[derive(Clone, Debug)]
+pub struct LocaleService<S> {
+    /// Wrapped service
+    inner: S,
+    /// Layer the service was created by
+    layer: LocaleLayer,
+}
+
+impl<S, B> Service<Request<B>> for LocaleService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        let language = self.layer.negotiate(request.headers());
+        request.extensions_mut().insert(language);
+
+        self.inner.call(request)
+    }
+}
@@ -0,0 +1,426 @@
+//! Locale-bound runtime translation lookup
+//!
+//! This module provides the [`Translator`] handle, which resolves dynamic
+//! paths for a language that has already been validated, against either the
+//! process-wide translations or an explicit collection.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+#[cfg(not(feature = "tokio"))]
+use std::sync::OnceLock;
+
+use translatable_shared::{
+    Language, TranslationNode, TranslationNodeCollection, VALUES_KEY, VARIANT_SEPARATOR,
+    variant_path,
+};
+
+use crate::hook::missing_translation;
+use crate::instrument::lookup as trace_lookup;
+use crate::overrides::overrides;
+use crate::random::pick_entry;
+use crate::{Error, Formatted, TranslationValue};
+
+/// Process-wide translations used by [`Translator::new`]
+#[cfg(not(feature = "tokio"))]
+static TRANSLATIONS: OnceLock<TranslationNodeCollection> = OnceLock::new();
+
+/// Process-wide translations used by [`Translator::new`], which concurrent
+/// [`init_translations`] calls wait for without blocking
+#[cfg(feature = "tokio")]
+static TRANSLATIONS: tokio::sync::OnceCell<TranslationNodeCollection> =
+    tokio::sync::OnceCell::const_new();
+
+/// Sets the process-wide translations used by [`Translator::new`]
+///
+/// Usually called once at startup with the output of
+/// [`embed_translations!`](crate::embed_translations).
+///
+/// # Returns
+/// - `Ok(())` if the translations were set
+/// - `Err(translations)` if they were already set
+pub fn set_translations(
+    translations: TranslationNodeCollection,
+) -> Result<(), TranslationNodeCollection> {
+    #[cfg(feature = "tokio")]
+    return TRANSLATIONS.set(translations).map_err(|err| match err {
+        tokio::sync::SetError::AlreadyInitializedError(translations)
+        | tokio::sync::SetError::InitializingError(translations) => translations,
+    });
+
+    #[cfg(not(feature = "tokio"))]
+    TRANSLATIONS.set(translations)
+}
+
+/// Sets the process-wide translations used by [`Translator::new`] from an
+/// asynchronous initialization, such as fetching them from a provider
+///
+/// The initialization only runs once, concurrent calls wait for it without
+/// blocking their executor thread and a failed initialization can be
+/// retried. Only available with the `tokio` feature.
+///
+/// # Returns
+/// - `Ok(translations)` with the translations set, by this or an earlier call
+/// - `Err(error)` if the initialization failed
+#[cfg(feature = "tokio")]
+pub async fn init_translations<F, E>(
+    init: impl FnOnce() -> F,
+) -> Result<&'static TranslationNodeCollection, E>
+where
+    F: Future<Output = Result<TranslationNodeCollection, E>>,
+{
+    TRANSLATIONS.get_or_try_init(init).await
+}
+
+/// Returns the process-wide translations, preferring the test-support
+/// override
+fn process_translations<'a>() -> Option<&'a TranslationNodeCollection> {
+    #[cfg(feature = "test-support")]
+    if let Some(translations) = crate::test_support::translations_override() {
+        return Some(translations);
+    }
+
+    TRANSLATIONS.get()
+}
+
+/// Locale-bound translation handle
+///
+/// Binds a validated [`Language`] once, so code performing many lookups for
+/// the same user doesn't repeat language validation on every call.
+///
+/// # Example
+/// ```ignore
+/// translatable::set_translations(translatable::embed_translations!()).unwrap();
+///
+/// let translator = Translator::new(Language::ES);
+/// translator.get_with("common.greeting", &[("name", &"john")]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Translator<'a> {
+    /// Language every lookup is resolved for
+    language: Language,
+    /// Explicit translations, `None` uses the process-wide translations
+    translations: Option<&'a TranslationNodeCollection>,
+    /// Whether argument values are isolated for right-to-left languages
+    bidi_isolation: bool,
+    /// Named variant resolved instead of the paths that have it
+    variant: Option<String>,
+}
+
+impl Translator<'static> {
+    /// Creates a translator resolving against the process-wide translations
+    /// set with [`set_translations`], and the overrides loaded with
+    /// [`load_overrides`](crate::load_overrides) before them
+    ///
+    /// Lookups return `Error::PathNotFound` while no translations are set.
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            translations: None,
+            bidi_isolation: false,
+            variant: None,
+        }
+    }
+}
+
+impl<'a> Translator<'a> {
+    /// Creates a translator resolving against an explicit collection
+    pub fn with_translations(
+        language: Language,
+        translations: &'a TranslationNodeCollection,
+    ) -> Self {
+        Self {
+            language,
+            translations: Some(translations),
+            bidi_isolation: false,
+            variant: None,
+        }
+    }
+
+    /// Sets whether argument values are wrapped in bidi isolation
+    /// characters, only applied if the language is right-to-left
+    pub fn with_bidi_isolation(mut self, bidi_isolation: bool) -> Self {
+        self.bidi_isolation = bidi_isolation;
+        self
+    }
+
+    /// Sets the named variant resolved instead of the paths that have it,
+    /// such as the group of an A/B test
+    ///
+    /// Paths without the variant, or whose variant lacks the language, are
+    /// resolved as usual.
+    pub fn with_variant(mut self, variant: impl Into<String>) -> Self {
+        self.variant = Some(variant.into());
+        self
+    }
+
+    /// Gets the language this translator resolves for
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Resolves a dot-separated translation path
+    ///
+    /// # Returns
+    /// - `Ok(Cow::Borrowed)` with the raw translation, or one of its random
+    ///   entries picked
+    /// - `Err(Error)` if the path or language are not available
+    pub fn get(&self, path: &str) -> Result<Cow<'a, str>, Error> {
+        self.get_with(path, &[])
+    }
+
+    /// Resolves a dot-separated translation path replacing its templates
+    ///
+    /// # Arguments
+    /// * `path` - Dot-separated translation path
+    /// * `arguments` - Template names and their values
+    ///
+    /// # Returns
+    /// The translation is only copied if there are arguments to replace.
+    pub fn get_with(
+        &self,
+        path: &str,
+        arguments: &[(&str, &dyn Display)],
+    ) -> Result<Cow<'a, str>, Error> {
+        let translation = pick_entry(self.find(path)?);
+
+        if arguments.is_empty() {
+            return Ok(Cow::Borrowed(translation));
+        }
+
+        Ok(Cow::Owned(
+            Formatted::new(translation, arguments)
+                .with_language(&self.language)
+                .with_bidi_isolation(self.bidi_isolation)
+                .to_string(),
+        ))
+    }
+
+    /// Resolves a dot-separated path of the `_values` namespace to a typed
+    /// value, such as a per-locale constant
+    ///
+    /// # Example
+    /// ```ignore
+    /// let first_weekday = translator.value::<u8>("calendar.first_weekday")?;
+    /// ```
+    ///
+    /// # Returns
+    /// - `Ok(T)` with the value of the language
+    /// - `Err(Error)` if the path or language are not available, or the value
+    ///   can't be read as `T`
+    pub fn value<T: TranslationValue>(&self, path: &str) -> Result<T, Error> {
+        let value = self.find(&format!("{VALUES_KEY}.{path}"))?;
+
+        T::parse_value(value).ok_or_else(|| {
+            missing_translation(Error::InvalidValue {
+                path: path.to_string(),
+                language: self.language.clone(),
+            })
+        })
+    }
+
+    /// Resolves a dot-separated translation path to be written with its
+    /// templates replaced
+    ///
+    /// The result implements `Display`, so it can be written into any
+    /// `fmt::Write` or `io::Write` target with `write!` without allocating.
+    ///
+    /// # Arguments
+    /// * `path` - Dot-separated translation path
+    /// * `arguments` - Template names and their values
+    pub fn format_with<'b>(
+        &'b self,
+        path: &str,
+        arguments: &'b [(&'b str, &'b dyn Display)],
+    ) -> Result<Formatted<'b>, Error>
+    where
+        'a: 'b,
+    {
+        Ok(Formatted::new(pick_entry(self.find(path)?), arguments)
+            .with_language(&self.language)
+            .with_bidi_isolation(self.bidi_isolation))
+    }
+
+    /// Collects the raw translations of the language, keyed by dot-separated
+    /// path
+    ///
+    /// Every path resolves to the translation [`Translator::get`] would, one
+    /// of the random entries included, paths without the language are left
+    /// out, so a frontend can be served the whole catalog at once.
+    pub fn catalog(&self) -> BTreeMap<String, &'a str> {
+        let mut found = self
+            .translations
+            .or_else(process_translations)
+            .map(|translations| collection_catalog(translations, &self.language))
+            .unwrap_or_default();
+
+        // Overrides take precedence per path and language, like in lookups
+        if self.translations.is_none()
+            && let Some(overrides) = overrides()
+        {
+            found.extend(collection_catalog(overrides, &self.language));
+        }
+
+        // Variants replace the translation of their path
+        let mut catalog = BTreeMap::new();
+        let mut variants = Vec::new();
+
+        for (path, translation) in found {
+            match path.split_once(VARIANT_SEPARATOR) {
+                Some((path, variant)) if Some(variant) == self.variant.as_deref() => {
+                    variants.push((path.to_string(), translation))
+                },
+                Some(_) => {},
+                None => {
+                    catalog.insert(path, translation);
+                },
+            }
+        }
+
+        catalog.extend(variants);
+        catalog.into_iter().map(|(path, translation)| (path, pick_entry(translation))).collect()
+    }
+
+    /// Finds the translation file a dot-separated path is resolved from,
+    /// such as to see which file won the overlap resolution
+    ///
+    /// # Returns
+    /// The original path of the file [`Translator::get`] would read the
+    /// translation from, overrides included, or `None` if the path or
+    /// language are not available
+    pub fn source(&self, path: &str) -> Option<&'a str> {
+        self.variant
+            .as_ref()
+            .and_then(|variant| self.find_source(&variant_path(path, variant)))
+            .or_else(|| self.find_source(path))
+    }
+
+    /// Finds the translation file a dot-separated path is resolved from,
+    /// without its variant
+    fn find_source(&self, path: &str) -> Option<&'a str> {
+        let path = self.migrate(path);
+        let found_in = |translations: &'a TranslationNodeCollection| {
+            translations
+                .find_path_with_source(path)
+                .filter(|(translation_object, _)| translation_object.contains_key(&self.language))
+                .map(|(_, source)| source)
+        };
+
+        if self.translations.is_none()
+            && let Some(source) = overrides().and_then(found_in)
+        {
+            return Some(source);
+        }
+
+        self.translations.or_else(process_translations).and_then(found_in)
+    }
+
+    /// Gets the path a dot-separated path is looked up at, its new path if
+    /// the translations migrated it
+    fn migrate<'p>(&self, path: &'p str) -> &'p str
+    where
+        'a: 'p,
+    {
+        self.translations
+            .or_else(process_translations)
+            .map_or(path, |translations| translations.migrate(path))
+    }
+
+    /// Finds the raw translation of a dot-separated path
+    ///
+    /// Failures are reported to the missing translation hook.
+    fn find(&self, path: &str) -> Result<&'a str, Error> {
+        trace_lookup(self.language.code(), path);
+
+        // Migrated paths are looked up at their new path, overrides included
+        let path = self.migrate(path);
+
+        // Missing variants fall back to their path without being reported
+        if let Some(variant) = &self.variant
+            && let Some(translation) = self.find_variant(&variant_path(path, variant))
+        {
+            return Ok(translation);
+        }
+
+        // Overrides only take precedence over the process-wide translations
+        if self.translations.is_none()
+            && let Some(translation) = overrides()
+                .and_then(|overrides| overrides.find_path(path))
+                .and_then(|translation_object| translation_object.get(&self.language))
+        {
+            return Ok(translation);
+        }
+
+        let translation_object = self
+            .translations
+            .or_else(process_translations)
+            .and_then(|translations| translations.find_path(path))
+            .ok_or_else(|| missing_translation(Error::PathNotFound { path: path.to_string() }))?;
+
+        translation_object.get(&self.language).map(String::as_str).ok_or_else(|| {
+            let mut available = translation_object.keys().cloned().collect::<Vec<_>>();
+            available.sort_by_key(|language| language.code());
+
+            missing_translation(Error::LanguageNotAvailable {
+                language: self.language.clone(),
+                path: path.to_string(),
+                available,
+            })
+        })
+    }
+
+    /// Finds the raw translation of a variant path, overrides included
+    fn find_variant(&self, path: &str) -> Option<&'a str> {
+        let find_in = |translations: &'a TranslationNodeCollection| {
+            translations.find_path(path)?.get(&self.language).map(String::as_str)
+        };
+
+        if self.translations.is_none()
+            && let Some(translation) = overrides().and_then(find_in)
+        {
+            return Some(translation);
+        }
+
+        self.translations.or_else(process_translations).and_then(find_in)
+    }
+}
+
+/// Collects the translations of a collection in a language
+///
+/// The first translation file containing a path wins, even if it lacks the
+/// language, like in [`TranslationNodeCollection::find_path`].
+fn collection_catalog<'a>(
+    translations: &'a TranslationNodeCollection,
+    language: &Language,
+) -> BTreeMap<String, &'a str> {
+    let mut catalog = BTreeMap::new();
+
+    for association in translations.iter() {
+        collect_catalog(association.translation_table(), "", language, &mut catalog);
+    }
+
+    catalog.into_iter().filter_map(|(path, translation)| Some((path, translation?))).collect()
+}
+
+/// Adds the paths of a node missing from a catalog, with their translation
+/// in a language if any
+fn collect_catalog<'a>(
+    node: &'a TranslationNode,
+    path: &str,
+    language: &Language,
+    catalog: &mut BTreeMap<String, Option<&'a str>>,
+) {
+    match node {
+        TranslationNode::Object(nesting) => {
+            for (key, node) in nesting {
+                let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                collect_catalog(node, &path, language, catalog);
+            }
+        },
+        TranslationNode::Translation(translations) => {
+            catalog
+                .entry(path.to_string())
+                .or_insert(translations.get(language).map(String::as_str));
+        },
+    }
+}
@@ -0,0 +1,56 @@
+//! Typed translation values
+//!
+//! This module reads the values of the `_values` namespace of translation
+//! files back to their types, such as the first day of the week as an
+//! integer or the metric system as a boolean.
+
+use translatable_shared::array_elements;
+
+/// Type a value of the `_values` namespace can be read as
+///
+/// Implemented for strings, booleans, characters, numbers and vectors of
+/// them, values being stored as they're written in TOML.
+///
+/// # Example
+/// ```
+/// use translatable::TranslationValue;
+///
+/// assert_eq!(u8::parse_value("1"), Some(1));
+/// assert_eq!(Vec::<String>::parse_value(r#"["A4", "A5"]"#), Some(vec!["A4".into(), "A5".into()]));
+/// ```
+pub trait TranslationValue: Sized {
+    /// Parses a stored value
+    ///
+    /// # Returns
+    /// The value, or `None` if it isn't of this type
+    fn parse_value(value: &str) -> Option<Self>;
+}
+
+impl TranslationValue for String {
+    fn parse_value(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// Implements [`TranslationValue`] for types parsed with `FromStr`
+macro_rules! from_str_values {
+    ($($value:ty),*) => {
+        $(
+            impl TranslationValue for $value {
+                fn parse_value(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+from_str_values!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl<T: TranslationValue> TranslationValue for Vec<T> {
+    fn parse_value(value: &str) -> Option<Self> {
+        array_elements(value)?.iter().map(|element| T::parse_value(element)).collect()
+    }
+}
@@ -0,0 +1,114 @@
+//! Yew bindings of the shared locale
+//!
+//! This module provides the [`Locale`] to Yew components through a context
+//! provider, so switching the language re-renders the components using it.
+
+use ::yew::{
+    Callback, ContextProvider, Html, Properties, UseStateHandle, function_component, hook, html,
+    use_context, use_state_eq,
+};
+use translatable_shared::Language;
+
+use crate::{Locale, Translator};
+
+/// Context provided by [`LocaleProvider`]
+#[derive(Clone, PartialEq)]
+struct LocaleContext {
+    /// Handle shared with the rest of the application
+    locale: Locale,
+    /// Current language, the state of the provider
+    language: UseStateHandle<Language>,
+}
+
+/// Properties of [`LocaleProvider`]
+#[derive(Properties, PartialEq)]
+pub struct LocaleProviderProps {
+    /// Locale provided to the children
+    pub locale: Locale,
+    /// Components using the locale
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provides a locale to its children
+///
+/// Yew state can't be updated from other threads, so the children re-render
+/// when the language is switched with [`use_set_language`], not with
+/// [`Locale::set_language`] directly.
+///
+/// # Example
+/// ```ignore
+/// html! {
+///     <LocaleProvider locale={Locale::new(Language::EN)}>
+///         <Greeting />
+///     </LocaleProvider>
+/// }
+/// ```
+#[function_component]
+pub fn LocaleProvider(props: &LocaleProviderProps) -> Html {
+    let language = use_state_eq(|| props.locale.language());
+    let context = LocaleContext { locale: props.locale.clone(), language };
+
+    html! {
+        <ContextProvider<LocaleContext> {context}>
+            { props.children.clone() }
+        </ContextProvider<LocaleContext>>
+    }
+}
+
+/// Context provided by an ancestor, panics without a [`LocaleProvider`]
+#[hook]
+fn use_locale_context() -> LocaleContext {
+    use_context::<LocaleContext>().expect("no LocaleProvider is an ancestor of this component")
+}
+
+/// Gets the locale provided by an ancestor
+///
+/// # Panics
+/// If no [`LocaleProvider`] is an ancestor of the component.
+#[hook]
+pub fn use_locale() -> Locale {
+    use_locale_context().locale
+}
+
+/// Gets the current language
+///
+/// Compile-time validated lookups take it, either with the typed accessors or
+/// with the `translation!` macro and a static path.
+///
+/// ```ignore
+/// let language = use_language();
+///
+/// html! { <p>{ common::greeting(language, "john") }</p> }
+/// ```
+///
+/// # Panics
+/// If no [`LocaleProvider`] is an ancestor of the component.
+#[hook]
+pub fn use_language() -> Language {
+    (*use_locale_context().language).clone()
+}
+
+/// Gets a callback switching the language of the locale and re-rendering
+/// the components using it
+///
+/// # Panics
+/// If no [`LocaleProvider`] is an ancestor of the component.
+#[hook]
+pub fn use_set_language() -> Callback<Language> {
+    let LocaleContext { locale, language } = use_locale_context();
+
+    Callback::from(move |changed: Language| {
+        locale.set_language(changed.clone());
+        language.set(changed);
+    })
+}
+
+/// Gets a translator for the current language, for lookups of dynamic paths
+///
+/// # Panics
+/// If no [`LocaleProvider`] is an ancestor of the component.
+#[hook]
+pub fn use_translation() -> Translator<'static> {
+    Translator::new(use_language())
+}
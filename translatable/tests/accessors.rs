@@ -0,0 +1,16 @@
+use translatable::{Error, Language};
+
+mod translations {
+    translatable::translation_accessors!();
+}
+
+#[test]
+fn typed_accessors() {
+    assert!(translations::common::greeting(Language::ES, "john").unwrap() == "¡Hola john!");
+    assert!(translations::welcome_message(Language::EN).unwrap() == "Welcome to our app!");
+    assert!(translations::messages::items(Language::EN, 2).unwrap() == "2 items");
+    assert!(matches!(
+        translations::welcome_message(Language::FR),
+        Err(Error::LanguageNotAvailable { .. })
+    ));
+}
@@ -0,0 +1,21 @@
+use translatable::{translation, translation_prefix};
+
+macro_rules! t {
+    ($language:expr, static $($path:ident)::+) => {
+        translation!($language, static $($path)::+)
+    };
+}
+
+fn main() {
+    println!("{}", translation!("en", static common::greeting, name = "john"));
+    println!("{}", t!("en", static checkout::title));
+    println!("{}", translation!("en", "common.dynamic"));
+    println!("{}", translation!("en", static users::[id]::name));
+}
+
+#[translation_prefix("settings.page")]
+mod settings {
+    fn title() -> String {
+        translation!("en", static title).unwrap()
+    }
+}
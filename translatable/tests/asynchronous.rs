@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+
+use translatable::{
+    AsyncTranslationProvider, Language, MockTranslations, TranslationNodeCollection, Translator,
+    clear_overrides, refresh_overrides,
+};
+
+/// Provider returning a fixed catalog
+struct Edited;
+
+impl AsyncTranslationProvider for Edited {
+    type Error = Infallible;
+
+    async fn fetch(&self) -> Result<TranslationNodeCollection, Infallible> {
+        Ok(MockTranslations::new().translation("common.farewell", Language::EN, "See you!").build())
+    }
+}
+
+// The process-wide state is shared, so the steps run in a single test
+#[tokio::test]
+async fn asynchronous_loading() {
+    #[cfg(feature = "tokio")]
+    {
+        let failed = translatable::init_translations(|| async { Err("unavailable") }).await;
+        assert!(failed.is_err());
+
+        let translations = translatable::init_translations(|| async {
+            Ok::<_, Infallible>(translatable::embed_translations!())
+        })
+        .await
+        .unwrap();
+        assert!(translations.find_path("common.farewell").is_some());
+
+        let again = translatable::init_translations(|| async { Err("unused") }).await.unwrap();
+        assert!(std::ptr::eq(translations, again));
+        assert!(translatable::set_translations(TranslationNodeCollection::default()).is_err());
+    }
+
+    #[cfg(not(feature = "tokio"))]
+    let _ = translatable::set_translations(translatable::embed_translations!());
+
+    let translator = Translator::new(Language::EN);
+    assert!(translator.get("common.farewell").unwrap() == "Goodbye!");
+
+    refresh_overrides(&Edited).await.unwrap();
+    assert!(translator.get("common.farewell").unwrap() == "See you!");
+
+    #[cfg(feature = "tokio")]
+    {
+        translatable::load_overrides_async("tests/assets/overrides.toml").await.unwrap();
+        assert!(translator.get("common.farewell").unwrap() == "Goodbye!");
+        assert!(translator.get("common.notice").is_ok());
+
+        let missing = translatable::load_overrides_async("tests/assets/missing.toml").await;
+        assert!(matches!(missing, Err(translatable::LoadError::Io(..))));
+    }
+
+    clear_overrides();
+    assert!(translator.get("common.notice").is_err());
+}
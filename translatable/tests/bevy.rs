@@ -0,0 +1,56 @@
+#![cfg(feature = "bevy")]
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use bevy::app::{App, TaskPoolPlugin, Update};
+use bevy::asset::AssetPlugin;
+use bevy::ecs::resource::Resource;
+use bevy::ecs::system::ResMut;
+use translatable::bevy::{TranslatablePlugin, Translations};
+use translatable::{Language, Locale, embed_translations, set_translations};
+
+#[derive(Resource, Default)]
+struct Greetings(Vec<String>);
+
+fn greet(translations: Translations, mut greetings: ResMut<Greetings>) {
+    if translations.is_changed() {
+        let greeting = translations.get_with("common.greeting", &[("name", &"john")]).unwrap();
+        greetings.0.push(greeting.into_owned());
+    }
+}
+
+#[test]
+fn game_localization() {
+    let _ = set_translations(embed_translations!());
+    let mut app = App::new();
+
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin {
+            file_path: "tests/assets".into(),
+            ..Default::default()
+        },
+        TranslatablePlugin::new(Language::EN).with_file("overrides.toml"),
+    ))
+    .init_resource::<Greetings>()
+    .add_systems(Update, greet);
+
+    app.update();
+    assert!(app.world().resource::<Greetings>().0 == ["Hello john!"]);
+
+    for _ in 0..500 {
+        if app.world().resource::<Greetings>().0.len() > 1 {
+            break;
+        }
+
+        sleep(Duration::from_millis(10));
+        app.update();
+    }
+    assert!(app.world().resource::<Greetings>().0 == ["Hello john!", "Hi john!"]);
+
+    app.world().resource::<Locale>().set_language(Language::ES);
+    app.update();
+    app.update();
+    assert!(app.world().resource::<Greetings>().0 == ["Hello john!", "Hi john!", "¡Hola john!"]);
+}
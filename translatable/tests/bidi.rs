@@ -0,0 +1,20 @@
+use translatable::{Formatted, Language, MockTranslations};
+
+#[test]
+fn isolated_translator_arguments() {
+    let translator = MockTranslations::new()
+        .translation("greeting", Language::HE, "שלום {name}")
+        .translator(Language::HE);
+
+    assert!(translator.get_with("greeting", &[("name", &"john")]).unwrap() == "שלום john");
+    assert!(
+        translator.with_bidi_isolation(true).get_with("greeting", &[("name", &"john")]).unwrap()
+            == "שלום \u{2068}john\u{2069}"
+    );
+
+    let formatted = Formatted::new("{name} / {name|upper}", &[("name", &"john")])
+        .with_language(&Language::AR)
+        .with_bidi_isolation(true);
+
+    assert!(formatted.to_string() == "\u{2068}john\u{2069} / \u{2068}JOHN\u{2069}");
+}
@@ -0,0 +1,36 @@
+use translatable::{Language, translation, translation_or_key, try_translation};
+
+#[test]
+fn static_catalog_path() {
+    assert!(
+        translation!("es", static title, @path = "translatable/tests/fixtures/product")
+            == "Producto B"
+    );
+    assert!(
+        translation!(
+            "en",
+            static greeting,
+            name = "john",
+            @path = "translatable/tests/fixtures/product",
+        ) == "Welcome to Product B, john!"
+    );
+
+    // Other call sites keep the configured translations
+    assert!(translation!("en", static welcome_message) == "Welcome to our app!");
+}
+
+#[test]
+fn dynamic_catalog_path() {
+    let language = Language::ES;
+    let path = "title";
+    let missing = String::from("welcome_message");
+
+    assert!(
+        try_translation!(language, path, @path = "translatable/tests/fixtures/product").unwrap()
+            == "Producto B"
+    );
+    assert!(
+        translation_or_key!("en", missing, @path = "translatable/tests/fixtures/product")
+            == "welcome_message"
+    );
+}
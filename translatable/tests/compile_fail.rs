@@ -0,0 +1,152 @@
+#![cfg(feature = "test-support")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use translatable::test_support::{
+    CompileFail, override_missing_translation_hook, override_translations, reset,
+};
+use translatable::{
+    Language, MissingArguments, SeekMode, Severity, TranslatableConfig, TranslationOverlap,
+    Translator, set_translations,
+};
+
+#[test]
+fn macro_diagnostics() {
+    // No other test of this binary accesses the environment
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/translations")
+            .config("[aliases]\ncastellano = \"es\"")
+            .case("tests/ui/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn warning_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/translations")
+            .config(r#"on_missing_language = "warn""#)
+            .case("tests/ui/severity/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn built_config_diagnostics() {
+    let config = TranslatableConfig::builder()
+        .seek_mode(SeekMode::Alphabetical)
+        .overlap(TranslationOverlap::Overwrite)
+        .missing_arguments(MissingArguments::Ignore)
+        .on_missing_language(Severity::Warn)
+        .build();
+
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/translations")
+            .config(config.to_toml())
+            .case("tests/ui/config/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn feature_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/translations")
+            .config(r#"features = ["premium"]"#)
+            .case("tests/ui/features/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn unicode_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/unicode")
+            .config("strict_unicode = true")
+            .case("tests/ui/unicode/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn lint_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/lints")
+            .config("[lints]\ndouble_spaces = \"warn\"")
+            .case("tests/ui/lints/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn deprecation_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/deprecations")
+            .case("tests/ui/deprecations/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn migration_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/migrations")
+            .config("[migrations]\n\"common.greeting\" = \"common.greeting_v2\"")
+            .case("tests/ui/migrations/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn namespace_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/namespace")
+            .config(r#"namespace = "billing""#)
+            .case("tests/ui/namespace/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn version_diagnostics() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/versions")
+            .config("min_catalog_version = 3")
+            .case("tests/ui/versions/*.rs")
+            .run();
+    }
+}
+
+#[test]
+fn runtime_overrides() {
+    let _ = set_translations(translatable::embed_translations!());
+    let failures = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&failures);
+
+    override_translations(translatable::include_translation_file!("tests/assets/library.toml"));
+    override_missing_translation_hook(move |_| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let translator = Translator::new(Language::EN);
+    assert!(translator.get("welcome_message").unwrap() == "Welcome to the library!");
+    assert!(translator.get("common.greeting").is_err());
+    assert!(failures.load(Ordering::Relaxed) == 1);
+
+    reset();
+
+    assert!(translator.get("welcome_message").unwrap() == "Welcome to our app!");
+    assert!(translator.get("common.missing").is_err());
+    assert!(failures.load(Ordering::Relaxed) == 1);
+}
@@ -0,0 +1,43 @@
+use translatable::{
+    Escape, Language, Lint, SeekMode, Severity, TranslatableConfig, TranslationOverlap,
+};
+
+#[test]
+fn built_config() {
+    let config = TranslatableConfig::builder()
+        .path("../../locales")
+        .seek_mode(SeekMode::Unalphabetical)
+        .overlap(TranslationOverlap::Overwrite)
+        .escape(Escape::Html)
+        .bidi_isolation(true)
+        .languages([Language::EN, Language::ES])
+        .alias("pt-br", Language::PT)
+        .lint(Lint::DoubleSpaces, Severity::Warn)
+        .min_catalog_version(3)
+        .build();
+
+    let table = config.to_toml().parse::<toml::Table>().unwrap();
+
+    assert!(table["path"].as_str() == Some("../../locales"));
+    assert!(table["seek_mode"].as_str() == Some("unalphabetical"));
+    assert!(table["overlap"].as_str() == Some("overwrite"));
+    assert!(table["escape"].as_str() == Some("html"));
+    assert!(table["bidi_isolation"].as_bool() == Some(true));
+    assert!(table["languages"].as_array().unwrap() == &["en".into(), "es".into()]);
+    assert!(table["aliases"]["pt-br"].as_str() == Some("pt"));
+    assert!(table["lints"]["double_spaces"].as_str() == Some("warn"));
+    assert!(table["min_catalog_version"].as_integer() == Some(3));
+    assert!(TranslatableConfig::builder().build().to_toml().is_empty());
+}
+
+#[test]
+fn written_config() {
+    let directory = std::env::temp_dir().join("translatable_config_test");
+    let path = directory.join("nested/translatable.toml");
+    let config = TranslatableConfig::builder().namespace("billing").build();
+
+    config.write(&path).unwrap();
+    assert!(std::fs::read_to_string(&path).unwrap() == config.to_toml());
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "test-support")]
+
+use translatable::test_support::CompileFail;
+
+#[test]
+fn language_aliases() {
+    // No other test of this binary accesses the environment
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config("[aliases]\n\"es-es\" = \"es\"\ncastellano = \"es\"")
+            .pass("tests/run/aliases.rs")
+            .run();
+    }
+}
+
+#[test]
+fn language_subset() {
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config("languages = [\"en\", \"es\"]\n\n[aliases]\nenglish = \"en\"")
+            .pass("tests/run/language_subset.rs")
+            .run();
+    }
+}
+
+#[test]
+fn missing_arguments_error() {
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config(r#"missing_arguments = "error""#)
+            .pass("tests/run/missing_arguments.rs")
+            .run();
+    }
+}
+
+#[test]
+fn embedded_languages() {
+    unsafe {
+        CompileFail::new()
+            .translations("tests/fixtures/embedding")
+            .config(r#"embed_languages = ["en", "es"]"#)
+            .pass("tests/run/embed_languages.rs")
+            .run();
+    }
+}
+
+#[test]
+fn bidi_isolation() {
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config("bidi_isolation = true")
+            .pass("tests/run/bidi_isolation.rs")
+            .run();
+    }
+}
+
+#[test]
+fn migrated_paths() {
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config("[migrations]\n\"common.welcome\" = \"welcome_message\"")
+            .pass("tests/run/migrations.rs")
+            .run();
+    }
+}
+
+#[test]
+fn capitalized_config() {
+    unsafe {
+        CompileFail::new()
+            .translations("../translations")
+            .config("seek_mode = \"Alphabetical\"\noverlap = \"Overwrite\"")
+            .pass("tests/run/capitalized_config.rs")
+            .run();
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "csv")]
+
+use translatable::{Language, Translator};
+
+#[test]
+fn csv_import() {
+    let translations = translatable::include_translation_file!("tests/assets/sheet.csv");
+
+    let english = Translator::with_translations(Language::EN, &translations);
+    assert!(english.get("checkout.title").unwrap() == "Checkout, then pay");
+    assert!(english.get("checkout.empty").unwrap() == "Your cart is empty");
+
+    let spanish = Translator::with_translations(Language::ES, &translations);
+    assert!(spanish.get("checkout.title").unwrap() == "Pagar, y finalizar");
+    assert!(spanish.get_with("checkout.items", &[("count", &1)]).unwrap() == "1 artículo");
+    assert!(spanish.get("checkout.empty").is_err());
+}
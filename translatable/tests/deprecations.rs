@@ -0,0 +1,30 @@
+use translatable::{Language, TranslationNodeCollection};
+
+#[test]
+fn deprecated_paths() {
+    let translations =
+        translatable::include_translation_file!("tests/fixtures/deprecations/labels.toml");
+
+    assert!(translations.find_deprecation("common.greeting") == Some("use common.greeting_v2"));
+    assert!(
+        translations.find_deprecation("legacy.title")
+            == Some("the legacy screens are being removed")
+    );
+    assert!(translations.find_deprecation("common.greeting_v2").is_none());
+    assert!(translations.find_deprecation("common.missing").is_none());
+
+    // Deprecated paths still resolve
+    assert!(translations.find_path("common.greeting").unwrap()[&Language::EN] == "Hello");
+
+    let subtree = translations.subtree("legacy").unwrap();
+    assert!(subtree.find_deprecation("title") == Some("the legacy screens are being removed"));
+
+    let decoded = TranslationNodeCollection::from_bytes(&translations.to_bytes()).unwrap();
+    assert!(decoded == translations);
+}
+
+#[test]
+fn invalid_deprecations() {
+    let mut table = "[label]\n_deprecated = 1\nen = 'Label'\n".parse::<toml::Table>().unwrap();
+    assert!(translatable_shared::take_deprecations(&mut table).is_err());
+}
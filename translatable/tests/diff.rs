@@ -0,0 +1,77 @@
+use translatable::{CatalogChange, CatalogDiff, Language};
+
+#[test]
+fn catalog_changes() {
+    let old = translatable::include_translation_file!("tests/assets/releases/v1/app.toml");
+    let new = translatable::include_translation_file!("tests/assets/releases/v2/app.toml");
+    let diff = CatalogDiff::new(&old, &new);
+
+    assert!(
+        diff.changes()
+            == [
+                CatalogChange::Removed {
+                    path: "checkout.empty".into(),
+                    language: Language::ES,
+                    translation: "Tu carrito está vacío".into(),
+                },
+                CatalogChange::Changed {
+                    path: "checkout.title".into(),
+                    language: Language::ES,
+                    old: "Pago".into(),
+                    new: "Pagar".into(),
+                },
+                CatalogChange::Added {
+                    path: "checkout.total".into(),
+                    language: Language::EN,
+                    translation: "Total: {amount}".into(),
+                },
+                CatalogChange::Added {
+                    path: "checkout.total".into(),
+                    language: Language::ES,
+                    translation: "Total: {amount}".into(),
+                },
+                CatalogChange::Removed {
+                    path: "legacy.banner".into(),
+                    language: Language::EN,
+                    translation: "Try the new checkout".into(),
+                },
+                CatalogChange::Removed {
+                    path: "legacy.banner".into(),
+                    language: Language::ES,
+                    translation: "Prueba el nuevo pago".into(),
+                },
+            ]
+    );
+
+    assert!(diff.removed_paths() == ["legacy.banner"]);
+    assert!(diff.language(&Language::ES).count() == 4);
+    assert!(CatalogDiff::new(&new, &new).is_empty());
+}
+
+#[test]
+fn directory_diff() {
+    let diff = CatalogDiff::load("tests/assets/releases/v1", "tests/assets/releases/v2").unwrap();
+
+    assert!(diff.changes().len() == 6);
+    assert!(diff.to_string().starts_with(
+        "- es checkout.empty: \"Tu carrito está vacío\"\n~ es checkout.title: \"Pago\" -> \
+         \"Pagar\"\n+ en checkout.total: \"Total: {amount}\"\n"
+    ));
+
+    assert!(CatalogDiff::load("tests/assets/releases/v0", "tests/assets/releases/v2").is_err());
+}
+
+#[test]
+fn prioritized_directory_diff() {
+    let diff = CatalogDiff::load("tests/assets/releases/v2", "tests/assets/releases/v3").unwrap();
+
+    assert!(
+        diff.changes()
+            == [CatalogChange::Changed {
+                path: "checkout.title".into(),
+                language: Language::EN,
+                old: "Checkout".into(),
+                new: "Check out".into(),
+            }]
+    );
+}
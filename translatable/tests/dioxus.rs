@@ -0,0 +1,39 @@
+#![cfg(feature = "dioxus")]
+
+use std::sync::Mutex;
+
+use dioxus::dioxus_core::{Element, VNode, VirtualDom};
+use translatable::dioxus::{use_language, use_locale, use_locale_provider, use_translation};
+use translatable::{Language, Locale, embed_translations, set_translations};
+
+/// Locale of the rendered app, and the greetings of every render
+static RENDERED: Mutex<(Option<Locale>, Vec<String>)> = Mutex::new((None, Vec::new()));
+
+fn app() -> Element {
+    let locale = use_locale_provider(|| Locale::new(Language::EN));
+    assert!(use_locale() == locale);
+
+    let greeting = use_translation().get_with("common.greeting", &[("name", &"john")]).unwrap();
+    assert!(*use_translation().language() == use_language());
+
+    let mut rendered = RENDERED.lock().unwrap();
+    rendered.0 = Some(locale);
+    rendered.1.push(greeting.into_owned());
+
+    Ok(VNode::placeholder())
+}
+
+#[test]
+fn reactive_locale() {
+    let _ = set_translations(embed_translations!());
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let locale = RENDERED.lock().unwrap().0.clone().unwrap();
+    locale.set_language(Language::ES);
+
+    dom.process_events();
+    dom.render_immediate_to_vec();
+
+    assert!(RENDERED.lock().unwrap().1 == ["Hello john!", "¡Hola john!"]);
+}
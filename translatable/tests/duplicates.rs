@@ -0,0 +1,30 @@
+use translatable::{Language, MockTranslations, find_duplicates};
+
+#[test]
+fn duplicate_strings() {
+    let catalog = MockTranslations::new()
+        .translations("profile.save", [(Language::EN, "Save"), (Language::ES, "Guardar")])
+        .translations("checkout.save", [(Language::EN, "save."), (Language::ES, "Guardar")])
+        .translations("settings.save", [(Language::EN, "Save  changes"), (Language::ES, "Guardar")])
+        .translations("settings.apply", [(Language::EN, "Save changes"), (Language::ES, "Aplicar")])
+        .build();
+
+    let duplicates = find_duplicates(&catalog);
+    assert!(duplicates.len() == 3);
+
+    assert!(*duplicates[0].language() == Language::EN);
+    assert!(duplicates[0].paths().collect::<Vec<_>>() == ["checkout.save", "profile.save"]);
+    assert!(!duplicates[0].is_identical());
+    assert!(
+        duplicates[0].to_string()
+            == "2 similar translations in en: checkout.save, profile.save, consider reusing \
+                'checkout.save'"
+    );
+
+    assert!(duplicates[1].canonical() == "settings.apply");
+    assert!(duplicates[1].translations()[1] == ("settings.save".into(), "Save  changes".into()));
+
+    assert!(*duplicates[2].language() == Language::ES);
+    assert!(duplicates[2].paths().count() == 3);
+    assert!(duplicates[2].is_identical());
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "egui")]
+
+use std::sync::Arc;
+
+use egui::Context;
+use translatable::egui::{Translate, clear_translation_cache, set_locale};
+use translatable::{Language, Locale, embed_translations, set_translations};
+
+#[test]
+fn cached_translations() {
+    let _ = set_translations(embed_translations!());
+    let ctx = Context::default();
+
+    assert!(ctx.t("welcome_message").text() == "welcome_message");
+    assert!(ctx.language().is_none());
+
+    let locale = Locale::new(Language::EN);
+    set_locale(&ctx, locale.clone());
+
+    let farewell = ctx.t("welcome_message");
+    assert!(farewell.text() == "Welcome to our app!");
+    assert!(Arc::ptr_eq(&farewell, &ctx.t("welcome_message")));
+    assert!(ctx.t("common.missing").text() == "common.missing");
+    assert!(ctx.t_with("common.greeting", &[("name", &"john")]) == "Hello john!");
+
+    clear_translation_cache(&ctx);
+    assert!(!Arc::ptr_eq(&farewell, &ctx.t("welcome_message")));
+
+    locale.set_language(Language::ES);
+    assert!(ctx.language() == Some(Language::ES));
+    assert!(ctx.t("welcome_message").text() == "¡Bienvenido a nuestra aplicación!");
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "test-support")]
+
+use translatable::embed_translations;
+use translatable::test_support::embedded_decodes;
+
+#[test]
+fn decoded_once() {
+    let embedded = || embed_translations!();
+    let decodes = embedded_decodes();
+
+    let first = embedded();
+    let second = embedded();
+    assert!(first == second);
+    assert!(first.find_path("common.greeting").is_some());
+    assert!(embedded_decodes() == decodes + 1);
+}
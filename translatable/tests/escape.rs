@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use translatable::{HtmlEscaped, translation, translation_inline, translation_write};
+
+#[test]
+fn escaped_arguments() {
+    let name = "<script>alert('x')</script>";
+    let language = "en";
+
+    assert!(
+        translation!("en", static common::greeting, name, escape = "html")
+            == "Hello &lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt;!"
+    );
+    assert!(
+        translation!(language, static common::greeting, name = "Tom & Jerry", escape = "html")
+            .unwrap()
+            == "Hello Tom &amp; Jerry!"
+    );
+    assert!(
+        translation!("en", static common::greeting, name = "<b>", escape = "none") == "Hello <b>!"
+    );
+    assert!(translation!("en", static common::greeting, name = "<b>") == "Hello <b>!");
+}
+
+#[test]
+fn escaped_markup() {
+    let names = HashMap::from([("name", "<i>john</i>")]);
+    let language = "en";
+
+    // Only the values are escaped, the markup of the translation is kept
+    assert!(
+        translation_inline!("en", { en = "<b>{name}</b>" }, name = "\"john\"", escape = "html")
+            == "<b>&quot;john&quot;</b>"
+    );
+    assert!(
+        translation_inline!(language, { en = "<b>{name}</b>" }, ..&names, escape = "html").unwrap()
+            == "<b>&lt;i&gt;john&lt;/i&gt;</b>"
+    );
+
+    let mut output = String::new();
+    translation_write!(&mut output, "en", static common::greeting, name = "<b>", escape = "html")
+        .unwrap();
+    assert!(output == "Hello &lt;b&gt;!");
+}
+
+#[test]
+fn escaped_values() {
+    assert!(
+        HtmlEscaped("a < b && c > \"d\"").to_string() == "a &lt; b &amp;&amp; c &gt; &quot;d&quot;"
+    );
+    assert!(format!("{:#}", HtmlEscaped(42)) == "42");
+}
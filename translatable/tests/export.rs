@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::env::temp_dir;
+use std::fs::read_to_string;
+use std::process;
+
+use translatable::export::{
+    export_android, export_apple, export_gettext, export_i18next, export_schema,
+    write_android_strings, write_apple_strings, write_apple_stringsdict, write_i18next, write_po,
+    write_pot, write_schema, write_xliff,
+};
+use translatable::{AssociatedTranslation, Language, TranslationNode, TranslationNodeCollection};
+
+#[test]
+fn gettext_catalogs() {
+    let translations = translatable::embed_translations!();
+
+    let mut template = Vec::new();
+    write_pot(&translations, &Language::EN, &mut template).unwrap();
+    let template = String::from_utf8(template).unwrap();
+
+    assert!(template.starts_with("msgid \"\"\nmsgstr \"\"\n"));
+    assert!(template.contains(
+        "#: ./translations/test.toml\nmsgctxt \"common.greeting\"\nmsgid \"Hello \
+         {name}!\"\nmsgstr \"\"\n"
+    ));
+
+    let mut catalog = Vec::new();
+    write_po(&translations, &Language::EN, &Language::ES, &mut catalog).unwrap();
+    let catalog = String::from_utf8(catalog).unwrap();
+
+    assert!(catalog.contains("\"Language: es\\n\""));
+    assert!(catalog.contains(
+        "msgctxt \"common.greeting\"\nmsgid \"Hello {name}!\"\nmsgstr \"¡Hola {name}!\"\n"
+    ));
+    assert!(catalog.contains("msgctxt \"common.farewell\"\nmsgid \"Goodbye!\"\nmsgstr \"\"\n"));
+}
+
+#[test]
+fn gettext_escaping() {
+    let translations = TranslationNodeCollection::new(vec![AssociatedTranslation::new(
+        "quotes.toml",
+        TranslationNode::Object(HashMap::from([(
+            "quote".into(),
+            TranslationNode::Translation(HashMap::from([(
+                Language::ES,
+                "Di \"hola\"\ny vete".into(),
+            )])),
+        )])),
+    )]);
+
+    let mut catalog = Vec::new();
+    write_po(&translations, &Language::EN, &Language::ES, &mut catalog).unwrap();
+    let catalog = String::from_utf8(catalog).unwrap();
+
+    assert!(catalog.contains(
+        "msgctxt \"quote\"\nmsgid \"quote\"\nmsgstr \"\"\n\"Di \\\"hola\\\"\\n\"\n\"y vete\"\n"
+    ));
+}
+
+#[test]
+fn gettext_directory() {
+    let directory = temp_dir().join(format!("translatable-export-{}", process::id()));
+    export_gettext(&translatable::embed_translations!(), &Language::EN, &directory).unwrap();
+
+    assert!(
+        read_to_string(directory.join("messages.pot"))
+            .unwrap()
+            .contains("msgctxt \"welcome_message\"")
+    );
+    assert!(
+        read_to_string(directory.join("es.po"))
+            .unwrap()
+            .contains("msgstr \"¡Bienvenido a nuestra aplicación!\"")
+    );
+    assert!(!directory.join("fr.po").exists());
+}
+
+#[test]
+fn xliff_documents() {
+    let mut document = Vec::new();
+    write_xliff(&translatable::embed_translations!(), &Language::EN, &Language::ES, &mut document)
+        .unwrap();
+    let document = String::from_utf8(document).unwrap();
+
+    assert!(document.contains(r#"srcLang="en" trgLang="es""#));
+    assert!(document.contains(r#"<file id="f1" original="./translations/test.toml">"#));
+    assert!(document.contains(concat!(
+        "    <unit id=\"common.greeting\">\n",
+        "      <segment state=\"translated\">\n",
+        "        <source>Hello {name}!</source>\n",
+        "        <target>¡Hola {name}!</target>\n",
+    )));
+    assert!(document.contains(concat!(
+        "    <unit id=\"common.farewell\">\n",
+        "      <segment state=\"initial\">\n",
+        "        <source>Goodbye!</source>\n",
+        "      </segment>\n",
+    )));
+}
+
+#[test]
+fn android_resources() {
+    let translations = translatable::include_translation_file!("tests/assets/mobile.toml");
+
+    let mut resources = Vec::new();
+    write_android_strings(&translations, &Language::EN, &mut resources).unwrap();
+    let resources = String::from_utf8(resources).unwrap();
+
+    assert!(resources.contains(r#"<string name="cart_discount">Save 50%% now, %1$s!</string>"#));
+    assert!(resources.contains(r#"<string name="cart_title">Bob\'s \"cart\"</string>"#));
+    assert!(resources.contains(concat!(
+        "    <plurals name=\"cart_items\">\n",
+        "        <item quantity=\"one\">You have %1$d item in %2$s.</item>\n",
+        "        <item quantity=\"other\">You have %1$d items in %2$s.</item>\n",
+        "    </plurals>\n",
+    )));
+
+    let mut resources = Vec::new();
+    write_android_strings(&translations, &Language::ES, &mut resources).unwrap();
+    assert!(!String::from_utf8(resources).unwrap().contains("cart_title"));
+}
+
+#[test]
+fn apple_strings() {
+    let translations = translatable::include_translation_file!("tests/assets/mobile.toml");
+
+    let mut strings = Vec::new();
+    write_apple_strings(&translations, &Language::EN, &mut strings).unwrap();
+    let strings = String::from_utf8(strings).unwrap();
+
+    assert!(
+        strings
+            == "\"cart.discount\" = \"Save 50%% now, %1$@!\";\n\"cart.title\" = \"Bob's \
+                \\\"cart\\\"\";\n"
+    );
+
+    let mut stringsdict = Vec::new();
+    write_apple_stringsdict(&translations, &Language::EN, &mut stringsdict).unwrap();
+    let stringsdict = String::from_utf8(stringsdict).unwrap();
+
+    assert!(stringsdict.contains("<string>%1$#@count@</string>"));
+    assert!(
+        stringsdict
+            .contains("<key>zero</key>\n            <string>You have no items in %2$@.</string>")
+    );
+    assert!(
+        stringsdict.contains(
+            "<key>other</key>\n            <string>You have %1$d items in %2$@.</string>"
+        )
+    );
+}
+
+#[test]
+fn mobile_directories() {
+    let translations = translatable::include_translation_file!("tests/assets/mobile.toml");
+    let directory = temp_dir().join(format!("translatable-export-mobile-{}", process::id()));
+
+    export_android(&translations, &Language::EN, directory.join("res")).unwrap();
+    assert!(
+        read_to_string(directory.join("res/values/strings.xml")).unwrap().contains("cart_title")
+    );
+    assert!(
+        read_to_string(directory.join("res/values-es/strings.xml")).unwrap().contains("Tienes")
+    );
+
+    export_apple(&translations, &directory).unwrap();
+    assert!(
+        read_to_string(directory.join("es.lproj/Localizable.strings")).unwrap().contains("Ahorra")
+    );
+    assert!(
+        read_to_string(directory.join("es.lproj/Localizable.stringsdict"))
+            .unwrap()
+            .contains("Tienes")
+    );
+}
+
+#[test]
+fn i18next_resources() {
+    let translations = translatable::include_translation_file!("tests/assets/mobile.toml");
+
+    let mut resources = Vec::new();
+    write_i18next(&translations, &Language::EN, &mut resources).unwrap();
+    let resources = serde_json::from_slice::<serde_json::Value>(&resources).unwrap();
+
+    assert!(resources["cart"]["items_zero"] == "You have no items in {{place}}.");
+    assert!(resources["cart"]["items_one"] == "You have {{count}} item in {{place}}.");
+    assert!(resources["cart"]["items_other"] == "You have {{count}} items in {{place}}.");
+    assert!(resources["cart"]["discount"] == "Save 50% now, {{name}}!");
+    assert!(resources["cart"]["title"] == "Bob's \"cart\"");
+
+    let mut resources = Vec::new();
+    write_i18next(&translations, &Language::ES, &mut resources).unwrap();
+    let resources = serde_json::from_slice::<serde_json::Value>(&resources).unwrap();
+
+    assert!(resources["cart"].get("items_zero").is_none());
+    assert!(resources["cart"].get("title").is_none());
+
+    let directory = temp_dir().join(format!("translatable-export-i18next-{}", process::id()));
+    export_i18next(&translatable::embed_translations!(), &directory).unwrap();
+
+    let resources = read_to_string(directory.join("en/translation.json")).unwrap();
+    let resources = serde_json::from_str::<serde_json::Value>(&resources).unwrap();
+
+    assert!(resources["common"]["greeting"] == "Hello {{name}}!");
+}
+
+#[test]
+fn catalog_schema() {
+    let translations = translatable::embed_translations!();
+
+    let mut schema = Vec::new();
+    write_schema(&translations, &[Language::FR], &mut schema).unwrap();
+    let schema = serde_json::from_slice::<serde_json::Value>(&schema).unwrap();
+
+    assert!(schema["$schema"] == "http://json-schema.org/draft-07/schema#");
+    assert!(schema["properties"]["_priority"]["$ref"] == "#/definitions/priority");
+    assert!(schema["properties"]["_version"]["$ref"] == "#/definitions/version");
+    assert!(schema["additionalProperties"]["$ref"] == "#/definitions/node");
+
+    // Known paths are completed with the catalog and extra languages
+    let greeting = &schema["properties"]["common"]["properties"]["greeting"];
+    assert!(greeting["additionalProperties"] == false);
+    assert!(greeting["x-taplo"]["initKeys"] == serde_json::json!(["en", "es"]));
+    assert!(greeting["properties"]["_cfg"]["$ref"] == "#/definitions/cfg");
+    assert!(greeting["properties"]["fr"]["description"] == "French (FR), with {name}");
+    assert!(greeting["properties"]["en"]["allOf"][0]["pattern"] == "\\{\\s*name\\s*[|,}]");
+    assert!(greeting["properties"].get("de").is_none());
+
+    let farewell = &schema["properties"]["common"]["properties"]["farewell"];
+    assert!(farewell["properties"]["en"].get("allOf").is_none());
+
+    // New tables are validated as translation objects or nestings
+    let node = &schema["definitions"]["node"];
+    assert!(node["properties"]["es"]["$ref"] == "#/definitions/translation");
+    assert!(node["additionalProperties"]["$ref"] == "#/definitions/node");
+
+    let path = temp_dir()
+        .join(format!("translatable-export-schema-{}", process::id()))
+        .join("translations.schema.json");
+    export_schema(&translations, &[], &path).unwrap();
+
+    let exported =
+        serde_json::from_str::<serde_json::Value>(&read_to_string(path).unwrap()).unwrap();
+    assert!(
+        exported["properties"]["common"]["properties"]["greeting"]["properties"]
+            .get("fr")
+            .is_none()
+    );
+}
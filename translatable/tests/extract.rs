@@ -0,0 +1,55 @@
+#![cfg(feature = "extract")]
+
+use std::env::temp_dir;
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+use std::process;
+
+use translatable::Language;
+use translatable::extract::{extract_paths, extract_source, update_skeleton};
+
+#[test]
+fn source_paths() {
+    let paths = extract_source(
+        &read_to_string("tests/assets/sources/app.rs").unwrap(),
+        "tests/assets/sources/app.rs",
+    )
+    .unwrap();
+
+    assert!(paths == ["common.greeting", "checkout.title", "settings.page.title"]);
+    assert!(extract_source("fn main() { \"", "broken.rs").is_err());
+
+    let paths = extract_paths("tests/assets/sources").unwrap();
+    assert!(paths.len() == 3);
+    assert!(paths["checkout.title"].ends_with("app.rs"));
+}
+
+#[test]
+fn skeleton_entries() {
+    let directory = temp_dir().join(format!("translatable-extract-{}", process::id()));
+    let translations = directory.join("translations");
+    let skeleton = translations.join("untranslated.toml");
+
+    let _ = remove_dir_all(&directory);
+    create_dir_all(&translations).unwrap();
+    write(translations.join("app.toml"), "[common.greeting]\nen = \"Hello {name}!\"\n").unwrap();
+
+    let languages = [Language::EN, Language::ES];
+    let added =
+        update_skeleton("tests/assets/sources", &translations, &skeleton, &languages).unwrap();
+    assert!(added == ["checkout.title", "settings.page.title"]);
+
+    let content = read_to_string(&skeleton).unwrap();
+    assert!(content.contains(
+        "[checkout.title]\n# Found in tests/assets/sources/app.rs\nen = \"\" # TODO\nes = \"\" # \
+         TODO\n"
+    ));
+    assert!(content.parse::<toml::Table>().is_ok());
+
+    // Entries already in the skeleton aren't added again
+    let added =
+        update_skeleton("tests/assets/sources", &translations, &skeleton, &languages).unwrap();
+    assert!(added.is_empty());
+    assert!(read_to_string(&skeleton).unwrap() == content);
+
+    remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,72 @@
+use std::cell::Cell;
+
+use translatable::{Formatted, Language, MockTranslations, register_filter, translation_inline};
+
+#[test]
+fn builtin_filters() {
+    let language = "en";
+
+    assert!(
+        translation_inline!("en", { en = "Hello {name|capitalize}!" }, name = "jOHN")
+            == "Hello John!"
+    );
+    assert!(
+        translation_inline!(language, { en = "{name|upper} and {name|lower}" }, name = "John")
+            .unwrap()
+            == "JOHN and john"
+    );
+    assert!(
+        translation_inline!("en", { en = "{name|unknown} is {name}" }, name = "john")
+            == "john is john"
+    );
+}
+
+#[test]
+fn filtered_arguments_evaluated_once() {
+    let evaluations = Cell::new(0);
+    let name = || {
+        evaluations.set(evaluations.get() + 1);
+        "john"
+    };
+    let language = "en";
+
+    assert!(
+        translation_inline!("en", { en = "{name|upper} and {name}" }, name = name())
+            == "JOHN and john"
+    );
+    assert!(
+        translation_inline!(language, { en = "{name|capitalize}" }, name = name()).unwrap()
+            == "John"
+    );
+    assert!(evaluations.get() == 2);
+}
+
+#[test]
+fn chained_filters() {
+    let formatted = Formatted::new("{name|upper|lower|capitalize}", &[("name", &"jOHN")]);
+
+    assert!(formatted.to_string() == "John");
+}
+
+#[test]
+fn registered_filters() {
+    register_filter("initial", |value, language| {
+        let initial = value.chars().next().map(String::from).unwrap_or_default();
+
+        match language {
+            Some(Language::ES) => format!("{initial}."),
+            _ => initial,
+        }
+    });
+
+    let translator = MockTranslations::new()
+        .translation("signature", Language::EN, "{name|initial|upper}")
+        .translation("signature", Language::ES, "{name|initial|upper}")
+        .translator(Language::ES);
+
+    assert!(translator.get_with("signature", &[("name", &"john")]).unwrap() == "J.");
+    assert!(Formatted::new("{name|initial}", &[("name", &"john")]).to_string() == "j");
+    assert!(
+        translation_inline!("es", { es = "Firma: {name|initial}" }, name = "john") == "Firma: j."
+    );
+}
@@ -0,0 +1,41 @@
+use std::sync::Mutex;
+
+use translatable::{
+    Language, Translator, embed_translations, set_missing_translation_hook, translation,
+};
+
+/// Codes and paths of the failed lookups, shared by the whole test binary
+static FAILURES: Mutex<Vec<(&'static str, String)>> = Mutex::new(Vec::new());
+
+#[test]
+fn missing_translation_hook() {
+    let _ = set_missing_translation_hook(|error| {
+        let path = match error {
+            translatable::Error::PathNotFound { path } => path.clone(),
+            translatable::Error::LanguageNotAvailable { path, .. } => path.clone(),
+            _ => String::new(),
+        };
+
+        FAILURES.lock().unwrap().push((error.code(), path));
+    });
+
+    let language = "fr";
+    let path = "common.missing";
+
+    assert!(translation!("en", static welcome_message) == "Welcome to our app!");
+    assert!(translation!("en", path, default = "N/A") == "N/A");
+    assert!(translation!(language, static welcome_message).is_err());
+
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::EN, &translations);
+    assert!(translator.get("common.unknown").is_err());
+
+    assert!(
+        *FAILURES.lock().unwrap()
+            == [
+                ("path_not_found", "common.missing".to_string()),
+                ("language_not_available", "welcome_message".to_string()),
+                ("path_not_found", "common.unknown".to_string()),
+            ]
+    );
+}
@@ -0,0 +1,64 @@
+use translatable::{AssociatedTranslation, Language, TranslationNode, TranslationNodeCollection};
+
+#[test]
+fn collection_iteration() {
+    let library = translatable::include_translation_file!("tests/assets/library.toml");
+    let overrides = translatable::include_translation_file!("tests/assets/overrides.toml");
+    let collection =
+        library.clone().into_iter().chain(overrides).collect::<TranslationNodeCollection>();
+
+    assert!(collection.len() == 2);
+    assert!(!collection.is_empty() && TranslationNodeCollection::default().is_empty());
+    assert!(
+        collection.keys()
+            == ["common.greeting", "common.notice", "library.title", "welcome_message"]
+    );
+    assert!(
+        (&collection).into_iter().map(AssociatedTranslation::original_path).collect::<Vec<_>>()
+            == ["tests/assets/library.toml", "tests/assets/overrides.toml"]
+    );
+    assert!(library == translatable::include_translation_file!("tests/assets/library.toml"));
+}
+
+#[test]
+fn node_iteration() {
+    let library = translatable::include_translation_file!("tests/assets/library.toml");
+    let node = library.iter().next().unwrap().translation_table();
+
+    let mut keys = node.keys().collect::<Vec<_>>();
+    keys.sort();
+    assert!(keys == ["library", "welcome_message"]);
+    assert!(node.len() == 2);
+
+    let objects = node.objects();
+    assert!(
+        objects.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>()
+            == ["library.title", "welcome_message"]
+    );
+    assert!(objects[0].1[&Language::ES] == "Título de la biblioteca");
+
+    let (_, title) = node.iter().find(|(key, _)| *key == "library").unwrap();
+    assert!(matches!(title, TranslationNode::Object(_)));
+    assert!(title.objects()[0].1.len() == 2);
+    assert!(node.get_node(vec!["welcome_message"]).unwrap().iter().next().is_none());
+}
+
+#[test]
+fn path_patterns() {
+    let library = translatable::include_translation_file!("tests/assets/library.toml");
+    let overrides = translatable::include_translation_file!("tests/assets/overrides.toml");
+    let collection = overrides.into_iter().chain(library).collect::<TranslationNodeCollection>();
+
+    let paths = |pattern| {
+        collection.find_matching(pattern).into_iter().map(|(path, _)| path).collect::<Vec<_>>()
+    };
+
+    assert!(paths("common.*") == ["common.greeting", "common.notice"]);
+    assert!(paths("*.title") == ["library.title"]);
+    assert!(paths("**") == collection.keys());
+    assert!(paths("**.welcome_*") == ["welcome_message"]);
+    assert!(paths("*.*.*").is_empty() && paths("common").is_empty());
+
+    let (_, title) = &collection.find_matching("library.*")[0];
+    assert!(title[&Language::ES] == "Título de la biblioteca");
+}
@@ -0,0 +1,109 @@
+use translatable::{Language, TextDirection};
+
+#[test]
+fn native_names() {
+    assert!(Language::ES.native_name() == "Español");
+    assert!(Language::JA.native_name() == "日本語");
+    assert!(Language::NG.to_string() == "Ndonga" && Language::NG.native_name() == "Owambo");
+}
+
+#[test]
+fn text_direction() {
+    assert!(Language::EN.direction() == TextDirection::LeftToRight);
+    assert!(Language::AR.direction() == TextDirection::RightToLeft);
+    assert!(TextDirection::RightToLeft.to_string() == "rtl");
+}
+
+#[test]
+fn from_names() {
+    assert!(Language::from_name("spanish") == Some(Language::ES));
+    assert!(Language::from_autonym("ESPAÑOL") == Some(Language::ES));
+    assert!(Language::from_name("es").is_none());
+}
+
+#[test]
+fn suggestions() {
+    assert!(Language::suggestions("es-MX", 10).first() == Some(&Language::ES));
+    assert!(Language::suggestions("spansh", 10) == [Language::ES]);
+    assert!(Language::suggestions("Frnech", 10) == [Language::FR]);
+    assert!(Language::suggestions("Span", 10) == [Language::ES]);
+    assert!(Language::suggestions("xx", 10).is_empty());
+}
+
+#[test]
+fn suggestions_among() {
+    let shipped = [Language::EN, Language::ES];
+
+    assert!(Language::suggestions_among("es-MX", 10, &shipped) == [Language::ES]);
+    assert!(Language::suggestions_among("spansh", 10, &shipped) == [Language::ES]);
+    assert!(Language::suggestions_among("Frnech", 10, &shipped).is_empty());
+    assert!(Language::suggestions_among("pt-BR", 10, &shipped).is_empty());
+}
+
+#[test]
+fn from_locales() {
+    assert!(Language::from_locale("es_ES.UTF-8") == Some(Language::ES));
+    assert!(Language::from_locale("PT-br") == Some(Language::PT));
+    assert!(Language::from_locale("de_DE@euro") == Some(Language::DE));
+    assert!(Language::from_locale("C").is_none());
+    assert!(Language::from_locale("POSIX").is_none());
+}
+
+#[test]
+fn from_environment() {
+    // No other test in this binary reads the environment
+    unsafe {
+        std::env::set_var("LC_ALL", "");
+        std::env::set_var("LC_MESSAGES", "fr_FR.UTF-8");
+        std::env::set_var("LANG", "es_ES.UTF-8");
+    }
+    assert!(Language::from_env() == Some(Language::FR));
+
+    unsafe { std::env::remove_var("LC_MESSAGES") };
+    assert!(Language::from_env() == Some(Language::ES));
+
+    unsafe { std::env::set_var("LC_ALL", "C") };
+    assert!(Language::from_env().is_none());
+}
+
+#[test]
+fn language_codes() {
+    assert!(Language::ES.code() == "es");
+    assert!(Language::NG.code() == "ng");
+    assert!(Language::AB.code() == "ab");
+    assert!(Language::ZU.code() == "zu");
+}
+
+#[test]
+fn serde_code() {
+    assert!(serde_json::to_string(&Language::ES).unwrap() == "\"es\"");
+    assert!(serde_json::from_str::<Language>("\"ES\"").unwrap() == Language::ES);
+    assert!(serde_json::from_str::<Language>("\"xx\"").is_err());
+    assert!(serde_json::from_str::<Language>("\"Spanish\"").is_err());
+}
+
+#[test]
+fn clap_value_enum() {
+    use clap::ValueEnum;
+
+    assert!(Language::from_str("es", false) == Ok(Language::ES));
+    assert!(Language::from_str("ES", true) == Ok(Language::ES));
+    assert!(Language::from_str("xx", true).is_err());
+}
+
+#[test]
+fn unic_langid_interop() {
+    let identifier: unic_langid::LanguageIdentifier = "es-MX".parse().unwrap();
+
+    assert!(Language::try_from(&identifier) == Ok(Language::ES));
+    assert!(unic_langid::LanguageIdentifier::from(Language::ES) == "es");
+}
+
+#[test]
+fn language_tags_interop() {
+    let tag = language_tags::LanguageTag::parse("pt-BR").unwrap();
+
+    assert!(Language::try_from(&tag) == Ok(Language::PT));
+    assert!(Language::try_from(&language_tags::LanguageTag::parse("und").unwrap()).is_err());
+    assert!(language_tags::LanguageTag::from(Language::PT).as_str() == "pt");
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "leptos")]
+
+use leptos::prelude::{GetUntracked, Owner};
+use translatable::leptos::{provide_locale, use_language, use_locale, use_translation};
+use translatable::{Language, Locale, embed_translations, set_translations};
+
+#[test]
+fn reactive_locale() {
+    let _ = set_translations(embed_translations!());
+    let owner = Owner::new();
+    owner.set();
+
+    let locale = Locale::new(Language::EN);
+    let language = provide_locale(locale.clone());
+
+    assert!(use_locale() == locale);
+    assert!(language.get_untracked() == Language::EN);
+
+    locale.set_language(Language::ES);
+
+    assert!(use_language().get_untracked() == Language::ES);
+    assert!(
+        use_translation()
+            .get_untracked()
+            .get_with("common.greeting", &[("name", &"john")])
+            .unwrap()
+            == "¡Hola john!"
+    );
+
+    drop(owner);
+    locale.set_language(Language::EN);
+}
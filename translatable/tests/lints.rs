@@ -0,0 +1,87 @@
+use translatable::{
+    AssociatedTranslation, Language, Lint, TranslationNode, TranslationNodeCollection, lint_catalog,
+};
+
+/// Lints a catalog with every lint
+fn lint(translations: &str) -> Vec<String> {
+    let node = TranslationNode::try_from(translations.parse::<toml::Table>().unwrap()).unwrap();
+    let collection =
+        TranslationNodeCollection::new(vec![AssociatedTranslation::new("labels.toml", node)]);
+    let lints = [
+        Lint::DoubleSpaces,
+        Lint::TrailingWhitespace,
+        Lint::TerminalPunctuation,
+        Lint::Untranslated,
+        Lint::PlaceholderOnly,
+    ];
+
+    lint_catalog(&collection, &Language::EN, &lints).iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn catalog_lints() {
+    let findings = lint(
+        r#"
+        [clean]
+        en = "Are you sure?"
+        es = "¿Estás seguro？"
+
+        [spaces]
+        en = "Save  changes"
+        es = "Guardar cambios "
+
+        [question]
+        en = "Delete {count} files?"
+        es = "Borrar {count} archivos"
+
+        [copy]
+        en = "Settings"
+        es = "Settings"
+
+        [name]
+        en = "{name}"
+        es = "{name}:"
+
+        [progress]
+        en = "100%"
+        es = "100%"
+        "#,
+    );
+
+    assert_eq!(
+        findings,
+        [
+            "untranslated: the 'es' translation of 'copy' is a copy of the source language \
+             translation, in labels.toml",
+            "placeholder_only: the 'en' translation of 'name' only has templates, in labels.toml",
+            "terminal_punctuation: the 'es' translation of 'name' ends with other punctuation \
+             than the source language translation, in labels.toml",
+            "placeholder_only: the 'es' translation of 'name' only has templates, in labels.toml",
+            "terminal_punctuation: the 'es' translation of 'question' ends with other punctuation \
+             than the source language translation, in labels.toml",
+            "double_spaces: the 'en' translation of 'spaces' has double spaces, in labels.toml",
+            "trailing_whitespace: the 'es' translation of 'spaces' has trailing whitespace, in \
+             labels.toml",
+        ]
+    );
+}
+
+#[test]
+fn block_lints() {
+    let findings = lint(
+        r#"
+        [files]
+        en = "{count, plural, one {# file} other {# files}}"
+        es = "{count, plural, one {# archivo} other {# archivos}}"
+
+        [greetings]
+        en = ["Hi!", "Hello!"]
+        es = ["¡Hola!", "Buenas  tardes"]
+        "#,
+    );
+
+    assert_eq!(
+        findings,
+        ["double_spaces: the 'es' translation of 'greetings' has double spaces, in labels.toml"]
+    );
+}
@@ -0,0 +1,51 @@
+use translatable::{Formatted, Language, List, MockTranslations, format_list, translation_inline};
+
+#[test]
+fn language_conjunctions() {
+    assert!(format_list(&Language::EN, ["a"], true) == "a");
+    assert!(format_list(&Language::EN, ["a", "b"], true) == "a and b");
+    assert!(format_list(&Language::EN, ["a", "b", "c"], true) == "a, b, and c");
+    assert!(format_list(&Language::EN, ["a", "b", "c"], false) == "a, b and c");
+    assert!(format_list(&Language::ES, ["a", "b", "c"], true) == "a, b y c");
+    assert!(format_list(&Language::ES, ["Luis", "Inés"], true) == "Luis e Inés");
+    assert!(format_list(&Language::ES, ["agua", "hielo"], true) == "agua y hielo");
+    assert!(format_list(&Language::DE, [1, 2, 3], true) == "1, 2 und 3");
+    assert!(format_list(&Language::EN, Vec::<&str>::new(), true).is_empty());
+}
+
+#[test]
+fn list_filters() {
+    let names = vec!["Ana", "Luis", "Marta"];
+    let language = "en";
+
+    assert!(
+        translation_inline!("es", { es = "{names|list}" }, names = List(&names))
+            == "Ana, Luis y Marta"
+    );
+    assert!(
+        translation_inline!(
+            language,
+            { en = "{names|list_no_oxford|upper}" },
+            names = List(&names)
+        )
+        .unwrap()
+            == "ANA, LUIS AND MARTA"
+    );
+    assert!(
+        translation_inline!("en", { en = "{names} / {names|upper}" }, names = List(&names))
+            == "Ana, Luis, Marta / ANA, LUIS, MARTA"
+    );
+
+    let translator = MockTranslations::new()
+        .translation("guests", Language::FR, "Invités : {names|list}")
+        .translator(Language::FR);
+
+    assert!(
+        translator.get_with("guests", &[("names", &List(&names))]).unwrap()
+            == "Invités : Ana, Luis et Marta"
+    );
+    assert!(
+        Formatted::new("{names|list}", &[("names", &List(&names))]).to_string()
+            == "Ana, Luis, and Marta"
+    );
+}
@@ -0,0 +1,27 @@
+use std::sync::{Arc, Mutex};
+
+use translatable::{Language, Locale, embed_translations, set_translations};
+
+#[test]
+fn shared_language() {
+    let _ = set_translations(embed_translations!());
+    let locale = Locale::new(Language::EN);
+    let notified = Arc::new(Mutex::new(Vec::new()));
+
+    let subscriber = Arc::clone(&notified);
+    locale.subscribe(move |language| subscriber.lock().unwrap().push(language.clone()));
+
+    let clone = locale.clone();
+    clone.set_language(Language::ES);
+    clone.set_language(Language::ES);
+
+    assert!(locale.language() == Language::ES);
+    assert!(*notified.lock().unwrap() == [Language::ES]);
+    assert!(
+        locale.translator().get_with("common.greeting", &[("name", &"john")]).unwrap()
+            == "¡Hola john!"
+    );
+
+    assert!(locale == clone);
+    assert!(locale != Locale::new(Language::ES));
+}
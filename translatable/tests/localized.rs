@@ -0,0 +1,85 @@
+use translatable::{Error, Language, error_catalog};
+
+#[test]
+fn english_messages() {
+    let errors = [
+        Error::InvalidLanguage {
+            language: "xx".into(),
+            suggestions: Vec::new(),
+        },
+        Error::LanguageNotAvailable {
+            language: Language::FR,
+            path: "errors.not_found".into(),
+            available: vec![Language::EN, Language::ES],
+        },
+        Error::PathNotFound { path: "home.title".into() },
+        Error::MissingArgument {
+            template: "name".into(),
+            path: "common.greeting".into(),
+        },
+        Error::InvalidValue {
+            path: "calendar.first_weekday".into(),
+            language: Language::EN,
+        },
+    ];
+
+    // The English catalog matches the `Display` output
+    for error in errors {
+        assert!(error.localized(&Language::EN) == error.to_string());
+    }
+}
+
+#[test]
+fn localized_messages() {
+    let error = Error::LanguageNotAvailable {
+        language: Language::FR,
+        path: "errors.not_found".into(),
+        available: vec![Language::EN, Language::ES],
+    };
+    assert!(
+        error.localized(&Language::ES)
+            == "El idioma 'fr' no está disponible para la ruta 'errors.not_found'. Está \
+                disponible en en, es."
+    );
+
+    // Suggestions are named by their autonyms
+    let error = Error::InvalidLanguage {
+        language: "spansh".into(),
+        suggestions: vec![Language::ES],
+    };
+    assert!(
+        error.localized(&Language::DE)
+            == "Die Sprache 'spansh' ist ungültig. Ähnliche Sprachen: Español (ES)."
+    );
+
+    let error = Error::MissingArgument {
+        template: "name".into(),
+        path: "common.greeting".into(),
+    };
+    assert!(
+        error.localized(&Language::FR)
+            == "Le modèle '{name}' n'a pas d'argument dans la traduction 'common.greeting'."
+    );
+}
+
+#[test]
+fn english_fallback() {
+    let error = Error::PathNotFound { path: "home.title".into() };
+
+    assert!(error.localized(&Language::JA) == error.to_string());
+}
+
+#[test]
+fn complete_catalog() {
+    let languages =
+        [Language::EN, Language::ES, Language::FR, Language::DE, Language::PT, Language::IT];
+
+    for association in error_catalog() {
+        for (path, translation_object) in association.translation_table().objects() {
+            assert!(
+                languages.iter().all(|language| translation_object.contains_key(language)),
+                "{path}"
+            );
+        }
+    }
+}
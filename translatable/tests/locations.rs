@@ -0,0 +1,37 @@
+use translatable::{SourceLocation, embed_translations, error_catalog};
+
+#[test]
+fn embedded_locations() {
+    let translations = embed_translations!();
+
+    let (file_path, location) = translations.find_location("common.greeting").unwrap();
+    assert!(file_path.ends_with("test.toml"));
+    assert!(location.line() == 7);
+    assert!(location.column() == 9);
+    assert!(location.to_string() == "7:9");
+
+    // Languages are located in the file, not looked up
+    let file = translations.iter().find(|file| file.original_path() == file_path).unwrap();
+    assert!(file.location("common.greeting.en").is_some_and(|language| language.line() == 8));
+
+    assert!(translations.find_location("common.missing").is_none());
+}
+
+#[test]
+fn runtime_locations() {
+    let catalog = error_catalog();
+    let locations = catalog.path_locations("path_not_found");
+
+    assert!(locations.len() == 1);
+    assert!(locations[0].0 == "translatable/locales/errors.toml");
+    assert!(locations[0].1.is_some_and(|location| location.column() == 2));
+}
+
+#[test]
+fn offset_locations() {
+    let content = "[greeting]\nen = \"Hello\"\n";
+
+    assert!(SourceLocation::of_offset(content, 11) == SourceLocation::new(11, 2, 1));
+    assert!(SourceLocation::of_offset(content, 0) == SourceLocation::new(0, 1, 1));
+    assert!(SourceLocation::of_offset(content, 100).offset() == content.len());
+}
@@ -0,0 +1,99 @@
+use std::cell::Cell;
+use std::convert::Infallible;
+
+use translatable::{Language, MtProvider, TranslationNode, fill_missing};
+
+/// Provider prefixing every text with the target language code
+struct Prefixing {
+    /// Number of batches translated
+    batches: Cell<usize>,
+}
+
+impl MtProvider for Prefixing {
+    type Error = Infallible;
+
+    fn translate(
+        &self,
+        texts: &[&str],
+        _source: &Language,
+        target: &Language,
+    ) -> Result<Vec<String>, Infallible> {
+        self.batches.set(self.batches.get() + 1);
+
+        Ok(texts
+            .iter()
+            .map(|text| match *text {
+                // Translation services may translate template names
+                "Bye {name}!" => "Adieu {nom} !".to_string(),
+                text => format!("[{}] {text}", target.code()),
+            })
+            .collect())
+    }
+}
+
+const CATALOG: &str = r#"[common.greeting]
+en = "Hello {name}!"
+es = "¡Hola {name}!"
+
+[common.farewell]
+en = "Bye {name}!"
+
+[cart.items]
+en = "{count, plural, one {# item} other {# items}}"
+"#;
+
+fn catalog() -> TranslationNode {
+    TranslationNode::try_from(CATALOG.parse::<toml::Table>().unwrap()).unwrap()
+}
+
+#[test]
+fn filled_translations() {
+    let mut catalog = catalog();
+    let provider = Prefixing { batches: Cell::new(0) };
+
+    let report = fill_missing(
+        &mut catalog,
+        &Language::EN,
+        &[Language::EN, Language::ES, Language::FR],
+        &provider,
+    )
+    .unwrap();
+
+    assert!(provider.batches.get() == 2);
+    assert!(report.filled() == [(Language::FR, "common.greeting".to_string())]);
+    assert!(
+        report.skipped()
+            == [
+                (Language::ES, "cart.items".to_string()),
+                (Language::FR, "cart.items".to_string()),
+                (Language::ES, "common.farewell".to_string()),
+                (Language::FR, "common.farewell".to_string()),
+            ]
+    );
+
+    let greeting = catalog.get_path(vec!["common", "greeting"]).unwrap();
+    assert!(greeting[&Language::ES] == "¡Hola {name}!");
+    assert!(greeting[&Language::FR] == "[fr] Hello {name}!");
+    assert!(catalog.get_path(vec!["common", "farewell"]).unwrap().get(&Language::FR).is_none());
+}
+
+#[test]
+fn marked_translations() {
+    let mut catalog = catalog();
+    let provider = Prefixing { batches: Cell::new(0) };
+
+    let report = fill_missing(&mut catalog, &Language::EN, &[Language::FR], &provider).unwrap();
+    let document = report.mark_toml(&catalog.update_toml(CATALOG).unwrap()).unwrap();
+
+    assert!(document.contains("fr = \"[fr] Hello {name}!\" # machine-translated\n"));
+    assert!(document.contains("es = \"¡Hola {name}!\"\n"));
+
+    // Reviewed translations are updated keeping the mark until it's removed
+    catalog.set(vec!["common", "greeting"], Language::FR, "Bonjour {name} !").unwrap();
+    assert!(
+        catalog
+            .update_toml(&document)
+            .unwrap()
+            .contains("fr = \"Bonjour {name} !\" # machine-translated\n")
+    );
+}
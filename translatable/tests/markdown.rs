@@ -0,0 +1,49 @@
+#![cfg(feature = "markdown")]
+
+use translatable::{render_markdown, translation_markdown};
+
+#[test]
+fn rendered_translations() {
+    let language = "es";
+    let path = "help.upload";
+
+    assert!(
+        translation_markdown!(
+            "en",
+            static help::upload,
+            limit = "10 MB",
+            section = "files",
+            name = "<i>Ana</i>"
+        ) == "Files up to <strong>10 MB</strong>, see the <a href=\"https://example.com/files\">guide</a> \
+              or &lt;b&gt;ask&lt;/b&gt; &lt;i&gt;Ana&lt;/i&gt;."
+    );
+    assert!(
+        translation_markdown!(language, static help::upload, limit = "10 MB", section = "files", name = "Ana")
+            .unwrap()
+            == "Archivos de hasta <strong>10 MB</strong>, consulta la <a href=\"https://example.com/files\">guía</a> \
+                o &lt;b&gt;pregunta&lt;/b&gt; a Ana."
+    );
+    assert!(
+        translation_markdown!("en", path, limit = "1 GB", section = "", name = "<i>Ana</i>", escape = "none")
+            .unwrap()
+            == "Files up to <strong>1 GB</strong>, see the <a href=\"https://example.com/\">guide</a> \
+                or &lt;b&gt;ask&lt;/b&gt; <i>Ana</i>."
+    );
+}
+
+#[test]
+fn sanitized_markdown() {
+    assert!(
+        render_markdown("[click](javascript:alert(1)) <script>")
+            == "<a href=\"#\">click</a> &lt;script&gt;"
+    );
+    assert!(
+        render_markdown("[mail](mailto:ana@example.com)")
+            == "<a href=\"mailto:ana@example.com\">mail</a>"
+    );
+    assert!(
+        render_markdown("# Help\n\n- {first}\n- ~~second~~")
+            == "<h1>Help</h1>\n<ul>\n<li>{first}</li>\n<li><del>second</del></li>\n</ul>"
+    );
+    assert!(render_markdown("**Hi**\u{1e}*Hey*") == "<strong>Hi</strong>\u{1e}<em>Hey</em>");
+}
@@ -0,0 +1,131 @@
+use std::cell::Cell;
+use std::fmt::Write;
+
+use translatable::{Language, MockTranslations, Translator, embed_translations, translation};
+
+#[test]
+fn static_plurals() {
+    assert!(translation!("en", static messages::items, count = 0) == "No items");
+    assert!(translation!("en", static messages::items, count = 1) == "1 item");
+    assert!(translation!("es", static messages::items, count = 5) == "5 artículos");
+}
+
+#[test]
+fn dynamic_plurals() {
+    let language = "es";
+    let path = "messages.items";
+
+    assert!(translation!(language, static messages::items, count = 1).unwrap() == "1 artículo");
+    assert!(translation!("en", path, count = 3).unwrap() == "3 items");
+    assert!(translation!(language, path, count = 0).unwrap() == "Ningún artículo");
+}
+
+#[test]
+fn arguments_evaluated_once() {
+    let evaluations = Cell::new(0);
+    let count = || {
+        evaluations.set(evaluations.get() + 1);
+        2
+    };
+    let language = "en";
+
+    assert!(translation!("en", static messages::items, count = count()) == "2 items");
+    assert!(translation!(language, static messages::items, count = count()).unwrap() == "2 items");
+    assert!(evaluations.get() == 2);
+}
+
+#[test]
+fn select_arguments() {
+    let language = "en";
+
+    assert!(
+        translation!("es", static messages::invitation, gender = "female", name = "john")
+            == "Ella invitó a john."
+    );
+    assert!(
+        translation!(language, static messages::invitation, gender = "unknown", name = "john")
+            .unwrap()
+            == "They invited john."
+    );
+}
+
+#[test]
+fn written_plurals() {
+    let mut output = String::new();
+
+    translatable::translation_write!(&mut output, "en", static messages::items, count = 2).unwrap();
+    assert!(output == "2 items");
+}
+
+#[test]
+fn translator_plurals() {
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    assert!(translator.get_with("messages.items", &[("count", &1)]).unwrap() == "1 item");
+    assert!(
+        translator.format_with("messages.items", &[("count", &4)]).unwrap().to_string()
+            == "4 items"
+    );
+}
+
+#[test]
+fn language_plural_rules() {
+    let translator = MockTranslations::new()
+        .translation(
+            "apples",
+            Language::RU,
+            "{count, plural, one {# яблоко} few {# яблока} many {# яблок} other {# яблока}}",
+        )
+        .translator(Language::RU);
+
+    let apples = |count: &dyn std::fmt::Display| {
+        translator.get_with("apples", &[("count", count)]).unwrap().into_owned()
+    };
+
+    assert!(apples(&1) == "1 яблоко");
+    assert!(apples(&3) == "3 яблока");
+    assert!(apples(&5) == "5 яблок");
+    assert!(apples(&21) == "21 яблоко");
+    assert!(apples(&1.5) == "1.5 яблока");
+}
+
+#[test]
+fn conditional_blocks() {
+    let language = "es";
+
+    assert!(
+        translation!("en", static messages::member, is_admin = true, name = "Ana") == "Admin: Ana"
+    );
+    assert!(translation!("en", static messages::member, is_admin = false, name = "Ana") == "Ana");
+    assert!(
+        translation!(language, static messages::member, is_admin = true, name = "Ana").unwrap()
+            == "Administrador: Ana"
+    );
+
+    let translator = MockTranslations::new()
+        .translation(
+            "cart",
+            Language::EN,
+            "{count, plural, one {# item{?gift} (gift){/}} other {# items}}",
+        )
+        .translator(Language::EN);
+
+    assert!(
+        translator.get_with("cart", &[("count", &1), ("gift", &true)]).unwrap() == "1 item (gift)"
+    );
+    assert!(translator.get_with("cart", &[("count", &1), ("gift", &false)]).unwrap() == "1 item");
+}
+
+#[test]
+fn invalid_conditional_blocks() {
+    let parse = |translation: &str| {
+        let table = format!("[member]\nen = \"{translation}\"\n").parse::<toml::Table>().unwrap();
+        translatable::TranslationNode::try_from(table)
+    };
+
+    assert!(parse("{?is_admin}Admin: {/}{name}").is_ok());
+    assert!(parse("{?is_admin}Admin: {name}").is_err());
+    assert!(parse("Admin{/}: {name}").is_err());
+    assert!(parse("{?}Admin: {/}{name}").is_err());
+}
@@ -0,0 +1,31 @@
+use std::collections::BTreeMap;
+
+use translatable::{Language, TranslationNodeCollection};
+
+#[test]
+fn collection_migrations() {
+    let migrations = BTreeMap::from([
+        ("library.name".to_string(), "library.title".to_string()),
+        ("welcome".to_string(), "welcome_message".to_string()),
+    ]);
+    let translations = translatable::include_translation_file!("tests/assets/library.toml")
+        .with_migrations(migrations);
+
+    assert!(
+        translations.find_path("library.name").unwrap()[&Language::ES] == "Título de la biblioteca"
+    );
+    assert!(translations.find_path("welcome").is_some());
+
+    // Migrations within a subtree are kept relative to it
+    let subtree = translations.subtree("library").unwrap();
+    assert!(subtree.migrations().len() == 1 && subtree.find_path("name").is_some());
+
+    let decoded = TranslationNodeCollection::from_bytes(&translations.to_bytes()).unwrap();
+    assert!(decoded == translations);
+
+    let inherited = TranslationNodeCollection::default()
+        .with_migrations(BTreeMap::from([("welcome".to_string(), "library.title".to_string())]))
+        .inherit(translations, translatable::Precedence::Application);
+    assert!(inherited.migrate("welcome") == "library.title");
+    assert!(inherited.migrate("library.name") == "library.title");
+}
@@ -0,0 +1,27 @@
+use translatable::{Error, Language, MockTranslations, Translator};
+
+#[test]
+fn mocked_translations() {
+    let translations = MockTranslations::new()
+        .translation("common.greeting", Language::EN, "Hello {name}!")
+        .translations("checkout.title", [(Language::EN, "Checkout"), (Language::ES, "Pago")])
+        .build();
+    let translator = Translator::with_translations(Language::ES, &translations);
+
+    assert!(translator.get("checkout.title").unwrap() == "Pago");
+    assert!(matches!(
+        translator.get("common.greeting"),
+        Err(Error::LanguageNotAvailable { available, .. }) if available == [Language::EN]
+    ));
+    assert!(matches!(translator.get("common"), Err(Error::PathNotFound { .. })));
+}
+
+#[test]
+fn mocked_translator() {
+    let translator = MockTranslations::new()
+        .translation("common.greeting", Language::EN, "Hi {name}!")
+        .translation("common.greeting", Language::EN, "Hello {name}!")
+        .translator(Language::EN);
+
+    assert!(translator.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hello john!");
+}
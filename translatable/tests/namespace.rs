@@ -0,0 +1,28 @@
+use std::collections::BTreeMap;
+
+use translatable::{Language, Precedence};
+
+#[test]
+fn mounted_collections() {
+    let library = translatable::include_translation_file!("tests/assets/library.toml")
+        .with_migrations(BTreeMap::from([("welcome".to_string(), "welcome_message".to_string())]))
+        .with_namespace("apps.library");
+
+    assert!(
+        library.find_path("apps.library.library.title").unwrap()[&Language::EN] == "Library title"
+    );
+    assert!(library.find_path("library.title").is_none());
+    assert!(library.migrate("apps.library.welcome") == "apps.library.welcome_message");
+
+    let billing = translatable::include_translation_file!("tests/assets/overrides.toml")
+        .with_namespace("billing");
+    let merged = library.inherit(billing, Precedence::Application);
+
+    assert!(merged.find_path("billing.common.greeting").is_some());
+    assert!(
+        merged
+            .keys()
+            .iter()
+            .all(|path| path.starts_with("apps.library.") || path.starts_with("billing."))
+    );
+}
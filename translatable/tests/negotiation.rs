@@ -0,0 +1,14 @@
+use translatable::{Language, negotiate_language};
+
+#[test]
+fn accept_language() {
+    let available = [Language::EN, Language::ES];
+
+    assert!(negotiate_language("es-AR,es;q=0.9,en;q=0.8", &available) == Some(Language::ES));
+    assert!(negotiate_language("en;q=0.5, es ; q=0.7", &available) == Some(Language::ES));
+    assert!(negotiate_language("fr, de;q=0.9, en;q=0.1", &available) == Some(Language::EN));
+    assert!(negotiate_language("es;q=0.5, en;q=0.5", &available) == Some(Language::ES));
+    assert!(negotiate_language("fr, *;q=0.1", &available) == Some(Language::EN));
+    assert!(negotiate_language("es;q=0, fr", &available).is_none());
+    assert!(negotiate_language("", &available).is_none());
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "num-format")]
+
+use translatable::{Formatted, Language, MockTranslations, format_number, translation_inline};
+
+#[test]
+fn language_separators() {
+    assert!(format_number(&Language::EN, &1234567) == "1,234,567");
+    assert!(format_number(&Language::ES, &-1234.25) == "-1.234,25");
+    assert!(format_number(&Language::HI, &12345678) == "1,23,45,678");
+    assert!(format_number(&Language::EN, &123) == "123");
+    assert!(format_number(&Language::EN, &"N/A") == "N/A");
+}
+
+#[test]
+fn number_filter() {
+    let language = "es";
+
+    assert!(
+        translation_inline!("en", { en = "{count|number} items" }, count = 12500) == "12,500 items"
+    );
+    assert!(
+        translation_inline!(language, { es = "{count|number} artículos" }, count = 12500.5)
+            .unwrap()
+            == "12.500,5 artículos"
+    );
+
+    let translator = MockTranslations::new()
+        .translation("total", Language::DE, "Summe: {total|number}")
+        .translator(Language::DE);
+
+    assert!(translator.get_with("total", &[("total", &9999.99)]).unwrap() == "Summe: 9.999,99");
+    assert!(Formatted::new("{total|number}", &[("total", &9999)]).to_string() == "9999");
+}
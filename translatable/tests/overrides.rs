@@ -0,0 +1,42 @@
+use translatable::{
+    Language, LoadError, Translator, clear_overrides, embed_translations, load_overrides,
+    set_translations, translation,
+};
+
+#[test]
+fn layered_overrides() {
+    let _ = set_translations(embed_translations!());
+    let english = Translator::new(Language::EN);
+    let spanish = Translator::new(Language::ES);
+
+    load_overrides("tests/assets/overrides.toml").unwrap();
+
+    assert!(english.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hi john!");
+    assert!(english.get("common.notice").unwrap() == "We are performing maintenance.");
+    assert!(english.get("common.farewell").unwrap() == "Goodbye!");
+    assert!(spanish.get_with("common.greeting", &[("name", &"john")]).unwrap() == "¡Hola john!");
+    assert!(translation!("en", static common::greeting, name = "john") == "Hello john!");
+
+    let explicit = embed_translations!();
+    let explicit = Translator::with_translations(Language::EN, &explicit);
+    assert!(explicit.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hello john!");
+
+    assert!(matches!(
+        load_overrides("tests/assets/missing.toml"),
+        Err(LoadError::Io(_, path)) if path == "tests/assets/missing.toml"
+    ));
+    assert!(english.get("common.notice").is_ok());
+
+    clear_overrides();
+
+    assert!(english.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hello john!");
+    assert!(english.get("common.notice").is_err());
+}
+
+#[test]
+fn invalid_overrides() {
+    let error = load_overrides("Cargo.toml").unwrap_err();
+
+    assert!(matches!(error, LoadError::InvalidTomlFormat(..)));
+    assert!(error.to_string().starts_with("Invalid TOML structure in Cargo.toml"));
+}
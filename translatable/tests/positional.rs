@@ -0,0 +1,34 @@
+use translatable::translation_inline;
+
+#[test]
+fn static_positional_arguments() {
+    let name = "Alice";
+
+    assert!(
+        translation_inline!("en", { en = "{0} has {1} new messages" }, &name, 3)
+            == "Alice has 3 new messages"
+    );
+    assert!(
+        translation_inline!("es", { es = "{1} mensajes nuevos para {0}" }, "Alice", 3 + 1)
+            == "4 mensajes nuevos para Alice"
+    );
+    assert!(
+        translation_inline!(
+            "en",
+            { en = "{0} invited {name} {1} times" },
+            "Alice",
+            2,
+            name = "Bob"
+        ) == "Alice invited Bob 2 times"
+    );
+}
+
+#[test]
+fn dynamic_positional_arguments() {
+    let language = "es";
+
+    assert!(
+        translation_inline!(language, { en = "{0} of {1}", es = "{0} de {1}" }, 1, 10).unwrap()
+            == "1 de 10"
+    );
+}
@@ -0,0 +1,43 @@
+use translatable::{translation, translation_prefix};
+
+#[translation_prefix("common")]
+mod common {
+    use std::borrow::Cow;
+
+    use translatable::translation;
+
+    pub fn greeting(language: &str) -> Cow<'static, str> {
+        translation!(language, static greeting, name = "john").unwrap()
+    }
+
+    #[translatable::translation_prefix("nested")]
+    pub fn nested(path: &str) -> Result<Cow<'static, str>, translatable::Error> {
+        translation!("en", path)
+    }
+}
+
+#[test]
+fn module_prefix() {
+    assert!(common::greeting("es") == "¡Hola john!");
+    assert!(matches!(
+        common::nested("missing"),
+        Err(translatable::Error::PathNotFound { path }) if path == "common.nested.missing"
+    ));
+}
+
+#[test]
+#[translation_prefix("errors")]
+fn function_prefix() {
+    use std::fmt::Write;
+
+    let code = "forbidden";
+    let path = "not_found";
+
+    assert!(translation!("en", static not_found) == "The page was not found.");
+    assert!(translation!("en", static {code}).unwrap() == "Access denied.");
+    assert!(translation!("es", path).unwrap() == "No se encontró la página.");
+
+    let mut output = String::new();
+    translatable::translation_write!(&mut output, "en", static forbidden).unwrap();
+    assert!(output == "Access denied.");
+}
@@ -0,0 +1,149 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+use translatable::{
+    AsyncTranslationProvider, Language, MockTranslations, Precedence, TranslationNodeCollection,
+    TranslationProvider, Translator, embed_translations,
+};
+
+/// Provider returning a fixed catalog
+struct Catalog;
+
+impl TranslationProvider for Catalog {
+    type Error = Infallible;
+
+    fn fetch(&self) -> Result<TranslationNodeCollection, Infallible> {
+        Ok(MockTranslations::new()
+            .translation("common.greeting", Language::EN, "Hey {name}!")
+            .translation("remote.banner", Language::EN, "Sale!")
+            .build())
+    }
+}
+
+impl AsyncTranslationProvider for Catalog {
+    type Error = Infallible;
+
+    async fn fetch(&self) -> Result<TranslationNodeCollection, Infallible> {
+        TranslationProvider::fetch(self)
+    }
+}
+
+/// Wakes the thread blocked on a future
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls a future on the current thread until it completes
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(Unpark(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn merged_providers() {
+    let remote = TranslationProvider::fetch(&Catalog).unwrap();
+    let translations = embed_translations!().inherit(remote, Precedence::Application);
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    assert!(translator.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hello john!");
+    assert!(translator.get("remote.banner").unwrap() == "Sale!");
+
+    let remote = block_on(AsyncTranslationProvider::fetch(&Catalog)).unwrap();
+    let translations = embed_translations!().inherit(remote, Precedence::Dependency);
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    assert!(translator.get_with("common.greeting", &[("name", &"john")]).unwrap() == "Hey john!");
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use translatable::{
+        AsyncTranslationProvider, HttpProvider, Language, LoadError, TranslationProvider,
+        Translator,
+    };
+
+    use super::block_on;
+
+    /// Serves one response per expected request, returning the server URL
+    fn serve(responses: Vec<(&'static str, &'static str)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/translations.toml", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                // Requests missing the token are rejected
+                let mut authorized = false;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    authorized |= line.to_lowercase() == "authorization: bearer token\r\n";
+                    line.clear();
+                }
+
+                let status = if authorized { status } else { "401 Unauthorized" };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn http_provider() {
+        let url = serve(vec![
+            ("200 OK", "[remote.banner]\nen = \"Sale!\"\nes = \"¡Oferta!\"\n"),
+            ("200 OK", "[remote.banner]\nen = \"Sale!\"\n"),
+            ("404 Not Found", ""),
+            ("200 OK", "[remote]\nbanner = 1\n"),
+        ]);
+        let provider = HttpProvider::new(&url).header("Authorization", "Bearer token");
+
+        let remote = TranslationProvider::fetch(&provider).unwrap();
+        let translator = Translator::with_translations(Language::ES, &remote);
+        assert!(translator.get("remote.banner").unwrap() == "¡Oferta!");
+
+        let remote = block_on(AsyncTranslationProvider::fetch(&provider)).unwrap();
+        assert!(remote.find_path("remote.banner").is_some_and(|banner| banner.len() == 1));
+
+        let error = TranslationProvider::fetch(&provider).unwrap_err();
+        assert!(matches!(&error, LoadError::Http(_, source) if *source == url));
+
+        let error = TranslationProvider::fetch(&provider).unwrap_err();
+        assert!(matches!(error, LoadError::InvalidTomlFormat(..)));
+    }
+
+    #[test]
+    fn unauthorized_http_provider() {
+        let url = serve(vec![("200 OK", "")]);
+
+        let error = TranslationProvider::fetch(&HttpProvider::new(url)).unwrap_err();
+        assert!(error.to_string().contains("401"));
+    }
+}
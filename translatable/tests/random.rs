@@ -0,0 +1,54 @@
+use std::collections::BTreeSet;
+
+use translatable::{Language, Translator, embed_translations, set_random_seed, translation};
+
+const ENGLISH: [&str; 3] = ["Hi Ana!", "Hello Ana!", "Hey Ana!"];
+
+#[test]
+fn static_entries() {
+    set_random_seed(7);
+
+    let picked = (0..32)
+        .map(|_| translation!("en", static messages::greetings, name = "Ana"))
+        .collect::<BTreeSet<_>>();
+
+    assert!(picked.iter().all(|greeting| ENGLISH.contains(&greeting.as_str())));
+    assert!(picked.len() == ENGLISH.len());
+}
+
+#[test]
+fn runtime_entries() {
+    set_random_seed(7);
+
+    let greeting =
+        translation!("es".to_string(), static messages::greetings, name = "Ana").unwrap();
+    assert!(["¡Hola Ana!", "¡Buenas, Ana!"].contains(&greeting.as_ref()));
+
+    let path = "messages.greetings".to_string();
+    let greeting = translation!("en", path, name = "Ana").unwrap();
+    assert!(ENGLISH.contains(&greeting.as_ref()));
+}
+
+#[test]
+fn seeded_entries() {
+    let picks = |seed| {
+        set_random_seed(seed);
+        (0..8)
+            .map(|_| translation!("en", static messages::greetings, name = "Ana"))
+            .collect::<Vec<_>>()
+    };
+
+    assert!(picks(42) == picks(42));
+}
+
+#[test]
+fn translator_entries() {
+    set_random_seed(7);
+
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    let greeting = translator.get("messages.greetings").unwrap();
+    assert!(["Hi {name}!", "Hello {name}!", "Hey {name}!"].contains(&greeting.as_ref()));
+    assert!(translator.get("welcome_message").unwrap() == "Welcome to our app!");
+}
@@ -0,0 +1,9 @@
+use translatable::translation;
+
+fn main() {
+    let language = "Castellano";
+    let result = translation!(language, static common::greeting, name = "john");
+
+    assert!(translation!("es-ES", static common::greeting, name = "john") == "¡Hola john!");
+    assert!(result.unwrap() == "¡Hola john!");
+}
@@ -0,0 +1,30 @@
+use std::cell::Cell;
+
+use translatable::translation_inline;
+
+fn main() {
+    let language = "ar";
+
+    assert!(
+        translation_inline!("ar", { ar = "مرحبا {name}!" }, name = "john")
+            == "مرحبا \u{2068}john\u{2069}!"
+    );
+    assert!(
+        translation_inline!(language, { ar = "{name|upper} - {name}" }, name = "john").unwrap()
+            == "\u{2068}JOHN\u{2069} - \u{2068}john\u{2069}"
+    );
+    assert!(translation_inline!("en", { en = "Hello {name}!" }, name = "john") == "Hello john!");
+
+    // Isolated arguments are evaluated once, even when also filtered
+    let evaluations = Cell::new(0);
+    let name = || {
+        evaluations.set(evaluations.get() + 1);
+        "john"
+    };
+
+    assert!(
+        translation_inline!("ar", { ar = "{name|upper} - {name}" }, name = name())
+            == "\u{2068}JOHN\u{2069} - \u{2068}john\u{2069}"
+    );
+    assert!(evaluations.get() == 1);
+}
@@ -0,0 +1,5 @@
+use translatable::translation;
+
+fn main() {
+    assert!(translation!("en", static welcome_message) == "Welcome to our app!");
+}
@@ -0,0 +1,15 @@
+use translatable::{Language, translation};
+
+fn main() {
+    let language = "fr";
+
+    assert!(matches!(
+        translation!(language, static welcome_message),
+        Err(translatable::Error::LanguageNotAvailable { .. })
+    ));
+    assert!(translation!("es", static welcome_message) == "¡Bienvenido!");
+
+    let translations = translatable::embed_translations!();
+    let welcome_message = translations.find_path("welcome_message").unwrap();
+    assert!(welcome_message.len() == 2 && !welcome_message.contains_key(&Language::FR));
+}
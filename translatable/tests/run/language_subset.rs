@@ -0,0 +1,16 @@
+#![deny(unreachable_patterns)]
+
+use translatable::translation;
+
+fn main() {
+    for language in ["en", "English", "es", "spanish"] {
+        assert!(translation!(language, static welcome_message).is_ok());
+    }
+
+    let language = "fr";
+
+    assert!(matches!(
+        translation!(language, static welcome_message),
+        Err(translatable::Error::InvalidLanguage { .. })
+    ));
+}
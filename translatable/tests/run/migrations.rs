@@ -0,0 +1,28 @@
+#![allow(deprecated)]
+
+use std::borrow::Cow;
+
+use translatable::{Language, Translator, translation, translations};
+
+fn main() {
+    let path = String::from("common.welcome");
+    assert!(matches!(translation!("en", path), Ok(Cow::Borrowed("Welcome to our app!"))));
+
+    let (migrated, welcome) = translations!("en", static common::welcome, static welcome_message);
+    assert!(migrated == welcome);
+
+    let language = "es";
+    let (welcome,) = translations!(language, static common::welcome).unwrap();
+    assert!(welcome == "¡Bienvenido a nuestra aplicación!");
+
+    let translations = translatable::embed_translations!();
+    assert!(translations.migrate("common.welcome") == "welcome_message");
+    assert!(translations.migrate("common.greeting") == "common.greeting");
+
+    // Migrated paths are located at their new path
+    let (_, migrated) = translations.find_location("common.welcome").unwrap();
+    assert!(migrated.line() == 2);
+
+    let translator = Translator::with_translations(Language::ES, &translations);
+    assert!(translator.get("common.welcome").unwrap() == "¡Bienvenido a nuestra aplicación!");
+}
@@ -0,0 +1,12 @@
+use translatable::translation;
+
+fn main() {
+    let language = "es";
+    let result = translation!(language, "common.greeting");
+
+    assert!(matches!(
+        result,
+        Err(translatable::Error::MissingArgument { template, path })
+            if template == "name" && path == "common.greeting"
+    ));
+}
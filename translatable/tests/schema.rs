@@ -0,0 +1,51 @@
+use translatable::{KeyKind, Language, TranslationNode};
+use translatable_shared::TransformError;
+
+/// Converts a translation file the way the macros do
+fn parse(content: &str) -> Result<TranslationNode, TransformError> {
+    TranslationNode::try_from(content.parse::<toml::Table>().unwrap())
+}
+
+#[test]
+fn key_kinds() {
+    let table =
+        "_note = 'draft'\nen = 'Hello'\n[greeting]\nen = 'Hi'\n".parse::<toml::Table>().unwrap();
+
+    assert!(KeyKind::of("_note", &table["_note"]) == KeyKind::Metadata);
+    assert!(KeyKind::of("en", &table["en"]) == KeyKind::Language);
+    assert!(KeyKind::of("greeting", &table["greeting"]) == KeyKind::Nesting);
+}
+
+#[test]
+fn valid_tables() {
+    let node = parse("[menu.open]\nen = 'Open'\nes = ['Abrir', 'Abre']\n").unwrap();
+    assert!(node.get_path(vec!["menu", "open"]).unwrap()[&Language::EN] == "Open");
+
+    let values = parse("[_values.calendar]\nen = '0'\n").unwrap();
+    assert!(values.get_path(vec!["_values", "calendar"]).is_some());
+}
+
+#[test]
+fn invalid_tables() {
+    assert!(matches!(
+        parse("[menu]\n_comment = 'Main menu'\n[menu.open]\nen = 'Open'\n"),
+        Err(TransformError::UnknownMetadata(key)) if key == "_comment"
+    ));
+
+    let mixed = parse("[menu]\nen = 'Menu'\n[menu.open]\nen = 'Open'\n").unwrap_err();
+    assert!(matches!(
+        &mixed,
+        TransformError::MixedTable { language, nesting } if language == "en" && nesting == "open"
+    ));
+    assert!(mixed.to_string().contains("'en' with the nesting 'open'"));
+
+    assert!(matches!(
+        parse("[menu.open]\nklingon = 'Open'\n"),
+        Err(TransformError::UnknownLanguage(key)) if key == "klingon"
+    ));
+    assert!(matches!(
+        parse("[menu.open]\nen = 1\n"),
+        Err(TransformError::UnexpectedValue(key)) if key == "en"
+    ));
+    assert!(matches!(parse("[menu]\n"), Err(TransformError::EmptyTable)));
+}
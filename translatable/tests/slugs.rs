@@ -0,0 +1,25 @@
+use translatable::{Language, Slugs};
+
+static SLUGS: Slugs = translatable::translation_slugs!("routes");
+
+#[test]
+fn slug_lookups() {
+    assert!(SLUGS.slug("settings", &Language::ES) == Some("ajustes"));
+    assert!(SLUGS.slug("settings", &Language::FR).is_none());
+    assert!(SLUGS.key("perfil", &Language::ES) == Some("profile"));
+    assert!(SLUGS.key("perfil", &Language::EN).is_none());
+}
+
+#[test]
+fn localized_paths() {
+    assert!(SLUGS.path(&Language::ES, ["profile", "42"]).unwrap() == "/es/perfil/42");
+    assert!(SLUGS.path(&Language::FR, ["profile"]).is_none());
+
+    let (language, segments) = SLUGS.resolve("/es/perfil/42/").unwrap();
+    assert!(language == Language::ES);
+    assert!(segments == ["profile", "42"]);
+    assert!(SLUGS.resolve("/ajustes").is_none());
+
+    assert!(SLUGS.translate("/es/ajustes", &Language::EN).unwrap() == "/en/settings");
+    assert!(SLUGS.translate("/en/settings", &Language::EN).unwrap() == "/en/settings");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "sqlx")]
+
+use sqlx::AnyPool;
+use sqlx::any::{AnyPoolOptions, install_default_drivers};
+use translatable::{AsyncTranslationProvider, Language, LoadError, SqlxProvider, Translator};
+
+/// Creates an in-memory database with a translations table
+async fn database(rows: &[(&str, &str, &str)]) -> AnyPool {
+    install_default_drivers();
+
+    // Every connection has its own in-memory database
+    let pool = AnyPoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query("CREATE TABLE translations (path TEXT, language TEXT, translation TEXT)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for (path, language, translation) in rows {
+        sqlx::query("INSERT INTO translations VALUES ($1, $2, $3)")
+            .bind(*path)
+            .bind(*language)
+            .bind(*translation)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    pool
+}
+
+#[tokio::test]
+async fn database_rows() {
+    let pool = database(&[
+        ("landing.title", "en", "Spring sale"),
+        ("landing.title", "es", "Rebajas de primavera"),
+        ("landing.cta", "en", "Buy {count, plural, one {# item} other {# items}}"),
+    ])
+    .await;
+
+    let translations = SqlxProvider::new(pool.clone()).fetch().await.unwrap();
+    let translator = Translator::with_translations(Language::ES, &translations);
+    assert!(translator.get("landing.title").unwrap() == "Rebajas de primavera");
+
+    let translations = SqlxProvider::new(pool)
+        .query("SELECT path, language, translation FROM translations WHERE language = 'en'")
+        .fetch()
+        .await
+        .unwrap();
+    let translator = Translator::with_translations(Language::EN, &translations);
+    assert!(translator.get_with("landing.cta", &[("count", &2)]).unwrap() == "Buy 2 items");
+    assert!(translations.find_path("landing.title").is_some_and(|title| title.len() == 1));
+}
+
+#[tokio::test]
+async fn invalid_database_rows() {
+    let pool =
+        database(&[("landing.title", "en", "Sale"), ("landing.title.short", "en", "Sale")]).await;
+    let error = SqlxProvider::new(pool).fetch().await.unwrap_err();
+    assert!(matches!(error, LoadError::InvalidTomlFormat(..)));
+
+    let pool = database(&[("landing.title", "xx", "Sale")]).await;
+    let error = SqlxProvider::new(pool.clone()).fetch().await.unwrap_err();
+    assert!(matches!(error, LoadError::InvalidTomlFormat(..)));
+
+    let error = SqlxProvider::new(pool).query("SELECT * FROM missing").fetch().await.unwrap_err();
+    assert!(matches!(error, LoadError::Database(_, query) if query == "SELECT * FROM missing"));
+}
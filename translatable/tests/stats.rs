@@ -0,0 +1,28 @@
+use translatable::{Language, translation_stats};
+
+#[test]
+fn translation_coverage() {
+    let stats = translation_stats!();
+
+    assert!(stats.total_keys() == 12);
+    assert!(stats.missing() == [(Language::ES, "common.farewell")]);
+    assert!(stats.completion() == 23.0 / 24.0 * 100.0);
+
+    let languages = stats.languages().iter().map(|stats| stats.language()).collect::<Vec<_>>();
+    assert!(languages == [&Language::EN, &Language::ES]);
+    assert!(stats.language(&Language::EN).unwrap().completion() == 100.0);
+    assert!(stats.language(&Language::ES).unwrap().translated() == 11);
+    assert!(stats.language(&Language::FR).is_none());
+}
+
+#[test]
+fn complete_translations() {
+    translatable::assert_translations_complete!(prefix = "errors");
+    translatable::assert_translations_complete!(languages = ["en"]);
+}
+
+#[test]
+#[should_panic(expected = "incomplete, 1 missing:\n  - common.farewell: es")]
+fn incomplete_translations() {
+    translatable::assert_translations_complete!(prefix = "common");
+}
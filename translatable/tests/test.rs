@@ -1,3 +1,7 @@
+#![allow(clippy::cmp_owned)]
+
+use std::borrow::Cow;
+
 use translatable::translation;
 
 #[test]
@@ -30,3 +34,212 @@ fn both_dynamic() {
 
     assert!(result.unwrap() == "¡Hola john!".to_string())
 }
+
+#[test]
+fn validated_languages() {
+    let language: translatable::Language = "Es".parse().unwrap();
+
+    for name in ["john", "jane"] {
+        let result = translation!(&language, static common::greeting, name = name);
+        assert!(result.unwrap() == format!("¡Hola {name}!"));
+    }
+
+    assert!(
+        translation!(language.clone(), "common.greeting", name = "john").unwrap() == "¡Hola john!"
+    );
+    assert!(matches!(
+        translation!(translatable::Language::ZU, static common::greeting, name = "john"),
+        Err(translatable::Error::LanguageNotAvailable { .. })
+    ));
+}
+
+#[test]
+fn invalid_language_suggestions() {
+    let language = "Spanis";
+    let result = translation!(language, static common::greeting, name = "john");
+
+    assert!(matches!(
+        &result,
+        Err(translatable::Error::InvalidLanguage { suggestions, .. })
+            if suggestions == &[translatable::Language::ES]
+    ));
+    assert!(
+        result.unwrap_err().to_string()
+            == "The language 'Spanis' is invalid. Similar languages are Spanish (ES)."
+    );
+
+    let language = "portugese";
+    assert!(matches!(
+        translation!(language, static common::greeting, name = "john"),
+        Err(translatable::Error::InvalidLanguage { suggestions, .. })
+            if suggestions == [translatable::Language::PT]
+    ));
+
+    let language = "spanish";
+    assert!(
+        translation!(language, static common::greeting, name = "john").unwrap() == "¡Hola john!"
+    );
+}
+
+#[test]
+fn runtime_kwargs() {
+    let language = "es";
+    let map = std::collections::HashMap::from([("name", "john".to_string())]);
+    let slice: &[(&str, &dyn std::fmt::Display)] = &[("name", &"john")];
+
+    assert!(translation!("es", static common::greeting, ..&map) == "¡Hola john!");
+    assert!(translation!(language, "common.greeting", ..slice).unwrap() == "¡Hola john!");
+}
+
+#[test]
+fn missing_argument() {
+    let language = "es";
+    let result = translation!(language, "common.greeting");
+
+    // The raw template is kept unless `missing_arguments = "error"`
+    assert!(result.unwrap() == "¡Hola {name}!")
+}
+
+#[test]
+fn fallback_default() {
+    let language = "es";
+    let invalid = "xx";
+
+    assert!(translation!(language, "common.missing", default = "N/A") == "N/A");
+    assert!(
+        translation!(invalid, static common::greeting, name = "john", default = "N/A") == "N/A"
+    );
+    assert!(
+        translation!(language, "common.greeting", name = "john", default = "N/A") == "¡Hola john!"
+    );
+}
+
+#[test]
+fn try_translation_static() {
+    let result = translatable::try_translation!("es", static common::greeting, name = "john");
+
+    assert!(result.unwrap() == "¡Hola john!")
+}
+
+#[test]
+fn translation_or_key_fallback() {
+    let language = "es";
+    let path = String::from("common.missing");
+
+    assert!(translatable::translation_or_key!(language, path) == "common.missing");
+    assert!(translatable::translation_or_key!("en", "common.missing") == "common.missing");
+    assert!(
+        translatable::translation_or_key!(language, static welcome_message)
+            == "¡Bienvenido a nuestra aplicación!"
+    );
+    assert!(
+        translatable::translation_or_key!("es", static welcome_message)
+            == "¡Bienvenido a nuestra aplicación!"
+    );
+}
+
+#[test]
+fn batch_translations() {
+    let language = "es";
+    let invalid = "xx";
+
+    let (greeting, welcome) =
+        translatable::translations!("es", static common::greeting, static welcome_message);
+    assert!(greeting == "¡Hola {name}!" && welcome == "¡Bienvenido a nuestra aplicación!");
+
+    let (greeting, welcome) =
+        translatable::translations!(language, static common::greeting, static welcome_message)
+            .unwrap();
+    assert!(greeting == "¡Hola {name}!" && welcome == "¡Bienvenido a nuestra aplicación!");
+
+    assert!(matches!(
+        translatable::translations!(invalid, static welcome_message),
+        Err(translatable::Error::InvalidLanguage { .. })
+    ));
+}
+
+#[test]
+fn constant_folding() {
+    let result = translation!(concat!("e", "s"), concat!("common", ".greeting"), name = "john");
+    let language = "es";
+
+    assert!(result.unwrap() == "¡Hola john!");
+    assert!(
+        translation!(language, ("welcome_message")).unwrap() == "¡Bienvenido a nuestra aplicación!"
+    );
+}
+
+#[test]
+fn interpolated_path() {
+    let language = "es";
+    let code = "forbidden";
+    let missing = "teapot";
+
+    assert!(translation!("en", static errors::{code}).unwrap() == "Access denied.");
+    assert!(
+        translation!(language, static errors::{"not_found"}).unwrap()
+            == "No se encontró la página."
+    );
+    assert!(matches!(
+        translation!("en", static errors::{missing}),
+        Err(translatable::Error::PathNotFound { path }) if path == "errors.teapot"
+    ));
+    assert!(translatable::translation_or_key!("en", static errors::{missing}) == "errors.teapot");
+}
+
+#[test]
+fn inline_translation() {
+    let language = "es";
+
+    assert!(
+        translatable::translation_inline!("en", { en = "Hello {name}!", es = "¡Hola {name}!" }, name = "john")
+            == "Hello john!"
+    );
+    assert!(
+        translatable::translation_inline!(language, { en = "Bye!", es = "¡Adiós!" }).unwrap()
+            == "¡Adiós!"
+    );
+    assert!(translatable::translation_inline!(language, { en = "Bye!" }, default = "N/A") == "N/A");
+}
+
+#[test]
+fn borrowed_translations() {
+    let language = "en";
+    let path = "errors.not_found";
+
+    assert!(matches!(translation!(language, static errors::not_found), Ok(Cow::Borrowed(_))));
+    assert!(matches!(translation!("en", path), Ok(Cow::Borrowed("The page was not found."))));
+    assert!(matches!(
+        translation!(language, static common::greeting, name = "john"),
+        Ok(Cow::Owned(_))
+    ));
+    assert!(matches!(
+        translation!(language, static errors::not_found, name = "john"),
+        Ok(Cow::Borrowed("The page was not found."))
+    ));
+}
+
+#[test]
+fn structured_errors() {
+    let language = "fr";
+    let path = "errors.not_found";
+    let error = translation!(language, path).unwrap_err();
+
+    assert!(error.code() == "language_not_available");
+    assert!(
+        error
+            == translatable::Error::LanguageNotAvailable {
+                language: translatable::Language::FR,
+                path: path.to_string(),
+                available: vec![translatable::Language::EN, translatable::Language::ES],
+            }
+    );
+    assert!(
+        error.to_string()
+            == "The language 'fr' is not available for the path 'errors.not_found'. It's \
+                available in en, es."
+    );
+    let invalid = "xx";
+    assert!(translation!(invalid, path).unwrap_err().code() == "invalid_language");
+    assert!(translation!("en", language).unwrap_err().code() == "path_not_found");
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "tonic")]
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use http::{HeaderName, Request};
+use tower_layer::Layer;
+use tower_service::Service;
+use translatable::Language;
+use translatable::tonic::{LanguageScopeLayer, current_language, request_language, scope_language};
+use translatable::tower::LocaleLayer;
+
+/// Service answering with the task-local language and the one in the
+/// request extensions
+struct Echo;
+
+impl Service<Request<()>> for Echo {
+    type Response = (Option<Language>, Option<Language>);
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<()>) -> Self::Future {
+        let extension = request.extensions().get::<Language>().cloned();
+
+        Box::pin(async move { Ok((current_language(), extension)) })
+    }
+}
+
+#[tokio::test]
+async fn task_local_language() {
+    let locale = LocaleLayer::new(Language::EN, [Language::EN, Language::ES])
+        .header(HeaderName::from_static("x-language"));
+    let mut service = LanguageScopeLayer::from(locale).layer(Echo);
+
+    let request = Request::builder().header("accept-language", "es-ES").body(()).unwrap();
+    assert!(service.call(request).await.unwrap() == (Some(Language::ES), Some(Language::ES)));
+
+    let request = Request::builder().header("x-language", "en").body(()).unwrap();
+    assert!(service.call(request).await.unwrap() == (Some(Language::EN), Some(Language::EN)));
+
+    assert!(current_language().is_none());
+    assert!(scope_language(Language::ES, async { current_language() }).await == Some(Language::ES));
+
+    let request = tonic::Request::new(());
+    assert!(request_language(&request).is_none());
+    assert!(
+        scope_language(Language::ES, async { request_language(&request) }).await
+            == Some(Language::ES)
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+use std::future::{Ready, ready};
+use std::task::{Context, Poll};
+
+use http::{HeaderName, Request};
+use tower_layer::Layer;
+use tower_service::Service;
+use translatable::Language;
+use translatable::tower::LocaleLayer;
+
+/// Service answering with the language in the request extensions
+struct Echo;
+
+impl Service<Request<()>> for Echo {
+    type Response = Option<Language>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<()>) -> Self::Future {
+        ready(Ok(request.extensions().get::<Language>().cloned()))
+    }
+}
+
+fn request(headers: &[(&str, &str)]) -> Request<()> {
+    headers
+        .iter()
+        .fold(Request::builder(), |request, (name, value)| request.header(*name, *value))
+        .body(())
+        .unwrap()
+}
+
+#[test]
+fn request_language() {
+    let layer = LocaleLayer::new(Language::EN, [Language::EN, Language::ES])
+        .header(HeaderName::from_static("x-language"));
+    let mut service = layer.layer(Echo);
+    let mut language = |headers| service.call(request(headers)).into_inner().unwrap();
+
+    assert!(language(&[("accept-language", "es-AR,en;q=0.5")]) == Some(Language::ES));
+    assert!(
+        language(&[("accept-language", "fr"), ("accept-language", "es")]) == Some(Language::ES)
+    );
+    assert!(language(&[("accept-language", "es"), ("x-language", "en_US")]) == Some(Language::EN));
+    assert!(language(&[("accept-language", "es"), ("x-language", "fr")]) == Some(Language::ES));
+    assert!(language(&[("accept-language", "fr")]) == Some(Language::EN));
+    assert!(language(&[]) == Some(Language::EN));
+}
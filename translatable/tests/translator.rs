@@ -0,0 +1,159 @@
+use translatable::{Error, Language, Precedence, Translator, embed_translations, set_translations};
+
+#[test]
+fn global_translations() {
+    let _ = set_translations(embed_translations!());
+    let translator = Translator::new(Language::ES);
+
+    assert!(translator.get_with("common.greeting", &[("name", &"john")]).unwrap() == "¡Hola john!");
+    assert!(matches!(translator.get("common.missing"), Err(Error::PathNotFound { .. })));
+}
+
+#[test]
+fn explicit_translations() {
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::FR, &translations);
+
+    assert!(matches!(translator.get("welcome_message"), Err(Error::LanguageNotAvailable { .. })));
+}
+
+#[test]
+fn included_file() {
+    let translations = translatable::include_translation_file!("tests/assets/library.toml");
+    let translator = Translator::with_translations(Language::ES, &translations);
+
+    assert!(translator.get("library.title").unwrap() == "Título de la biblioteca");
+}
+
+#[test]
+fn target_variants() {
+    let translations = translatable::include_translation_file!("tests/assets/shortcuts.toml");
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    let expected =
+        if cfg!(target_os = "macos") { "Press Cmd+C to copy" } else { "Press Ctrl+C to copy" };
+    assert!(translator.get("shortcuts.copy").unwrap() == expected);
+    assert!(translator.get("shortcuts.copy.macos").is_err());
+    assert!(translator.get("shortcuts.linux").unwrap() == "Linux shortcuts");
+}
+
+#[test]
+fn trimmed_translations() {
+    let translations = translatable::include_translation_file!("tests/assets/onboarding.toml");
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    assert!(
+        translator.get("onboarding.intro").unwrap()
+            == "Welcome to the app.\n  Follow the steps below\nto get started."
+    );
+    assert!(translator.get("onboarding.raw").unwrap() == "  Kept as is\n");
+}
+
+#[test]
+fn inherited_translations() {
+    let library = || translatable::include_translation_file!("tests/assets/library.toml");
+
+    let translations = embed_translations!().inherit(library(), Precedence::Application);
+    let translator = Translator::with_translations(Language::EN, &translations);
+    assert!(translator.get("library.title").unwrap() == "Library title");
+    assert!(translator.get("welcome_message").unwrap() == "Welcome to our app!");
+
+    let translations = embed_translations!().inherit(library(), Precedence::Dependency);
+    let translator = Translator::with_translations(Language::EN, &translations);
+    assert!(translator.get("welcome_message").unwrap() == "Welcome to the library!");
+}
+
+#[test]
+fn translation_sources() {
+    let library = || translatable::include_translation_file!("tests/assets/library.toml");
+
+    let translations = embed_translations!().inherit(library(), Precedence::Dependency);
+    let (translation_object, source) =
+        translations.find_path_with_source("welcome_message").unwrap();
+    assert!(translation_object[&Language::EN] == "Welcome to the library!");
+    assert!(source.ends_with("library.toml"));
+
+    let sources = translations.path_sources("welcome_message");
+    assert!(sources.len() == 2);
+    assert!(sources[1].ends_with("test.toml"));
+
+    let translations = embed_translations!().inherit(library(), Precedence::Application);
+    let translator = Translator::with_translations(Language::EN, &translations);
+    assert!(translator.source("welcome_message").unwrap().ends_with("test.toml"));
+    assert!(translator.source("library.title").unwrap().ends_with("library.toml"));
+    assert!(translator.source("common.missing").is_none());
+
+    let translator = Translator::with_translations(Language::FR, &translations);
+    assert!(translator.source("welcome_message").is_none());
+}
+
+#[test]
+fn language_catalog() {
+    let library = translatable::include_translation_file!("tests/assets/library.toml");
+    let translations = embed_translations!().inherit(library, Precedence::Dependency);
+
+    let catalog = Translator::with_translations(Language::ES, &translations).catalog();
+    assert!(catalog["common.greeting"] == "¡Hola {name}!");
+    assert!(catalog["library.title"] == "Título de la biblioteca");
+    assert!(!catalog.contains_key("welcome_message"));
+
+    let catalog = Translator::with_translations(Language::FR, &translations).catalog();
+    assert!(!catalog.contains_key("common.greeting"));
+}
+
+#[test]
+fn binary_embedding() {
+    let translations = embed_translations!();
+    let decoded = translatable::TranslationNodeCollection::from_bytes(&translations.to_bytes());
+
+    assert!(decoded.and_then(|decoded| decoded.find_path("common.greeting").cloned()).is_some());
+    assert!(translatable::TranslationNodeCollection::from_bytes(b"\x01\x00").is_none());
+
+    let prioritized = translatable::TranslationNodeCollection::new(
+        translations.iter().map(|translation| translation.clone().with_priority(-2)).collect(),
+    );
+    let decoded =
+        translatable::TranslationNodeCollection::from_bytes(&prioritized.to_bytes()).unwrap();
+    assert!(decoded.iter().all(|translation| translation.priority() == -2));
+}
+
+#[test]
+fn deterministic_embedding() {
+    let bytes = embed_translations!().to_bytes();
+
+    // Decoded maps are rebuilt with their own iteration order
+    for _ in 0..8 {
+        let decoded = translatable::TranslationNodeCollection::from_bytes(&bytes).unwrap();
+        assert!(decoded.to_bytes() == bytes);
+    }
+}
+
+#[test]
+fn compressed_embedding() {
+    let translations = embed_translations!();
+    let decoded = translatable::TranslationNodeCollection::from_compressed_bytes(
+        &translations.to_compressed_bytes(),
+    );
+
+    assert!(decoded.and_then(|decoded| decoded.find_path("common.greeting").cloned()).is_some());
+}
+
+#[test]
+fn formatted_translations() {
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::EN, &translations);
+
+    let formatted = translator.format_with("common.greeting", &[("name", &"john")]).unwrap();
+    assert!(format!("{formatted} {formatted}") == "Hello john! Hello john!");
+}
+
+#[test]
+fn structured_errors() {
+    let translations = embed_translations!();
+    let translator = Translator::with_translations(Language::FR, &translations);
+
+    assert!(matches!(
+        translator.get("common.greeting"),
+        Err(Error::LanguageNotAvailable { available, .. }) if available == [Language::EN, Language::ES]
+    ));
+}
@@ -0,0 +1,9 @@
+#![deny(deprecated)]
+
+use translatable::{translation, translations};
+
+fn main() {
+    let _ = translation!("en", static common::greeting_v2);
+    let _ = translation!("en", static common::greeting);
+    let _ = translations!("en", static common::greeting_v2, static legacy::title);
+}
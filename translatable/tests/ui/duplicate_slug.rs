@@ -0,0 +1,5 @@
+static SLUGS: translatable::Slugs = translatable::translation_slugs!("routes");
+
+fn main() {
+    let _ = &SLUGS;
+}
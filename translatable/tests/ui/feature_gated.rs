@@ -0,0 +1,5 @@
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("en", static premium::banner);
+}
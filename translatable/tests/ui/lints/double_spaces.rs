@@ -0,0 +1,8 @@
+#![deny(deprecated)]
+
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("es", static save);
+    let _ = translation!("en", static save);
+}
@@ -0,0 +1,6 @@
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("en", static billing::invoice::title);
+    let _ = translation!("en", static invoice::title);
+}
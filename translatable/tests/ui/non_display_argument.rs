@@ -0,0 +1,9 @@
+use translatable::{translation, translation_inline};
+
+struct User;
+
+fn main() {
+    let user = User;
+    let _ = translation!("en", static greeting, name = user);
+    let _ = translation_inline!("en", { en = "{0} items" }, vec![1, 2]);
+}
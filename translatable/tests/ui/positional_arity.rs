@@ -0,0 +1,5 @@
+use translatable::translation_inline;
+
+fn main() {
+    let _ = translation_inline!("en", { en = "{0} has {1} items", es = "{0} tiene {1}" }, "Alice");
+}
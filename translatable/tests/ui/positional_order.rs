@@ -0,0 +1,5 @@
+use translatable::translation_inline;
+
+fn main() {
+    let _ = translation_inline!("en", { en = "{0} and {name}" }, name = "Bob", "Alice");
+}
@@ -0,0 +1,7 @@
+#![deny(deprecated)]
+
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("es", static greeting, name = "john");
+}
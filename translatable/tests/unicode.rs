@@ -0,0 +1,27 @@
+use translatable::{Language, TranslationNode};
+use translatable_shared::check_unicode;
+
+/// Parses a translation object the way the macros do
+fn parse(translation: &str) -> toml::Table {
+    format!("[label]\nen = \"{translation}\"\n").parse::<toml::Table>().unwrap()
+}
+
+#[test]
+fn normalized_translations() {
+    let mut node = TranslationNode::try_from(parse("Cafe\\u0301")).unwrap();
+    assert!(node.get_path(vec!["label"]).unwrap()[&Language::EN] == "Caf\u{e9}");
+
+    node.set(vec!["label"], Language::ES, "Cafe\u{301} con leche").unwrap();
+    assert!(node.get_path(vec!["label"]).unwrap()[&Language::ES] == "Caf\u{e9} con leche");
+}
+
+#[test]
+fn strict_translations() {
+    assert!(check_unicode(&parse("Caf\\u00e9")).is_ok());
+    assert!(check_unicode(&parse("Family: \\U0001F468\\u200D\\U0001F467")).is_ok());
+    assert!(check_unicode(&parse("Line\\nbreak")).is_ok());
+
+    assert!(check_unicode(&parse("Cafe\\u0301")).is_err());
+    assert!(check_unicode(&parse("Zero\\u200bwidth")).is_err());
+    assert!(check_unicode(&parse("Bell\\u0007")).is_err());
+}
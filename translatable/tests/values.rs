@@ -0,0 +1,34 @@
+use translatable::{Error, Language, Translator, load_overrides};
+
+#[test]
+fn typed_values() {
+    let translations = translatable::include_translation_file!("tests/assets/locale.toml");
+    let english = Translator::with_translations(Language::EN, &translations);
+    let spanish = Translator::with_translations(Language::ES, &translations);
+
+    assert!(english.value::<u8>("calendar.first_weekday") == Ok(0));
+    assert!(spanish.value::<u8>("calendar.first_weekday") == Ok(1));
+    assert!(spanish.value::<bool>("metric") == Ok(true));
+    assert!(spanish.value::<char>("decimal_separator") == Ok(','));
+    assert!(english.value::<Vec<String>>("paper.sizes").unwrap() == ["Letter", "Legal"]);
+    assert!(spanish.get("_values.calendar.first_weekday").unwrap() == "1");
+    assert!(spanish.get("settings").unwrap() == "Ajustes");
+
+    assert!(
+        english.value::<bool>("paper.sizes")
+            == Err(Error::InvalidValue {
+                path: "paper.sizes".into(),
+                language: Language::EN
+            })
+    );
+    assert!(english.value::<u8>("settings").unwrap_err().code() == "path_not_found");
+}
+
+#[test]
+fn runtime_values() {
+    load_overrides("tests/assets/locale.toml").unwrap();
+
+    let translator = Translator::new(Language::ES);
+    assert!(translator.value::<Vec<String>>("paper.sizes").unwrap() == ["A4", "A5"]);
+    assert!(translator.value::<f64>("calendar.first_weekday") == Ok(1.0));
+}
@@ -0,0 +1,54 @@
+use translatable::{Language, Translator, embed_translations, translation};
+
+#[test]
+fn static_variants() {
+    assert!(
+        translation!("en", static welcome_message, variant = "onboarding") == "Welcome aboard!"
+    );
+    assert!(
+        translation!("en", static welcome_message, variant = "missing") == "Welcome to our app!"
+    );
+    assert!(
+        translation!("es", static welcome_message, variant = "onboarding")
+            == "¡Bienvenido a nuestra aplicación!"
+    );
+}
+
+#[test]
+fn runtime_variants() {
+    let group = String::from("onboarding");
+
+    assert!(translation!("en", static welcome_message, variant = group) == "Welcome aboard!");
+    assert!(
+        translation!("en", static welcome_message, variant = "control".to_string())
+            == "Welcome to our app!"
+    );
+    assert!(
+        translation!("en".to_string(), static welcome_message, variant = &group).unwrap()
+            == "Welcome aboard!"
+    );
+    assert!(
+        translation!("es".to_string(), static welcome_message, variant = &group).unwrap()
+            == "¡Bienvenido a nuestra aplicación!"
+    );
+}
+
+#[test]
+fn translator_variants() {
+    let translations = embed_translations!();
+    let english =
+        Translator::with_translations(Language::EN, &translations).with_variant("onboarding");
+    let spanish =
+        Translator::with_translations(Language::ES, &translations).with_variant("onboarding");
+
+    assert!(english.get("welcome_message").unwrap() == "Welcome aboard!");
+    assert!(english.get("common.farewell").unwrap() == "Goodbye!");
+    assert!(spanish.get("welcome_message").unwrap() == "¡Bienvenido a nuestra aplicación!");
+
+    let catalog = english.catalog();
+    assert!(catalog["welcome_message"] == "Welcome aboard!");
+    assert!(catalog.keys().all(|path| !path.contains('@')));
+
+    let control = Translator::with_translations(Language::EN, &translations);
+    assert!(control.catalog()["welcome_message"] == "Welcome to our app!");
+}
@@ -0,0 +1,14 @@
+use translatable::TranslationNodeCollection;
+
+#[test]
+fn catalog_versions() {
+    let shared = translatable::include_translation_file!("tests/fixtures/versions/shared.toml");
+    let app = translatable::include_translation_file!("tests/fixtures/versions/app.toml");
+
+    assert!(shared.iter().next().unwrap().version() == Some(2));
+    assert!(app.iter().next().unwrap().version().is_none());
+    assert!(shared.find_path("checkout.title").is_some());
+
+    let decoded = TranslationNodeCollection::from_bytes(&shared.to_bytes()).unwrap();
+    assert!(decoded.iter().next().unwrap().version() == Some(2));
+}
@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use translatable::{Formatted, translation_write};
+
+#[test]
+fn static_write() {
+    let mut output = String::new();
+    translation_write!(&mut output, "es", static common::greeting, name = "john").unwrap();
+
+    assert!(output == "¡Hola john!");
+}
+
+#[test]
+fn dynamic_write() {
+    let language = "en";
+    let path = "common.greeting";
+    let mut output = String::new();
+
+    translation_write!(&mut output, language, static common::greeting, name = "john")
+        .unwrap()
+        .unwrap();
+    output.push(' ');
+    translation_write!(&mut output, language, path, name = 10).unwrap().unwrap();
+
+    assert!(output == "Hello john! Hello 10!");
+    assert!(matches!(
+        translation_write!(&mut output, "en", path.replace("greeting", "missing")),
+        Err(translatable::Error::PathNotFound { .. })
+    ));
+}
+
+#[test]
+fn io_write() {
+    use std::io::Write;
+
+    let language = "es";
+    let mut output = Vec::new();
+
+    translation_write!(output, language, static welcome_message).unwrap().unwrap();
+
+    assert!(output == "¡Bienvenido a nuestra aplicación!".as_bytes());
+}
+
+#[test]
+fn escaped_templates() {
+    let formatted = Formatted::new("{{name}} is {name}, {{other}} {other}", &[("name", &"john")]);
+
+    assert!(formatted.to_string() == "{name} is john, {{other}} {other}");
+}
@@ -0,0 +1,121 @@
+use translatable::{Language, TranslationNode};
+
+/// Parses a translation file the way the macros do
+fn parse(content: &str) -> TranslationNode {
+    TranslationNode::try_from(content.parse::<toml::Table>().unwrap()).unwrap()
+}
+
+#[test]
+fn node_mutation() {
+    let mut node = parse("[common.greeting]\nen = \"Hello {name}!\"\n");
+
+    assert!(node.set(vec!["common", "greeting"], Language::ES, "¡Hola {name}!").unwrap().is_none());
+    assert!(
+        node.set(vec!["common", "greeting"], Language::EN, "Hi {name}!").unwrap().as_deref()
+            == Some("Hello {name}!")
+    );
+    assert!(node.set(vec!["checkout", "title"], Language::EN, "Checkout").is_ok());
+
+    assert!(node.set(vec!["common"], Language::EN, "Common").is_err());
+    assert!(node.set(vec!["common", "greeting", "formal"], Language::EN, "Hello").is_err());
+    assert!(node.set(vec!["common", "broken"], Language::EN, "Hello {name").is_err());
+
+    node.rename_key(vec!["common", "greeting"], vec!["home", "greeting"]).unwrap();
+    assert!(node.get_path(vec!["home", "greeting"]).unwrap()[&Language::ES] == "¡Hola {name}!");
+    assert!(node.get_node(vec!["common"]).is_none());
+
+    assert!(node.rename_key(vec!["missing"], vec!["other"]).is_err());
+    assert!(node.rename_key(vec!["home"], vec!["checkout", "title"]).is_err());
+    assert!(node.rename_key(vec!["home"], vec!["checkout", "title", "home"]).is_err());
+    assert!(node.get_path(vec!["home", "greeting"]).is_some());
+
+    assert!(node.remove(vec!["home", "greeting"]).is_some());
+    assert!(node.get_node(vec!["home"]).is_none());
+    assert!(node.remove(vec!["home"]).is_none());
+}
+
+#[test]
+fn toml_serialization() {
+    let mut node =
+        parse("[b.title]\nes = \"Título\"\nen = \"Title\"\n\n[a.title]\nen = \"First\"\n");
+    node.set(vec!["a", "subtitle"], Language::EN, "Second").unwrap();
+
+    let written = node.to_toml();
+    assert!(
+        written
+            == "[a.subtitle]\nen = \"Second\"\n\n[a.title]\nen = \"First\"\n\n[b.title]\nen = \
+                \"Title\"\nes = \"Título\"\n"
+    );
+
+    let reparsed = parse(&written);
+    assert!(reparsed.get_path(vec!["b", "title"]).unwrap()[&Language::ES] == "Título");
+
+    let mut node = parse("[checkout.cta]\nen = \"Buy now\"\n");
+    node.set(vec!["checkout", "cta@b"], Language::EN, "Get it today").unwrap();
+    assert!(
+        node.to_toml()
+            == "[checkout.cta]\nen = \"Buy now\"\n\n[checkout.cta.b]\nen = \"Get it today\"\n"
+    );
+}
+
+#[test]
+fn toml_update() {
+    let document = "# Checkout page\n[checkout.title]\nen = \"Checkout\" # Shown in the \
+                    header\nes = \"Pago\"\n\n[checkout.legacy]\nen = \
+                    \"Old\"\n\n[common]\ngreeting.en = \"Hello\"\n";
+
+    let mut node = parse(document);
+    node.set(vec!["checkout", "title"], Language::ES, "Pagar").unwrap();
+    node.set(vec!["common", "greeting"], Language::FR, "Bonjour").unwrap();
+    node.set(vec!["checkout", "total"], Language::EN, "Total").unwrap();
+    node.remove(vec!["checkout", "legacy"]);
+
+    let updated = node.update_toml(document).unwrap();
+    assert!(
+        updated
+            == "# Checkout page\n[checkout.title]\nen = \"Checkout\" # Shown in the header\nes = \
+                \"Pagar\"\n\n[checkout.total]\nen = \"Total\"\n\n[common]\ngreeting.en = \
+                \"Hello\"\ngreeting.fr = \"Bonjour\"\n"
+    );
+
+    assert!(node.update_toml("[broken").is_err());
+
+    let prioritized = node.update_toml("_priority = 1\n").unwrap();
+    assert!(prioritized.starts_with("_priority = 1\n"));
+}
+
+#[test]
+fn random_entries_serialization() {
+    let document = "[chat.greeting]\nen = [\"Hi\", \"Hey\"] # Picked at random\n";
+    let mut node = parse(document);
+    assert!(node.update_toml(document).unwrap() == document);
+
+    node.set(vec!["chat", "greeting"], Language::ES, "Hola").unwrap();
+    assert!(node.to_toml() == "[chat.greeting]\nen = [\"Hi\", \"Hey\"]\nes = \"Hola\"\n");
+
+    assert!(
+        TranslationNode::try_from("[chat]\nen = []\n".parse::<toml::Table>().unwrap()).is_err()
+    );
+    assert!(
+        TranslationNode::try_from("[chat]\nen = [1]\n".parse::<toml::Table>().unwrap()).is_err()
+    );
+    assert!(
+        TranslationNode::try_from("[chat]\nen = [\"{name\"]\n".parse::<toml::Table>().unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn trimmed_serialization() {
+    let document = "_trim = true\n\n[intro]\nen = \"\"\"\n    Hello,\n      world!\n    \"\"\"\n";
+
+    let mut table = document.parse::<toml::Table>().unwrap();
+    translatable_shared::apply_trim(&mut table).unwrap();
+    let node = TranslationNode::try_from(table).unwrap();
+
+    assert!(node.get_path(vec!["intro"]).unwrap()[&Language::EN] == "Hello,\n  world!");
+    assert!(node.update_toml(document).unwrap() == document);
+
+    let mut table = "_trim = \"yes\"\n".parse::<toml::Table>().unwrap();
+    assert!(translatable_shared::apply_trim(&mut table).is_err());
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "xliff")]
+
+use translatable::{Language, Translator};
+
+#[test]
+fn xliff_import() {
+    let translations = translatable::include_translation_file!("tests/assets/catalog.xlf");
+
+    let english = Translator::with_translations(Language::EN, &translations);
+    assert!(english.get("checkout.title").unwrap() == "Checkout & pay");
+    assert!(english.get("checkout.empty").unwrap() == "Your cart is empty");
+
+    let spanish = Translator::with_translations(Language::ES, &translations);
+    assert!(spanish.get("checkout.title").unwrap() == "Pagar & finalizar");
+    assert!(spanish.get_with("checkout.items", &[("count", &3)]).unwrap() == "3 artículos");
+    assert!(spanish.get("checkout.empty").is_err());
+}
@@ -3,15 +3,19 @@
 //! This module provides functionality to load and manage configuration
 //! settings for localization/translation workflows from a TOML file.
 
-use std::env::var;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::env::{current_dir, var};
 use std::fs::read_to_string;
 use std::io::Error as IoError;
-use std::sync::OnceLock;
+use std::sync::Mutex;
 
-use strum::EnumString;
 use thiserror::Error;
-use toml::Table;
 use toml::de::Error as TomlError;
+use toml::{Table, Value};
+use translatable_shared::{
+    Escape, Language, Lint, MissingArguments, SeekMode, Severity, TranslationOverlap,
+};
 
 /// Errors that can occur during configuration loading
 #[derive(Error, Debug)]
@@ -34,28 +38,6 @@ pub enum ConfigError {
     InvalidValue(String, String),
 }
 
-/// File search order strategy
-#[derive(Default, Clone, Copy, EnumString)]
-pub enum SeekMode {
-    /// Alphabetical order (default)
-    #[default]
-    Alphabetical,
-
-    /// Reverse alphabetical order
-    Unalphabetical,
-}
-
-/// Translation conflict resolution strategy
-#[derive(Default, Clone, Copy, EnumString)]
-pub enum TranslationOverlap {
-    /// Last found translation overwrites previous ones (default)
-    #[default]
-    Overwrite,
-
-    /// First found translation is preserved
-    Ignore,
-}
-
 /// Main configuration structure for translation system
 pub struct MacroConfig {
     /// Path to directory containing translation files
@@ -66,6 +48,15 @@ pub struct MacroConfig {
     /// ```
     path: String,
 
+    /// Dot-separated path the translation files are mounted under, so the
+    /// crates of a workspace sharing a translations root don't collide
+    ///
+    /// # Example
+    /// ```toml
+    /// namespace = "billing"
+    /// ```
+    namespace: Option<String>,
+
     /// File processing order strategy
     ///
     /// Default: alphabetical file processing
@@ -76,6 +67,134 @@ pub struct MacroConfig {
     /// Determines behavior when multiple files contain the same translation
     /// path
     overlap: TranslationOverlap,
+
+    /// Unreplaced template strategy for dynamic resolutions
+    ///
+    /// Default: templates without an argument are kept as is
+    missing_arguments: MissingArguments,
+
+    /// Severity of static resolutions for a language a translation lacks
+    ///
+    /// Default: the build fails, otherwise the resolution evaluates to the
+    /// translation path
+    on_missing_language: Severity,
+
+    /// Severity of translations with unbalanced template braces
+    ///
+    /// Default: the build fails, otherwise the translation is kept as is
+    on_unbalanced_braces: Severity,
+
+    /// Whether templated arguments are wrapped in bidi isolation characters
+    /// for right-to-left languages
+    ///
+    /// Default: arguments are replaced as they are
+    bidi_isolation: bool,
+
+    /// Escaping of the argument values, overridden by the `escape` argument
+    /// of the translation macros
+    ///
+    /// Default: values are replaced as they are
+    escape: Escape,
+
+    /// Whether translations not NFC normalized or with invisible control
+    /// characters are an error
+    ///
+    /// Default: translations are normalized to NFC
+    strict_unicode: bool,
+
+    /// Language the other ones are translated from, which the lints
+    /// comparing translations compare with
+    ///
+    /// Default: English
+    source_language: Language,
+
+    /// Severity of the quality lints over the translations
+    ///
+    /// Lints are named after [`Lint`] in snake case, and only run if
+    /// configured to warn or deny.
+    ///
+    /// # Example
+    /// ```toml
+    /// [lints]
+    /// double_spaces = "warn"
+    /// untranslated = "deny"
+    /// ```
+    lints: Vec<(Lint, Severity)>,
+
+    /// Custom language identifiers mapped to ISO 639-1 languages
+    ///
+    /// Keys are stored lowercase, and consulted before ISO 639-1 validation
+    /// on both compile-time and runtime language resolution.
+    ///
+    /// # Example
+    /// ```toml
+    /// [aliases]
+    /// "pt-br" = "pt"
+    /// "no" = "nb"
+    /// ```
+    aliases: BTreeMap<String, Language>,
+
+    /// Translation paths migrated to other ones, such as renamed keys
+    ///
+    /// Static uses of the old paths compile with a warning and resolve the
+    /// new ones, as do runtime lookups.
+    ///
+    /// # Example
+    /// ```toml
+    /// [migrations]
+    /// "common.greeting" = "common.greeting_v2"
+    /// ```
+    migrations: BTreeMap<String, String>,
+
+    /// Languages kept in the embedded translations, `None` keeps all of them
+    ///
+    /// # Example
+    /// ```toml
+    /// embed_languages = ["en", "es"]
+    /// ```
+    embed_languages: Option<Vec<Language>>,
+
+    /// Languages the application can resolve, `None` allows all of them
+    ///
+    /// Static resolutions of other languages are compile errors and runtime
+    /// ones are invalid languages, so the generated validation only matches
+    /// these languages. Other languages are never embedded.
+    ///
+    /// # Example
+    /// ```toml
+    /// languages = ["en", "es"]
+    /// ```
+    languages: Option<Vec<Language>>,
+
+    /// Oldest catalog version the `_version` of the translation files can
+    /// declare, files without a `_version` aren't checked
+    ///
+    /// # Example
+    /// ```toml
+    /// min_catalog_version = 3
+    /// ```
+    min_catalog_version: Option<u64>,
+
+    /// Cargo features enabled for the `_cfg` predicates of translation files
+    ///
+    /// Procedural macros can't see the features of the crate they expand in,
+    /// so build scripts forward them with `TRANSLATABLE_FEATURES`.
+    ///
+    /// Default: no feature is enabled
+    features: Vec<String>,
+
+    /// Target operating system the translation variants are selected for
+    ///
+    /// Default: the operating system the macros run on, cross compiling
+    /// build scripts forward `CARGO_CFG_TARGET_OS` with
+    /// `TRANSLATABLE_TARGET_OS`
+    target_os: String,
+
+    /// Directory where parsed translation files are cached across builds
+    ///
+    /// Default: `$OUT_DIR/translatable` if the crate has a build script,
+    /// otherwise parsed files are only cached in memory
+    cache_path: Option<String>,
 }
 
 impl MacroConfig {
@@ -84,6 +203,11 @@ impl MacroConfig {
         &self.path
     }
 
+    /// Get the path the translation files are mounted under, if any
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
     /// Get current seek mode strategy
     pub fn seek_mode(&self) -> SeekMode {
         self.seek_mode
@@ -93,37 +217,240 @@ impl MacroConfig {
     pub fn overlap(&self) -> TranslationOverlap {
         self.overlap
     }
+
+    /// Get current unreplaced template strategy
+    pub fn missing_arguments(&self) -> MissingArguments {
+        self.missing_arguments
+    }
+
+    /// Get the severity of static resolutions for a missing language
+    pub fn on_missing_language(&self) -> Severity {
+        self.on_missing_language
+    }
+
+    /// Get the severity of unbalanced template braces
+    pub fn on_unbalanced_braces(&self) -> Severity {
+        self.on_unbalanced_braces
+    }
+
+    /// Get whether arguments are isolated for right-to-left languages
+    pub fn bidi_isolation(&self) -> bool {
+        self.bidi_isolation
+    }
+
+    /// Get the escaping of argument values
+    pub fn escape(&self) -> Escape {
+        self.escape
+    }
+
+    /// Get whether translations must be normalized and visible
+    pub fn strict_unicode(&self) -> bool {
+        self.strict_unicode
+    }
+
+    /// Get the language the other ones are translated from
+    pub fn source_language(&self) -> &Language {
+        &self.source_language
+    }
+
+    /// Get the severity of the configured lints
+    pub fn lints(&self) -> &[(Lint, Severity)] {
+        &self.lints
+    }
+
+    /// Get reference to configured language aliases
+    pub fn aliases(&self) -> &BTreeMap<String, Language> {
+        &self.aliases
+    }
+
+    /// Get the new path of every migrated translation path
+    pub fn migrations(&self) -> &BTreeMap<String, String> {
+        &self.migrations
+    }
+
+    /// Get the directory for the persistent translation cache, if any
+    pub fn cache_path(&self) -> Option<&str> {
+        self.cache_path.as_deref()
+    }
+
+    /// Get the languages kept in the embedded translations
+    pub fn embed_languages(&self) -> Option<&[Language]> {
+        self.embed_languages.as_deref()
+    }
+
+    /// Get the languages the application can resolve
+    pub fn languages(&self) -> Option<&[Language]> {
+        self.languages.as_deref()
+    }
+
+    /// Get the oldest catalog version the translation files can declare
+    pub fn min_catalog_version(&self) -> Option<u64> {
+        self.min_catalog_version
+    }
+
+    /// Get the cargo features enabled for `_cfg` predicates
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    /// Get the target operating system of the translation variants
+    pub fn target_os(&self) -> &str {
+        &self.target_os
+    }
+}
+
+/// Environment variables the configuration is loaded from
+const CONFIG_VARIABLES: [&str; 19] = [
+    "TRANSLATABLE_CONFIG",
+    "TRANSLATABLE_LOCALES_PATH",
+    "TRANSLATABLE_NAMESPACE",
+    "TRANSLATABLE_SEEK_MODE",
+    "TRANSLATABLE_OVERLAP",
+    "TRANSLATABLE_MISSING_ARGUMENTS",
+    "TRANSLATABLE_ON_MISSING_LANGUAGE",
+    "TRANSLATABLE_ON_UNBALANCED_BRACES",
+    "TRANSLATABLE_BIDI_ISOLATION",
+    "TRANSLATABLE_ESCAPE",
+    "TRANSLATABLE_STRICT_UNICODE",
+    "TRANSLATABLE_SOURCE_LANGUAGE",
+    "TRANSLATABLE_EMBED_LANGUAGES",
+    "TRANSLATABLE_LANGUAGES",
+    "TRANSLATABLE_MIN_CATALOG_VERSION",
+    "TRANSLATABLE_FEATURES",
+    "TRANSLATABLE_TARGET_OS",
+    "TRANSLATABLE_CACHE_PATH",
+    "OUT_DIR",
+];
+
+/// Global configuration cache, keyed by the configuration sources
+///
+/// Long-lived processes such as IDE procedural macro servers expand macros
+/// for different crates, so every distinct working directory and
+/// environment gets its own configuration. Configurations are leaked, as
+/// there is one per crate at most.
+static TRANSLATABLE_CONFIG: Mutex<BTreeMap<Vec<Option<String>>, &'static MacroConfig>> =
+    Mutex::new(BTreeMap::new());
+
+thread_local! {
+    /// Translations directory overriding the configured `path`, set while a
+    /// macro invocation with a `@path` argument expands
+    static PATH_OVERRIDE: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
-/// Global configuration cache
-static TRANSLATABLE_CONFIG: OnceLock<MacroConfig> = OnceLock::new();
+/// Expands a macro invocation with the translations of another directory
+///
+/// The configuration loaded during the expansion has its `path` replaced,
+/// even if `TRANSLATABLE_LOCALES_PATH` is set, so the translations and
+/// every cache keyed by the configuration are the ones of that directory.
+/// Nested expansions restore the override of the outer one.
+pub fn with_path_override<T>(path: Option<String>, expansion: impl FnOnce() -> T) -> T {
+    let Some(path) = path else {
+        return expansion();
+    };
+
+    // Restored even if the expansion panics
+    let _guard = PathOverrideGuard {
+        outer: PATH_OVERRIDE.with(|current| current.replace(Some(path))),
+    };
+
+    expansion()
+}
+
+/// Restores the path override of the outer expansion when dropped
+struct PathOverrideGuard {
+    /// Override of the outer expansion
+    outer: Option<String>,
+}
+
+impl Drop for PathOverrideGuard {
+    fn drop(&mut self) {
+        let outer = self.outer.take();
+        PATH_OVERRIDE.with(|current| current.replace(outer));
+    }
+}
 
 /// Load configuration from file or use defaults
 ///
 /// # Implementation Notes
-/// - Uses `OnceLock` for thread-safe singleton initialization
+/// - Cached once per working directory and configuration environment
 /// - Missing config file is not considered an error
-/// - Config file must be named `translatable.toml` in root directory
-/// - Environment variables take precedence over TOML configuration
+/// - Config file must be named `translatable.toml` in root directory, unless
+///   `TRANSLATABLE_CONFIG` points to another file
+/// - Environment variables take precedence over TOML configuration, and a
+///   `@path` macro argument over both, see [`with_path_override`]
 /// - Supported environment variables:
 ///   - `TRANSLATABLE_LOCALES_PATH`: Overrides translation directory path
 ///   - `TRANSLATABLE_SEEK_MODE`: Sets file processing order ("alphabetical" or
 ///     "unalphabetical")
 ///   - `TRANSLATABLE_OVERLAP`: Sets conflict strategy ("overwrite" or "ignore")
+///   - `TRANSLATABLE_MISSING_ARGUMENTS`: Sets unreplaced template strategy
+///     ("ignore" or "error")
+///   - `TRANSLATABLE_ON_MISSING_LANGUAGE`: Sets the missing language severity
+///     ("deny", "warn" or "allow")
+///   - `TRANSLATABLE_ON_UNBALANCED_BRACES`: Sets the unbalanced braces severity
+///     ("deny", "warn" or "allow")
+///   - `TRANSLATABLE_BIDI_ISOLATION`: Sets whether arguments are isolated for
+///     right-to-left languages ("true" or "false")
+///   - `TRANSLATABLE_ESCAPE`: Sets the escaping of argument values ("none" or
+///     "html")
+///   - `TRANSLATABLE_STRICT_UNICODE`: Sets whether translations must be NFC
+///     normalized and visible ("true" or "false")
+///   - `TRANSLATABLE_SOURCE_LANGUAGE`: Sets the language the other ones are
+///     translated from
+///   - `TRANSLATABLE_EMBED_LANGUAGES`: Sets the embedded languages, comma
+///     separated
+///   - `TRANSLATABLE_LANGUAGES`: Sets the languages the application can
+///     resolve, comma separated
+///   - `TRANSLATABLE_MIN_CATALOG_VERSION`: Sets the oldest catalog version of
+///     the translation files
+///   - `TRANSLATABLE_FEATURES`: Sets the cargo features enabled for `_cfg`
+///     predicates, comma separated
+///   - `TRANSLATABLE_TARGET_OS`: Sets the target operating system of the
+///     translation variants
+///   - `TRANSLATABLE_CACHE_PATH`: Overrides the persistent cache directory
+///   - `TRANSLATABLE_NAMESPACE`: Sets the path the translation files are
+///     mounted under
+///   - `TRANSLATABLE_CONFIG`: Path of the configuration file, which must exist
+/// - The `[aliases]` table maps custom identifiers to ISO 639-1 languages
+/// - The `[lints]` table maps lint names to severities
+/// - The `[migrations]` table maps old translation paths to new ones
 ///
 /// # Panics
 /// Will not panic but returns ConfigError for:
 /// - Malformed TOML syntax
 /// - Filesystem permission issues
 /// - Invalid environment variable values
+/// - Aliases pointing to invalid ISO 639-1 languages
+/// - Embedded or resolvable languages that are not valid ISO 639-1 languages
+/// - Unknown lints or severities
+/// - Minimum catalog versions that aren't non-negative integers
+/// - Migrations to other values than paths
+/// - Namespaces with empty segments or whitespace
 pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
-    if let Some(config) = TRANSLATABLE_CONFIG.get() {
+    let mut key = CONFIG_VARIABLES.map(|variable| var(variable).ok()).to_vec();
+    key.push(current_dir().ok().map(|dir| dir.to_string_lossy().to_string()));
+    key.push(PATH_OVERRIDE.with(|path| path.borrow().clone()));
+
+    let mut configs = TRANSLATABLE_CONFIG.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(config) = configs.get(&key) {
         return Ok(config);
     }
 
-    // Load base configuration from TOML file
-    let toml_content =
-        read_to_string("./translatable.toml").unwrap_or_default().parse::<Table>()?;
+    let config = Box::leak(Box::new(read_config()?));
+    configs.insert(key, config);
+
+    Ok(config)
+}
+
+/// Reads the configuration from the file and environment variables
+fn read_config() -> Result<MacroConfig, ConfigError> {
+    // Load base configuration from TOML file, an explicit path must exist
+    let toml_content = match var("TRANSLATABLE_CONFIG") {
+        Ok(path) => read_to_string(path)?,
+        Err(_) => read_to_string("./translatable.toml").unwrap_or_default(),
+    }
+    .parse::<Table>()?;
 
     macro_rules! config_value {
         ($env_var:expr, $key:expr, $default:expr) => {
@@ -134,9 +461,12 @@ pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
         };
 
         (parse($env_var:expr, $key:expr, $default:expr)) => {{
-            let value = var($env_var)
-                .ok()
-                .or_else(|| toml_content.get($key).and_then(|v| v.as_str()).map(|v| v.to_string()));
+            // Other TOML values than strings, such as booleans, are parsed as written
+            let value = var($env_var).ok().or_else(|| {
+                toml_content
+                    .get($key)
+                    .map(|v| v.as_str().map(String::from).unwrap_or_else(|| v.to_string()))
+            });
 
             if let Some(value) = value {
                 value.parse().map_err(|_| ConfigError::InvalidValue($key.into(), value.into()))
@@ -146,8 +476,119 @@ pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
         }};
     }
 
-    let config = MacroConfig {
-        path: config_value!("TRANSLATABLE_LOCALES_PATH", "path", "./translations"),
+    // Aliases are a table, so they can't be overridden by environment variables
+    let aliases = match toml_content.get("aliases") {
+        Some(Value::Table(aliases)) => aliases
+            .iter()
+            .map(|(alias, language)| {
+                language
+                    .as_str()
+                    .and_then(|language| language.parse().ok())
+                    .map(|language| (alias.to_lowercase(), language))
+                    .ok_or_else(|| {
+                        ConfigError::InvalidValue(format!("aliases.{alias}"), language.to_string())
+                    })
+            })
+            .collect::<Result<_, _>>()?,
+        Some(other) => return Err(ConfigError::InvalidValue("aliases".into(), other.to_string())),
+        None => BTreeMap::new(),
+    };
+
+    // Like aliases, lints are a table
+    let lints = match toml_content.get("lints") {
+        Some(Value::Table(lints)) => lints
+            .iter()
+            .map(|(lint, severity)| {
+                let invalid =
+                    || ConfigError::InvalidValue(format!("lints.{lint}"), severity.to_string());
+
+                Ok((
+                    lint.parse().map_err(|_| invalid())?,
+                    severity.as_str().and_then(|s| s.parse().ok()).ok_or_else(invalid)?,
+                ))
+            })
+            .collect::<Result<_, ConfigError>>()?,
+        Some(other) => return Err(ConfigError::InvalidValue("lints".into(), other.to_string())),
+        None => Vec::new(),
+    };
+
+    // Like aliases, migrations are a table
+    let migrations = match toml_content.get("migrations") {
+        Some(Value::Table(migrations)) => migrations
+            .iter()
+            .map(|(path, migrated)| {
+                migrated.as_str().map(|migrated| (path.clone(), migrated.to_string())).ok_or_else(
+                    || {
+                        ConfigError::InvalidValue(
+                            format!("migrations.{path}"),
+                            migrated.to_string(),
+                        )
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()?,
+        Some(other) => {
+            return Err(ConfigError::InvalidValue("migrations".into(), other.to_string()));
+        },
+        None => BTreeMap::new(),
+    };
+
+    // Namespaces are paths, so every segment must be named
+    let namespace = var("TRANSLATABLE_NAMESPACE")
+        .ok()
+        .or_else(|| toml_content.get("namespace").and_then(|v| v.as_str()).map(String::from));
+
+    if let Some(namespace) = &namespace
+        && namespace
+            .split('.')
+            .any(|segment| segment.is_empty() || segment.contains(char::is_whitespace))
+    {
+        return Err(ConfigError::InvalidValue("namespace".into(), namespace.clone()));
+    }
+
+    let embed_languages =
+        language_list(&toml_content, "TRANSLATABLE_EMBED_LANGUAGES", "embed_languages")?;
+    let languages = language_list(&toml_content, "TRANSLATABLE_LANGUAGES", "languages")?;
+
+    // Integers are written as they are, unlike the strings of `config_value!`
+    let min_catalog_version = var("TRANSLATABLE_MIN_CATALOG_VERSION")
+        .ok()
+        .or_else(|| toml_content.get("min_catalog_version").map(Value::to_string))
+        .map(|version| {
+            version
+                .parse()
+                .map_err(|_| ConfigError::InvalidValue("min_catalog_version".into(), version))
+        })
+        .transpose()?;
+
+    // Like the embedded languages, but without parsing
+    let features = match var("TRANSLATABLE_FEATURES") {
+        Ok(features) => features
+            .split(',')
+            .map(|feature| feature.trim().to_string())
+            .filter(|feature| !feature.is_empty())
+            .collect(),
+        Err(_) => match toml_content.get("features") {
+            Some(Value::Array(features)) => features
+                .iter()
+                .map(|feature| {
+                    feature.as_str().map(String::from).ok_or_else(|| {
+                        ConfigError::InvalidValue("features".into(), feature.to_string())
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            Some(other) => {
+                return Err(ConfigError::InvalidValue("features".into(), other.to_string()));
+            },
+            None => Vec::new(),
+        },
+    };
+
+    Ok(MacroConfig {
+        path: PATH_OVERRIDE.with(|path| path.borrow().clone()).unwrap_or_else(|| {
+            config_value!("TRANSLATABLE_LOCALES_PATH", "path", "./translations")
+        }),
+        namespace,
         overlap: config_value!(parse(
             "TRANSLATABLE_OVERLAP",
             "overlap",
@@ -158,8 +599,84 @@ pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
             "seek_mode",
             SeekMode::Alphabetical
         ))?,
+        missing_arguments: config_value!(parse(
+            "TRANSLATABLE_MISSING_ARGUMENTS",
+            "missing_arguments",
+            MissingArguments::Ignore
+        ))?,
+        on_missing_language: config_value!(parse(
+            "TRANSLATABLE_ON_MISSING_LANGUAGE",
+            "on_missing_language",
+            Severity::Deny
+        ))?,
+        on_unbalanced_braces: config_value!(parse(
+            "TRANSLATABLE_ON_UNBALANCED_BRACES",
+            "on_unbalanced_braces",
+            Severity::Deny
+        ))?,
+        bidi_isolation: config_value!(parse(
+            "TRANSLATABLE_BIDI_ISOLATION",
+            "bidi_isolation",
+            false
+        ))?,
+        escape: config_value!(parse("TRANSLATABLE_ESCAPE", "escape", Escape::None))?,
+        strict_unicode: config_value!(parse(
+            "TRANSLATABLE_STRICT_UNICODE",
+            "strict_unicode",
+            false
+        ))?,
+        source_language: config_value!(parse(
+            "TRANSLATABLE_SOURCE_LANGUAGE",
+            "source_language",
+            Language::EN
+        ))?,
+        lints,
+        aliases,
+        migrations,
+        embed_languages,
+        languages,
+        min_catalog_version,
+        features,
+        target_os: config_value!("TRANSLATABLE_TARGET_OS", "target_os", std::env::consts::OS),
+        cache_path: var("TRANSLATABLE_CACHE_PATH")
+            .ok()
+            .or_else(|| toml_content.get("cache_path").and_then(|v| v.as_str()).map(String::from))
+            .or_else(|| var("OUT_DIR").ok().map(|out_dir| format!("{out_dir}/translatable"))),
+    })
+}
+
+/// Reads a list of languages from the configuration
+///
+/// The TOML value is an array, the environment variable is comma separated.
+///
+/// # Returns
+/// - `Ok(Some(Vec<Language>))` with the configured languages
+/// - `Ok(None)` if the list isn't configured
+/// - `Err(ConfigError)` if the value isn't a list of ISO 639-1 languages
+fn language_list(
+    toml_content: &Table,
+    env_var: &str,
+    key: &str,
+) -> Result<Option<Vec<Language>>, ConfigError> {
+    let languages = match var(env_var) {
+        Ok(languages) => languages.split(',').map(|l| l.trim().to_string()).collect(),
+        Err(_) => match toml_content.get(key) {
+            Some(Value::Array(languages)) => languages
+                .iter()
+                .map(|language| {
+                    language.as_str().map(String::from).unwrap_or_else(|| language.to_string())
+                })
+                .collect::<Vec<_>>(),
+            Some(other) => return Err(ConfigError::InvalidValue(key.into(), other.to_string())),
+            None => return Ok(None),
+        },
     };
 
-    // Freeze configuration in global cache
-    Ok(TRANSLATABLE_CONFIG.get_or_init(|| config))
+    languages
+        .into_iter()
+        .map(|language| {
+            language.parse().map_err(|_| ConfigError::InvalidValue(key.into(), language))
+        })
+        .collect::<Result<Vec<Language>, _>>()
+        .map(Some)
 }
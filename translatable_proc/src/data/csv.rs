@@ -0,0 +1,57 @@
+//! CSV sheet translation file import
+//!
+//! This module reads the simple sheets maintained outside of the code, with
+//! a row per translation path and a column per language.
+
+use csv::ReaderBuilder;
+use toml::Table;
+
+use super::imports::{insert_translation, language_key};
+
+/// Parses a CSV sheet into a translation table
+///
+/// The first column holds the dot-separated paths, the header of every
+/// other column is the language of its cells. Empty cells are left
+/// untranslated, so rows only fill the languages they have.
+///
+/// # Returns
+/// The translation table, or a message describing why the sheet is invalid
+pub fn parse_csv(content: &str) -> Result<Table, String> {
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(content.as_bytes());
+    let mut table = Table::new();
+
+    let languages = reader
+        .headers()
+        .map_err(|err| err.to_string())?
+        .iter()
+        .skip(1)
+        .map(|header| language_key(header.trim().to_string()))
+        .collect::<Vec<_>>();
+
+    if languages.is_empty() {
+        return Err("The sheet has no language columns.".into());
+    }
+
+    for record in reader.records() {
+        let record = record.map_err(|err| err.to_string())?;
+        let line = record.position().map_or(0, |position| position.line());
+
+        let path = record.get(0).map(str::trim).unwrap_or_default();
+        if path.is_empty() {
+            // Rows without a path, usually blank lines, are skipped
+            if record.iter().all(str::is_empty) {
+                continue;
+            }
+
+            return Err(format!("The row at line {line} has no path."));
+        }
+
+        for (language, translation) in languages.iter().zip(record.iter().skip(1)) {
+            if !translation.is_empty() {
+                insert_translation(&mut table, path, language, translation.to_string())?;
+            }
+        }
+    }
+
+    Ok(table)
+}
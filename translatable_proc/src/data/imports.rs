@@ -0,0 +1,134 @@
+//! Translation files imported from other formats
+//!
+//! Spreadsheets and the documents of translation tools are parsed into the
+//! same TOML tables translation files are, so they go through the same
+//! validation, and their languages are merged into the translation files.
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+use toml::{Table, Value};
+use translatable_shared::{AssociatedTranslation, Language, TranslationNode};
+
+#[cfg(feature = "csv")]
+use super::csv::parse_csv;
+#[cfg(feature = "xliff")]
+use super::xliff::parse_xliff;
+use crate::translations::errors::TranslationError;
+
+/// Format of an imported translation file
+pub enum ImportFormat {
+    /// CSV sheet with a path column and a column per language
+    #[cfg(feature = "csv")]
+    Csv,
+    /// XLIFF 2.0 document
+    #[cfg(feature = "xliff")]
+    Xliff,
+}
+
+impl ImportFormat {
+    /// Gets the format of a translation file by its extension
+    ///
+    /// # Returns
+    /// The format, or `None` for TOML translation files
+    pub fn of(path: &str) -> Option<Self> {
+        match Path::new(path).extension().and_then(OsStr::to_str)? {
+            #[cfg(feature = "csv")]
+            "csv" => Some(Self::Csv),
+            #[cfg(feature = "xliff")]
+            "xlf" | "xliff" => Some(Self::Xliff),
+            _ => None,
+        }
+    }
+
+    /// Parses the content of a translation file in the format into a TOML
+    /// table
+    pub fn parse(&self, path: &str, content: &str) -> Result<Table, TranslationError> {
+        match self {
+            #[cfg(feature = "csv")]
+            Self::Csv => {
+                parse_csv(content).map_err(|err| TranslationError::ParseCsv(err, path.to_string()))
+            },
+            #[cfg(feature = "xliff")]
+            Self::Xliff => parse_xliff(content)
+                .map_err(|err| TranslationError::ParseXliff(err, path.to_string())),
+        }
+    }
+}
+
+/// Reduces a language tag to its ISO 639-1 code, `en-US` being `en`
+///
+/// Unknown tags are kept as they are so their validation reports them.
+pub fn language_key(tag: String) -> String {
+    Language::from_locale(&tag).map_or(tag, |language| language.code().to_string())
+}
+
+/// Inserts a translation at its dot-separated path in a table
+pub fn insert_translation(
+    table: &mut Table,
+    path: &str,
+    language: &str,
+    translation: String,
+) -> Result<(), String> {
+    let mut nesting = table;
+
+    for segment in path.split('.') {
+        nesting = match nesting.entry(segment).or_insert_with(|| Value::Table(Table::new())) {
+            Value::Table(table) => table,
+            _ => return Err(format!("The path '{path}' is nested in a translation.")),
+        };
+    }
+
+    if nesting.insert(language.to_string(), Value::String(translation)).is_some() {
+        return Err(format!("The path '{path}' is translated to '{language}' more than once."));
+    }
+
+    Ok(())
+}
+
+/// Merges the languages of imported files into the first translation file
+/// containing each of their paths
+///
+/// The imported files are looked up after every translation file, so the
+/// paths no translation file contains are still found, and the languages
+/// already in a translation file are never replaced.
+pub fn merge_imports(translations: Vec<AssociatedTranslation>) -> Vec<AssociatedTranslation> {
+    /// Merges the translation objects of a node into the translation files
+    fn merge<'a>(
+        files: &mut [AssociatedTranslation],
+        node: &'a TranslationNode,
+        path: &mut Vec<&'a str>,
+    ) {
+        match node {
+            TranslationNode::Object(nested) => {
+                for (key, node) in nested {
+                    path.push(key);
+                    merge(files, node, path);
+                    path.pop();
+                }
+            },
+            TranslationNode::Translation(translation) => {
+                let object = files
+                    .iter_mut()
+                    .find_map(|file| file.translation_table_mut().get_path_mut(path.clone()));
+
+                if let Some(object) = object {
+                    for (language, text) in translation {
+                        object.entry(language.clone()).or_insert_with(|| text.clone());
+                    }
+                }
+            },
+        }
+    }
+
+    let (imports, mut files) = translations.into_iter().partition::<Vec<_>, _>(|translation| {
+        ImportFormat::of(translation.original_path()).is_some()
+    });
+
+    for import in &imports {
+        merge(&mut files, import.translation_table(), &mut Vec::new());
+    }
+
+    files.extend(imports);
+    files
+}
@@ -1,2 +1,8 @@
 pub mod config;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(any(feature = "csv", feature = "xliff"))]
+pub mod imports;
 pub mod translations;
+#[cfg(feature = "xliff")]
+pub mod xliff;
@@ -1,58 +1,45 @@
-use std::collections::HashMap;
-use std::fs::{read_dir, read_to_string};
-use std::sync::OnceLock;
-
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use strum::ParseError;
-use syn::LitStr;
-use thiserror::Error;
-use toml::{Table, Value};
-
-use super::config::{SeekMode, TranslationOverlap, load_config};
-use crate::languages::Iso639a;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{create_dir_all, read, read_dir, read_to_string, write};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use toml::Table;
+#[cfg(feature = "markdown")]
+use translatable_shared::render_markdown;
+use translatable_shared::{
+    AssociatedTranslation, FORMAT_VERSION, Language, SeekMode, Severity, SourceLocation,
+    TransformError, TranslationNode, TranslationNodeCollection, TranslationOverlap,
+    apply_conditions, apply_named_variants, apply_target_variants, apply_trim, apply_values,
+    check_unicode, lint_catalog, parse_with_locations, sort_by_priority, take_deprecations,
+    take_priority, take_version,
+};
+
+use super::config::load_config;
+#[cfg(any(feature = "csv", feature = "xliff"))]
+use super::imports::{ImportFormat, merge_imports};
+use crate::translations::diagnostics::defer_warning;
 use crate::translations::errors::TranslationError;
 
-/// Errors occurring during TOML-to-translation structure transformation
-#[derive(Error, Debug)]
-pub enum TransformError {
-    /// Mixed content found in nesting node (strings and objects cannot coexist)
-    #[error("A nesting can contain either strings or other nestings, but not both.")]
-    InvalidNesting,
-
-    /// Template syntax error with unbalanced braces
-    #[error("Templates in translations should match '{{' and '}}'")]
-    UnclosedTemplate,
-
-    /// Invalid value type encountered in translation structure
-    #[error("Only strings and objects are allowed for nested objects.")]
-    InvalidValue,
-
-    /// Failed to parse language code from translation key
-    #[error("Couldn't parse ISO 639-1 string for translation key")]
-    LanguageParsing(#[from] ParseError),
-}
-
-/// Represents hierarchical translation structure
-#[derive(Clone)]
-pub enum NestingType {
-    /// Nested namespace containing other translation objects
-    Object(HashMap<String, NestingType>),
-    /// Leaf node containing actual translations per language
-    Translation(HashMap<Iso639a, String>),
-}
-
-/// Translation association with its source file
-pub struct AssociatedTranslation {
-    /// Original file path of the translation
-    original_path: String,
-    /// Hierarchical translation data
-    translation_table: NestingType,
+/// Global thread-safe cache for loaded translations, keyed by the address
+/// of the configuration they were loaded with
+///
+/// Collections are leaked like configurations, there is one per crate.
+static TRANSLATIONS: Mutex<BTreeMap<usize, &'static TranslationNodeCollection>> =
+    Mutex::new(BTreeMap::new());
+
+/// Translations rendered from Markdown, keyed like [`TRANSLATIONS`]
+#[cfg(feature = "markdown")]
+static MARKDOWN_TRANSLATIONS: Mutex<BTreeMap<usize, &'static TranslationNodeCollection>> =
+    Mutex::new(BTreeMap::new());
+
+#[cfg(feature = "markdown")]
+thread_local! {
+    /// Whether the translations are loaded rendered from Markdown, set while
+    /// a `translation_markdown!` invocation expands
+    static MARKDOWN: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
 }
 
-/// Global thread-safe cache for loaded translations
-static TRANSLATIONS: OnceLock<Vec<AssociatedTranslation>> = OnceLock::new();
-
 /// Recursively walks directory to find all translation files
 ///
 /// # Arguments
@@ -81,19 +68,198 @@ fn walk_dir(path: &str) -> Result<Vec<String>, TranslationError> {
     Ok(result)
 }
 
-/// Validates template brace balancing in translation strings
-fn templates_valid(translation: &str) -> bool {
-    let mut nestings = 0;
+/// Removes the languages not configured in `embed_languages` or `languages`
+///
+/// Translation objects are kept even if no language is left, so their
+/// paths are still found.
+fn strip_languages(node: &mut TranslationNode, languages: &[Language]) {
+    match node {
+        TranslationNode::Object(nested) => {
+            nested.values_mut().for_each(|node| strip_languages(node, languages))
+        },
+        TranslationNode::Translation(translation) => {
+            translation.retain(|language, _| languages.contains(language))
+        },
+    }
+}
 
-    for character in translation.chars() {
-        match character {
-            '{' => nestings += 1,
-            '}' => nestings -= 1,
-            _ => {},
-        }
+/// Gets the persistent cache file for a translation file
+///
+/// Cache files are keyed by a hash of the file content, so edited files
+/// never hit a stale entry, the cached structure was already validated
+/// and files with unbalanced braces are never cached.
+/// The `embed_languages` and `languages` configurations are applied after
+/// the cache, so they don't invalidate it, unlike the `features` and
+/// `target_os` configurations.
+///
+/// # Returns
+/// The cache file path, or `None` if no cache directory is configured
+fn cache_file(content: &str) -> Result<Option<PathBuf>, TranslationError> {
+    // The versions are hashed too, in case the cache format changes
+    let mut hasher = DefaultHasher::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    FORMAT_VERSION.hash(&mut hasher);
+    content.hash(&mut hasher);
+
+    // The features and target decide which tables are kept, strictness whether they
+    // load
+    let config = load_config()?;
+    config.features().hash(&mut hasher);
+    config.target_os().hash(&mut hasher);
+    config.strict_unicode().hash(&mut hasher);
+
+    Ok(config
+        .cache_path()
+        .map(|cache_path| PathBuf::from(cache_path).join(format!("{:016x}.bin", hasher.finish()))))
+}
+
+/// Parses the content of a translation file into a TOML table, along with
+/// the location of its paths
+///
+/// With the `csv` and `xliff` features, CSV sheets and XLIFF documents are
+/// parsed into the same table structure, without locations.
+fn parse_table(
+    path: &str,
+    content: &str,
+) -> Result<(Table, BTreeMap<String, SourceLocation>), TranslationError> {
+    #[cfg(any(feature = "csv", feature = "xliff"))]
+    if let Some(format) = ImportFormat::of(path) {
+        return format.parse(path, content).map(|table| (table, BTreeMap::new()));
     }
 
-    nestings == 0
+    parse_with_locations(content).map_err(|err| TranslationError::ParseToml(err, path.to_string()))
+}
+
+/// Loads and validates a single translation file
+///
+/// Only the languages configured in `embed_languages` and `languages`, and
+/// the tables whose `_cfg` predicate holds for the configured `features`
+/// are kept, the variants of the configured `target_os` are selected, and
+/// the `_priority` key is read into the priority of the translation.
+///
+/// # Arguments
+/// * `path` - Path to the TOML translation file
+///
+/// # Returns
+/// The translation associated with its file path or TranslationError
+pub fn load_translation_file(path: &str) -> Result<AssociatedTranslation, TranslationError> {
+    let content = read_to_string(path)?;
+    let cache_file = cache_file(&content)?;
+
+    let cached = cache_file
+        .as_ref()
+        .and_then(|cache_file| read(cache_file).ok())
+        .and_then(|bytes| TranslationNodeCollection::from_bytes(&bytes))
+        .and_then(|cached| {
+            cached.iter().next().map(|cached| {
+                (
+                    cached.translation_table().clone(),
+                    cached.priority(),
+                    cached.version(),
+                    cached.deprecations().clone(),
+                    cached.locations().clone(),
+                )
+            })
+        });
+
+    let (mut translation_table, priority, version, deprecations, locations) = match cached {
+        Some(cached) => cached,
+        None => {
+            let (mut table, locations) = parse_table(path, &content)?;
+            let invalid = |err| TranslationError::InvalidTomlFormat(err, path.to_string());
+            let priority = take_priority(&mut table).map_err(invalid)?;
+            let version = take_version(&mut table).map_err(invalid)?;
+
+            let was_empty = table.is_empty();
+            let enabled =
+                apply_conditions(&mut table, Some(load_config()?.features())).map_err(invalid)?;
+
+            let deprecations = take_deprecations(&mut table).map_err(invalid)?;
+
+            // Gated files are neither trimmed nor checked
+            if enabled {
+                apply_trim(&mut table).map_err(invalid)?;
+
+                if load_config()?.strict_unicode() {
+                    check_unicode(&table).map_err(invalid)?;
+                }
+            }
+
+            apply_values(&mut table).map_err(invalid)?;
+            apply_target_variants(&mut table, load_config()?.target_os());
+            apply_named_variants(&mut table);
+
+            // Files left without translations by disabled features are loaded empty
+            let parsed = if !enabled || (!was_empty && table.is_empty()) {
+                Ok(TranslationNode::Object(HashMap::new()))
+            } else {
+                TranslationNode::try_from(table.clone())
+            };
+
+            // Files with unbalanced braces are loaded again if they are not denied
+            let (translation_table, valid) = match parsed {
+                Ok(translation_table) => (translation_table, true),
+                Err(TransformError::UnclosedTemplate) => {
+                    let error = TranslationError::InvalidTomlFormat(
+                        TransformError::UnclosedTemplate,
+                        path.to_string(),
+                    );
+
+                    match load_config()?.on_unbalanced_braces() {
+                        Severity::Deny => return Err(error),
+                        Severity::Warn => defer_warning(error),
+                        Severity::Allow => {},
+                    }
+
+                    let translation_table =
+                        TranslationNode::from_table(table, false).map_err(|err| {
+                            TranslationError::InvalidTomlFormat(err, path.to_string())
+                        })?;
+
+                    (translation_table, false)
+                },
+                Err(err) => return Err(TranslationError::InvalidTomlFormat(err, path.to_string())),
+            };
+
+            // The cache is an optimization, failing to write it is not an error,
+            // files with findings are not cached so they are reported every build
+            if let Some(cache_file) = cache_file.filter(|_| valid) {
+                let cached = TranslationNodeCollection::new(vec![
+                    AssociatedTranslation::new(path, translation_table.clone())
+                        .with_priority(priority)
+                        .with_version(version)
+                        .with_deprecations(deprecations.clone())
+                        .with_locations(locations.clone()),
+                ]);
+
+                let _ = cache_file
+                    .parent()
+                    .map_or(Ok(()), create_dir_all)
+                    .and_then(|_| write(&cache_file, cached.to_bytes()));
+            }
+
+            (translation_table, priority, version, deprecations, locations)
+        },
+    };
+
+    let config = load_config()?;
+
+    // Unversioned files, such as the application ones, are never outdated
+    if let (Some(version), Some(min_version)) = (version, config.min_catalog_version())
+        && version < min_version
+    {
+        return Err(TranslationError::OutdatedCatalog(path.to_string(), version, min_version));
+    }
+
+    for languages in [config.embed_languages(), config.languages()].into_iter().flatten() {
+        strip_languages(&mut translation_table, languages);
+    }
+
+    Ok(AssociatedTranslation::new(path, translation_table)
+        .with_priority(priority)
+        .with_version(version)
+        .with_deprecations(deprecations)
+        .with_locations(locations))
 }
 
 /// Loads and caches translations from configured directory
@@ -102,15 +268,25 @@ fn templates_valid(translation: &str) -> bool {
 /// Reference to cached translations or TranslationError
 ///
 /// # Implementation Details
-/// - Uses OnceLock for thread-safe initialization
-/// - Applies sorting based on configuration
+/// - Cached once per configuration
+/// - Applies sorting based on configuration, then on file priorities
 /// - Handles file parsing and validation
-pub fn load_translations() -> Result<&'static Vec<AssociatedTranslation>, TranslationError> {
-    if let Some(translations) = TRANSLATIONS.get() {
-        return Ok(translations);
+/// - Mounts the files under the configured namespace, if any
+pub fn load_translations() -> Result<&'static TranslationNodeCollection, TranslationError> {
+    #[cfg(feature = "markdown")]
+    if MARKDOWN.with(|markdown| markdown.get()) {
+        return load_markdown_translations();
     }
 
     let config = load_config()?;
+    let key = config as *const _ as usize;
+
+    let mut cache = TRANSLATIONS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(translations) = cache.get(&key) {
+        return Ok(translations);
+    }
+
     let mut translation_paths = walk_dir(config.path())?;
 
     // Apply sorting based on configuration
@@ -121,17 +297,7 @@ pub fn load_translations() -> Result<&'static Vec<AssociatedTranslation>, Transl
 
     let mut translations = translation_paths
         .iter()
-        .map(|path| {
-            let table = read_to_string(path)?
-                .parse::<Table>()
-                .map_err(|err| TranslationError::ParseToml(err, path.clone()))?;
-
-            Ok(AssociatedTranslation {
-                original_path: path.to_string(),
-                translation_table: NestingType::try_from(table)
-                    .map_err(|err| TranslationError::InvalidTomlFormat(err, path.to_string()))?,
-            })
-        })
+        .map(|path| load_translation_file(path))
         .collect::<Result<Vec<_>, TranslationError>>()?;
 
     // Handle translation overlap configuration
@@ -139,113 +305,126 @@ pub fn load_translations() -> Result<&'static Vec<AssociatedTranslation>, Transl
         translations.reverse();
     }
 
-    Ok(TRANSLATIONS.get_or_init(|| translations))
-}
+    // Priorities override the order of the files, ties keep it
+    sort_by_priority(&mut translations);
 
-impl NestingType {
-    /// Resolves a translation path through the nesting hierarchy
-    ///
-    /// # Arguments
-    /// * `path` - Slice of path segments to resolve
-    ///
-    /// # Returns
-    /// Reference to translations if path exists and points to leaf node
-    pub fn get_path(&self, path: Vec<&str>) -> Option<&HashMap<Iso639a, String>> {
-        match self {
-            Self::Object(nested) => {
-                let (first, rest) = path.split_first()?;
-                nested.get(*first)?.get_path(rest.to_vec())
-            },
-            Self::Translation(translation) => path.is_empty().then_some(translation),
-        }
+    #[cfg(any(feature = "csv", feature = "xliff"))]
+    let translations = merge_imports(translations);
+
+    let mut translations = TranslationNodeCollection::new(translations);
+
+    // Migrations are written with the namespace, as they are used
+    if let Some(namespace) = config.namespace() {
+        translations = translations.with_namespace(namespace);
     }
+
+    let translations =
+        Box::leak(Box::new(translations.with_migrations(config.migrations().clone())));
+    lint_translations(translations)?;
+    cache.insert(key, translations);
+
+    Ok(translations)
 }
 
-impl From<NestingType> for TokenStream {
-    /// Converts NestingType to procedural macro output tokens
-    fn from(val: NestingType) -> Self {
-        match val {
-            NestingType::Object(nesting) => {
-                let entries = nesting.into_iter().map(|(key, value)| -> TokenStream {
-                    let key = LitStr::new(&key, Span::call_site());
-                    let value: TokenStream = value.into();
-                    quote! { (#key.to_string(), #value) }
-                });
-
-                quote! {
-                    translatable::internal::NestingType::Object(vec![#(#entries),*].into_iter().collect())
-                }
-            },
+/// Runs the configured lints over the translations
+///
+/// Lints run once per configuration, as the translations are cached, and
+/// before the cache is filled so denied findings fail every build.
+///
+/// # Returns
+/// - `Ok(())` if no finding is denied, the warned ones being deferred
+/// - `Err(TranslationError)` with the first denied finding
+fn lint_translations(translations: &TranslationNodeCollection) -> Result<(), TranslationError> {
+    let config = load_config()?;
 
-            NestingType::Translation(translation) => {
-                let entries = translation.into_iter().map(|(lang, value)| {
-                    let lang = LitStr::new(&format!("{lang:?}").to_lowercase(), Span::call_site());
-                    let value = LitStr::new(&value, Span::call_site());
+    let severities = config.lints().iter().copied().collect::<HashMap<_, _>>();
+    let lints = config
+        .lints()
+        .iter()
+        .filter(|(_, severity)| *severity != Severity::Allow)
+        .map(|(lint, _)| *lint)
+        .collect::<Vec<_>>();
 
-                    quote! { (#lang.to_string(), #value.to_string()) }
-                });
+    if lints.is_empty() {
+        return Ok(());
+    }
 
-                quote! {
-                    translatable::internal::NestingType::Translation(vec![#(#entries),*].into_iter().collect())
-                }
-            },
+    for finding in lint_catalog(translations, config.source_language(), &lints) {
+        let severity = severities[&finding.lint()];
+        let error = TranslationError::Lint(finding);
+
+        match severity {
+            Severity::Deny => return Err(error),
+            Severity::Warn => defer_warning(error),
+            Severity::Allow => {},
         }
     }
+
+    Ok(())
 }
 
-impl TryFrom<Table> for NestingType {
-    type Error = TransformError;
-
-    /// Converts TOML table to validated translation structure
-    fn try_from(value: Table) -> Result<Self, Self::Error> {
-        let mut result = None;
-
-        for (key, value) in value {
-            match value {
-                Value::String(translation_value) => {
-                    // Initialize result if first entry
-                    let result = result.get_or_insert_with(|| Self::Translation(HashMap::new()));
-
-                    match result {
-                        Self::Translation(translation) => {
-                            if !templates_valid(&translation_value) {
-                                return Err(TransformError::UnclosedTemplate);
-                            }
-                            translation.insert(key.parse()?, translation_value);
-                        },
-                        Self::Object(_) => return Err(TransformError::InvalidNesting),
-                    }
-                },
+/// Runs a macro expansion with the translations rendered from Markdown
+///
+/// Every translation the expansion loads is rendered to sanitized HTML, so
+/// both static and runtime resolutions embed the rendered translations.
+#[cfg(feature = "markdown")]
+pub fn with_markdown<T>(expansion: impl FnOnce() -> T) -> T {
+    MARKDOWN.with(|markdown| markdown.set(true));
+    let result = expansion();
+    MARKDOWN.with(|markdown| markdown.set(false));
+
+    result
+}
+
+/// Loads and caches the translations rendered from Markdown
+#[cfg(feature = "markdown")]
+fn load_markdown_translations() -> Result<&'static TranslationNodeCollection, TranslationError> {
+    /// Renders the translations of a node in place
+    fn render(node: &mut TranslationNode) {
+        match node {
+            TranslationNode::Object(nested) => nested.values_mut().for_each(render),
+            TranslationNode::Translation(translations) => {
+                for translation in translations.values_mut() {
+                    *translation = render_markdown(translation);
+                }
+            },
+        }
+    }
 
-                Value::Table(nesting_value) => {
-                    let result = result.get_or_insert_with(|| Self::Object(HashMap::new()));
+    let key = load_config()? as *const _ as usize;
 
-                    match result {
-                        Self::Object(nesting) => {
-                            nesting.insert(key, Self::try_from(nesting_value)?);
-                        },
-                        Self::Translation(_) => return Err(TransformError::InvalidNesting),
-                    }
-                },
+    // The source translations are loaded as outside Markdown expansions
+    let source = MARKDOWN.with(|markdown| {
+        markdown.set(false);
+        let source = load_translations();
+        markdown.set(true);
+        source
+    })?;
 
-                _ => return Err(TransformError::InvalidValue),
-            }
-        }
+    let mut cache = MARKDOWN_TRANSLATIONS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
 
-        result.ok_or(TransformError::InvalidValue)
+    if let Some(translations) = cache.get(&key) {
+        return Ok(translations);
     }
-}
 
-impl AssociatedTranslation {
-    /// Gets the original file path of the translation
-    #[allow(unused)]
-    pub fn original_path(&self) -> &str {
-        &self.original_path
-    }
+    let rendered = source
+        .iter()
+        .map(|association| {
+            let mut translation_table = association.translation_table().clone();
+            render(&mut translation_table);
+
+            AssociatedTranslation::new(association.original_path(), translation_table)
+                .with_priority(association.priority())
+                .with_version(association.version())
+                .with_deprecations(association.deprecations().clone())
+                .with_locations(association.locations().clone())
+        })
+        .collect();
 
-    /// Gets reference to the translation data structure
-    #[allow(unused)]
-    pub fn translation_table(&self) -> &NestingType {
-        &self.translation_table
-    }
+    let translations = Box::leak(Box::new(
+        TranslationNodeCollection::new(rendered).with_migrations(source.migrations().clone()),
+    ));
+    cache.insert(key, translations);
+
+    Ok(translations)
 }
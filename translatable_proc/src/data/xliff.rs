@@ -0,0 +1,158 @@
+//! XLIFF 2.0 translation file import
+//!
+//! This module reads the XLIFF documents translation management systems
+//! return into the same TOML tables translation files are parsed into, so
+//! they go through the same validation.
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use toml::Table;
+
+use super::imports::{insert_translation, language_key};
+
+/// Text element of a unit being read
+enum UnitText {
+    /// Text in the source language
+    Source,
+    /// Text in the target language
+    Target,
+}
+
+/// Unit being read, with its texts concatenated across segments
+struct Unit {
+    /// Dot-separated path of the unit
+    id: String,
+    /// Text in the source language
+    source: String,
+    /// Text in the target language, if translated
+    target: Option<String>,
+}
+
+/// Gets the value of an attribute of an element
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, String> {
+    element
+        .try_get_attribute(name)
+        .map_err(|err| err.to_string())?
+        .map(|attribute| attribute.unescape_value().map(|value| value.into_owned()))
+        .transpose()
+        .map_err(|err| err.to_string())
+}
+
+/// Appends text to the text element of a unit being read, if any
+fn push_text(unit: &mut Option<Unit>, text: &Option<UnitText>, content: &str) {
+    match (text, unit) {
+        (Some(UnitText::Source), Some(unit)) => unit.source.push_str(content),
+        (Some(UnitText::Target), Some(unit)) => {
+            unit.target.get_or_insert_with(String::new).push_str(content)
+        },
+        _ => {},
+    }
+}
+
+/// Parses an XLIFF 2.0 document into a translation table
+///
+/// Every unit is a translation object at the path of its `id`, with its
+/// source and target texts in the `srcLang` and `trgLang` languages of the
+/// document. Units without a target only have the source text.
+///
+/// # Returns
+/// The translation table, or a message describing why the document is
+/// invalid
+pub fn parse_xliff(content: &str) -> Result<Table, String> {
+    let mut reader = Reader::from_str(content);
+    let mut table = Table::new();
+
+    let mut languages = None;
+    let mut unit = None::<Unit>;
+    let mut text = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|err| format!("{err} at position {}", reader.error_position()))?;
+
+        match event {
+            Event::Start(element) | Event::Empty(element)
+                if element.local_name().as_ref() == b"xliff" =>
+            {
+                let source = attribute(&element, "srcLang")?
+                    .ok_or("The document has no 'srcLang' attribute.")?;
+
+                languages =
+                    Some((language_key(source), attribute(&element, "trgLang")?.map(language_key)));
+            },
+
+            Event::Start(element) if element.local_name().as_ref() == b"unit" => {
+                let id = attribute(&element, "id")?.ok_or("A unit has no 'id' attribute.")?;
+                unit = Some(Unit { id, source: String::new(), target: None });
+            },
+
+            Event::Start(element) | Event::Empty(element) if text.is_some() => {
+                let id = unit.as_ref().map_or("", |unit| unit.id.as_str());
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+
+                return Err(format!(
+                    "The unit '{id}' has inline markup ('{name}'), which isn't supported."
+                ));
+            },
+
+            Event::Start(element) if unit.is_some() => {
+                text = match element.local_name().as_ref() {
+                    b"source" => Some(UnitText::Source),
+                    b"target" => Some(UnitText::Target),
+                    _ => None,
+                };
+
+                if let (Some(UnitText::Target), Some(unit)) = (&text, &mut unit) {
+                    unit.target.get_or_insert_with(String::new);
+                }
+            },
+
+            Event::Empty(element) if element.local_name().as_ref() == b"target" => {
+                if let Some(unit) = &mut unit {
+                    unit.target.get_or_insert_with(String::new);
+                }
+            },
+
+            Event::Text(content) => {
+                push_text(&mut unit, &text, &content.unescape().map_err(|err| err.to_string())?)
+            },
+
+            Event::CData(content) => {
+                push_text(&mut unit, &text, &String::from_utf8_lossy(&content))
+            },
+
+            Event::End(element) => match element.local_name().as_ref() {
+                b"source" | b"target" => text = None,
+                b"unit" => {
+                    let Some(Unit { id, source, target }) = unit.take() else {
+                        continue;
+                    };
+
+                    let (source_language, target_language) =
+                        languages.as_ref().ok_or("A unit is outside of the 'xliff' element.")?;
+
+                    insert_translation(&mut table, &id, source_language, source)?;
+
+                    if let Some(target) = target {
+                        let target_language = target_language
+                            .as_ref()
+                            .ok_or("The document has targets but no 'trgLang' attribute.")?;
+
+                        insert_translation(&mut table, &id, target_language, target)?;
+                    }
+                },
+                _ => {},
+            },
+
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+
+    if languages.is_none() {
+        return Err("The document has no 'xliff' element.".into());
+    }
+
+    Ok(table)
+}
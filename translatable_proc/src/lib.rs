@@ -7,13 +7,26 @@
 //! - Configurable loading strategies
 //! - Procedural macro for compile-time checking
 
-use macros::{RawMacroArgs, translation_macro};
+#![cfg_attr(feature = "nightly", feature(proc_macro_diagnostic))]
+
+#[cfg(feature = "markdown")]
+use macros::translation_markdown_macro;
+use macros::{
+    RawBatchArgs, RawCompletenessArgs, RawInlineArgs, RawMacroArgs, RawWriteArgs,
+    assert_translations_complete_macro, embed_translations_macro, include_translation_file_macro,
+    translation_accessors_macro, translation_inline_macro, translation_macro,
+    translation_or_key_macro, translation_slugs_macro, translation_stats_macro,
+    translation_write_macro, translations_macro, try_translation_macro,
+};
+use prefix::translation_prefix_macro;
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use quote::quote;
+use syn::{Item, LitStr, parse_macro_input};
+use translations::diagnostics::{pending_warnings, with_pending_warnings};
 
 mod data;
-mod languages;
 mod macros;
+mod prefix;
 mod translations;
 
 /// Procedural macro for compile-time translation validation
@@ -26,7 +39,245 @@ mod translations;
 /// # Parameters
 /// - Language code/literal
 /// - Translation path (supports static analysis)
+/// - Format arguments, and a last `@path = "..."` argument loading the
+///   translations of another directory than the configured one
 #[proc_macro]
 pub fn translation(input: TokenStream) -> TokenStream {
-    translation_macro(parse_macro_input!(input as RawMacroArgs).into()).into()
+    let args = parse_macro_input!(input as RawMacroArgs);
+    with_pending_warnings(args.expand(|args| translation_macro(args.into()))).into()
+}
+
+/// Procedural macro for translations that always evaluate to a `Result`
+///
+/// # Usage
+/// ```ignore
+/// try_translation!("en", static some::path)
+/// ```
+///
+/// # Parameters
+/// Same as [`translation!`], fully static resolutions are wrapped in `Ok`.
+#[proc_macro]
+pub fn try_translation(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as RawMacroArgs);
+    with_pending_warnings(args.expand(|args| try_translation_macro(args.into()))).into()
+}
+
+/// Procedural macro for translations that fall back to their path
+///
+/// # Usage
+/// ```ignore
+/// translation_or_key!(language, "some.path")
+/// ```
+///
+/// # Parameters
+/// Same as [`translation!`], runtime resolution failures evaluate to the
+/// path itself.
+#[proc_macro]
+pub fn translation_or_key(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as RawMacroArgs);
+    with_pending_warnings(args.expand(|args| translation_or_key_macro(args.into()))).into()
+}
+
+/// Procedural macro for translations authored in Markdown, rendered to
+/// sanitized HTML
+///
+/// # Usage
+/// ```ignore
+/// translation_markdown!("en", static help::upload, limit = "10 MB")
+/// ```
+///
+/// # Parameters
+/// Same as [`translation!`], the argument values are escaped as HTML unless
+/// `escape = "none"` is given.
+#[cfg(feature = "markdown")]
+#[proc_macro]
+pub fn translation_markdown(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as RawMacroArgs).escape_html();
+    with_pending_warnings(args.expand(|args| translation_markdown_macro(args.into()))).into()
+}
+
+/// Procedural macro for translations written into a `fmt::Write` or
+/// `io::Write` target
+///
+/// # Usage
+/// ```ignore
+/// translation_write!(&mut output, "en", static common::greeting, name = "john")
+/// ```
+///
+/// # Parameters
+/// - Writer, its `Write` trait must be in scope
+/// - Same as [`translation!`], without `default` or `..collection` arguments
+#[proc_macro]
+pub fn translation_write(input: TokenStream) -> TokenStream {
+    let RawWriteArgs { writer, args } = parse_macro_input!(input as RawWriteArgs);
+    with_pending_warnings(args.expand(|args| translation_write_macro(writer, args.into()))).into()
+}
+
+/// Procedural macro for translations declared inline
+///
+/// # Usage
+/// ```ignore
+/// translation_inline!("en", { en = "Hello {name}!", es = "¡Hola {name}!" }, name = "john")
+/// ```
+///
+/// # Parameters
+/// Same as [`translation!`], with an inline translation object instead of
+/// the path, validated just like a translation file entry.
+#[proc_macro]
+pub fn translation_inline(input: TokenStream) -> TokenStream {
+    translation_inline_macro(parse_macro_input!(input as RawInlineArgs)).into()
+}
+
+/// Procedural macro for a batch of translations sharing a language
+///
+/// # Usage
+/// ```ignore
+/// let (greeting, welcome) = translations!("en", static common::greeting, static welcome_message);
+/// ```
+///
+/// # Parameters
+/// - Language code/literal, validated once for the whole batch
+/// - Static translation paths, evaluated to a tuple in the same order
+#[proc_macro]
+pub fn translations(input: TokenStream) -> TokenStream {
+    with_pending_warnings(translations_macro(parse_macro_input!(input as RawBatchArgs))).into()
+}
+
+/// Procedural macro embedding all the loaded translations
+///
+/// # Usage
+/// ```ignore
+/// translatable::set_translations(embed_translations!())
+/// ```
+///
+/// Evaluates to a `translatable::TranslationNodeCollection` in lookup
+/// priority order, for runtime APIs such as `translatable::Translator`.
+#[proc_macro]
+pub fn embed_translations(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as syn::parse::Nothing);
+
+    with_pending_warnings(embed_translations_macro()).into()
+}
+
+/// Procedural macro embedding a single translation file
+///
+/// # Usage
+/// ```ignore
+/// Translator::with_translations(language, &include_translation_file!("assets/translations.toml"))
+/// ```
+///
+/// The path is relative to the manifest directory of the crate invoking the
+/// macro, evaluates to a `translatable::TranslationNodeCollection` regardless
+/// of the `translatable.toml` configuration.
+#[proc_macro]
+pub fn include_translation_file(input: TokenStream) -> TokenStream {
+    with_pending_warnings(include_translation_file_macro(parse_macro_input!(input as LitStr)))
+        .into()
+}
+
+/// Procedural macro generating typed accessor functions per translation
+///
+/// # Usage
+/// ```ignore
+/// mod translations {
+///     translatable::translation_accessors!();
+/// }
+///
+/// translations::common::greeting(Language::ES, "john")
+/// ```
+///
+/// Expands to a module per nesting and a function per translation object,
+/// taking the language and one `impl Display` parameter per template in
+/// alphabetical order, returning `Result<Cow<'static, str>,
+/// translatable::Error>`.
+#[proc_macro]
+pub fn translation_accessors(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as syn::parse::Nothing);
+
+    let accessors = translation_accessors_macro();
+    let warnings = pending_warnings();
+
+    quote! { #warnings #accessors }.into()
+}
+
+/// Procedural macro computing the coverage of the translation files
+///
+/// # Usage
+/// ```ignore
+/// let stats = translatable::translation_stats!();
+/// assert!(stats.completion() >= 90.0);
+/// ```
+///
+/// Evaluates to a `translatable::TranslationStats` computed at compile time,
+/// with the total number of paths, the translated paths per language and
+/// the missing language and path pairs.
+#[proc_macro]
+pub fn translation_stats(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as syn::parse::Nothing);
+
+    with_pending_warnings(translation_stats_macro()).into()
+}
+
+/// Procedural macro declaring localized URL slugs as translations
+///
+/// # Usage
+/// ```ignore
+/// static SLUGS: translatable::Slugs = translatable::translation_slugs!("routes");
+///
+/// let (language, route) = SLUGS.resolve("/es/ajustes").unwrap();
+/// assert_eq!(route, ["settings"]);
+/// ```
+///
+/// Every translation object nested under the prefix is a route keyed by its
+/// path relative to the prefix, such as `routes.settings` with `en =
+/// "settings"` and `es = "ajustes"`. Fails to compile if a slug is empty,
+/// contains a `/`, or is used by more than one route in the same language.
+#[proc_macro]
+pub fn translation_slugs(input: TokenStream) -> TokenStream {
+    with_pending_warnings(translation_slugs_macro(parse_macro_input!(input as LitStr))).into()
+}
+
+/// Procedural macro asserting the translation files are complete
+///
+/// # Usage
+/// ```ignore
+/// #[test]
+/// fn translations_complete() {
+///     translatable::assert_translations_complete!(prefix = "checkout", languages = ["en", "es"]);
+/// }
+/// ```
+///
+/// # Parameters
+/// - `prefix` - Optional dot-separated path the check is scoped to
+/// - `languages` - Optional languages every path must have, the languages found
+///   in any translation by default
+///
+/// Panics listing every path missing a language, the check itself is done
+/// at compile time.
+#[proc_macro]
+pub fn assert_translations_complete(input: TokenStream) -> TokenStream {
+    with_pending_warnings(assert_translations_complete_macro(parse_macro_input!(
+        input as RawCompletenessArgs
+    )))
+    .into()
+}
+
+/// Attribute macro resolving inner translation paths relative to a prefix
+///
+/// # Usage
+/// ```ignore
+/// #[translation_prefix("checkout")]
+/// mod checkout {
+///     fn title() -> &'static str {
+///         translation!("en", static title) // resolves checkout.title
+///     }
+/// }
+/// ```
+///
+/// Applies to modules, functions and `impl` blocks, nested prefixes are
+/// resolved relative to their parent.
+#[proc_macro_attribute]
+pub fn translation_prefix(attr: TokenStream, item: TokenStream) -> TokenStream {
+    translation_prefix_macro(parse_macro_input!(attr as LitStr), parse_macro_input!(item as Item))
+        .into()
 }
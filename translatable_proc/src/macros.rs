@@ -1,27 +1,52 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::env::var;
 use std::fmt::Display;
+use std::path::PathBuf;
 
-use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Static;
+use syn::spanned::Spanned;
+use syn::token::{Brace, Static};
 use syn::{
-    Expr, ExprLit, ExprPath, Ident, Lit, MetaNameValue, Path, Result as SynResult, Token,
-    parse_quote,
+    Error as SynError, Expr, ExprGroup, ExprLit, ExprMacro, ExprParen, ExprPath, Ident, Lit,
+    LitStr, MetaNameValue, Path, Result as SynResult, Token, braced, parse_quote, parse2,
+};
+use toml::{Table, Value};
+use translatable_shared::{
+    AssociatedTranslation, Escape, TranslationNode, TranslationNodeCollection,
 };
 
+use crate::data::config::{load_config, with_path_override};
+#[cfg(feature = "markdown")]
+use crate::data::translations::with_markdown;
+use crate::data::translations::{load_translation_file, load_translations};
+use crate::translations::accessors::load_translation_accessors;
+use crate::translations::diagnostics::{error, item_error};
+use crate::translations::errors::TranslationError;
 use crate::translations::generation::{
-    load_lang_dynamic, load_lang_static, load_translation_dynamic, load_translation_static,
+    VARIANT_ARGUMENT, load_formatted_arguments, load_lang_dynamic, load_lang_static,
+    load_translation_dynamic, load_translation_interpolated, load_translation_object,
+    load_translation_static, load_translations_batch,
 };
+use crate::translations::slugs::load_translation_slugs;
+use crate::translations::stats::{load_translation_stats, load_translations_complete};
 
 /// Represents raw input arguments for the translation macro
 ///
 /// Parses input in the format: `(language_spec, static translation_path)`
 ///
 /// # Syntax
-/// - `language_spec`: String literal or expression implementing `Into<String>`
+/// - `language_spec`: String literal, expression implementing `Into<String>` or
+///   `Language`
 /// - `translation_path`: Path expression (either static or dynamic)
+/// - `key = value` pairs or `..collection` runtime format arguments
+/// - `default = value` fallback for runtime resolution failures
+/// - `escape = "html"` escaping of the format argument values
+/// - `@path = "./translations"` directory of the translations, overriding the
+///   configured `path` for this invocation
 pub struct RawMacroArgs {
     /// Language specification (either literal string or expression)
     language: Expr,
@@ -30,11 +55,38 @@ pub struct RawMacroArgs {
     /// Optional `static` keyword marker for path resolution
     static_marker: Option<Static>,
     /// Translation path (either static path or dynamic expression)
-    path: Expr,
+    path: RawPath,
     /// Optional comma separator for additional arguments
     _comma2: Option<Token![,]>,
     /// Format arguments for string interpolation
     format_kwargs: Punctuated<MetaNameValue, Token![,]>,
+    /// Positional format arguments, replacing the `{0}`, `{1}`... templates
+    format_args: Vec<Expr>,
+    /// Runtime format argument collections prefixed with `..`
+    format_maps: Vec<Expr>,
+    /// Translations directory of the `@path` meta argument
+    catalog_path: Option<LitStr>,
+}
+
+/// Represents a raw translation path argument
+pub enum RawPath {
+    /// Path expression (either static path or dynamic expression)
+    Expr(Expr),
+    /// Static path with a single runtime segment
+    Interpolated(InterpolatedPath),
+}
+
+/// Static path containing a single runtime segment
+///
+/// Parses input in the format: `prefix::{segment}::suffix`, where the suffix
+/// is optional and at least one prefix segment is required.
+pub struct InterpolatedPath {
+    /// Static segments before the runtime segment
+    prefix: Vec<Ident>,
+    /// Runtime segment expression implementing `Display`
+    segment: Expr,
+    /// Static segments after the runtime segment
+    suffix: Vec<Ident>,
 }
 
 /// Represents the type of translation path resolution
@@ -43,6 +95,8 @@ pub enum PathType {
     OnScopeExpression(TokenStream),
     /// Compile-time resolved path string
     CompileTimePath(String),
+    /// Compile-time resolved prefix and suffix around a runtime segment
+    Interpolated(String, TokenStream, String),
 }
 
 /// Represents the type of language specification
@@ -60,64 +114,108 @@ pub struct TranslationArgs {
     /// Path resolution type
     path: PathType,
     /// Format arguments for string interpolation
-    format_kwargs: HashMap<String, TokenStream>,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    /// Runtime format argument collections
+    format_maps: Vec<TokenStream>,
+    /// Fallback expression for runtime resolution failures
+    fallback: Option<TokenStream>,
+    /// Whether a runtime-looking argument was folded to a compile-time
+    /// value, keeping the runtime return type
+    folded: bool,
+    /// Marker types naming the format arguments in type errors, and the
+    /// bindings of their values
+    argument_markers: TokenStream,
+    /// Span of the language argument, errors about the language are reported
+    /// at it
+    language_span: Span,
+    /// Span of the path argument, errors about the translation are reported
+    /// at it
+    path_span: Span,
+}
+
+/// Represents raw input arguments for the batch translation macro
+///
+/// Parses input in the format: `(language_spec, static path, static path...)`
+pub struct RawBatchArgs {
+    /// Language specification (either literal string or expression)
+    language: Expr,
+    /// Static translation paths resolved in the batch
+    paths: Vec<Path>,
+}
+
+/// Represents raw input arguments for the translation completeness macro
+///
+/// Parses input in the format: `(prefix = "path", languages = ["en", ...])`
+/// where both arguments are optional.
+pub struct RawCompletenessArgs {
+    /// Dot-separated path the check is scoped to
+    prefix: Option<LitStr>,
+    /// Languages every path must have
+    languages: Option<Vec<LitStr>>,
+}
+
+/// Represents raw input arguments for the inline translation macro
+///
+/// Parses input in the format: `(language_spec, { lang = "...", ... }, ...)`
+/// where the rest of arguments are the same format arguments as in
+/// [`RawMacroArgs`].
+pub struct RawInlineArgs {
+    /// Language specification (either literal string or expression)
+    language: Expr,
+    /// Inline translation object as language and translation pairs
+    translations: Punctuated<MetaNameValue, Token![,]>,
+    /// Format arguments for string interpolation
+    format_kwargs: Punctuated<MetaNameValue, Token![,]>,
+    /// Positional format arguments, replacing the `{0}`, `{1}`... templates
+    format_args: Vec<Expr>,
+    /// Runtime format argument collections prefixed with `..`
+    format_maps: Vec<Expr>,
+}
+
+/// Represents raw input arguments for the writing translation macro
+///
+/// Parses input in the format: `(writer, language_spec, path, ...)` where the
+/// rest of arguments are the same as in [`RawMacroArgs`].
+pub struct RawWriteArgs {
+    /// Writer expression implementing `fmt::Write` or `io::Write`
+    pub writer: Expr,
+    /// Translation arguments after the writer
+    pub args: RawMacroArgs,
+}
+
+impl Parse for RawWriteArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let writer = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        Ok(RawWriteArgs { writer, args: input.parse()? })
+    }
 }
 
 impl Parse for RawMacroArgs {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let language = input.parse()?;
         let _comma = input.parse()?;
-        let static_marker = input.parse()?;
-        let path = input.parse()?;
+        let static_marker: Option<Static> = input.parse()?;
+
+        // Static paths may contain a runtime segment, otherwise parse as an expression
+        let interpolated = input.fork();
+        let path = match interpolated.parse::<InterpolatedPath>() {
+            Ok(path) if static_marker.is_some() => {
+                input.advance_to(&interpolated);
+                RawPath::Interpolated(path)
+            },
+            _ => RawPath::Expr(input.parse()?),
+        };
 
         // Parse optional comma before format arguments
         let _comma2 = if input.peek(Token![,]) { Some(input.parse()?) } else { None };
 
-        let mut format_kwargs = Punctuated::new();
-
         // Parse format arguments if comma was present
-        if _comma2.is_some() {
-            while !input.is_empty() {
-                let lookahead = input.lookahead1();
-
-                // Handle both identifier-based and arbitrary key-value pairs
-                if lookahead.peek(Ident) {
-                    let key: Ident = input.parse()?;
-                    let eq_token: Token![=] = input.parse().unwrap_or(Token![=](key.span()));
-                    let mut value = input.parse::<Expr>();
-
-                    if let Ok(value) = &mut value {
-                        let key_string = key.to_string();
-                        if key_string == value.to_token_stream().to_string() {
-                            // let warning = format!(
-                            //     "redundant field initialier, use
-                            //  `{key_string}` instead of `{key_string} = {key_string}`"
-                            // );
-
-                            // Generate warning for redundant initializer
-                            *value = parse_quote! {{
-                                // compile_warn!(#warning);
-                                // !!! https://internals.rust-lang.org/t/pre-rfc-add-compile-warning-macro/9370 !!!
-                                #value
-                            }}
-                        }
-                    }
-
-                    let value = value.unwrap_or(parse_quote!(#key));
-
-                    format_kwargs.push(MetaNameValue { path: Path::from(key), eq_token, value });
-                } else {
-                    format_kwargs.push(input.parse()?);
-                }
+        let (format_kwargs, format_args, format_maps) =
+            if _comma2.is_some() { parse_format_args(input)? } else { Default::default() };
 
-                // Continue parsing while commas are present
-                if input.peek(Token![,]) {
-                    input.parse::<Token![,]>()?;
-                } else {
-                    break;
-                }
-            }
-        };
+        let catalog_path = parse_meta_args(input)?;
 
         Ok(RawMacroArgs {
             language,
@@ -126,64 +224,620 @@ impl Parse for RawMacroArgs {
             path,
             _comma2,
             format_kwargs,
+            format_args,
+            format_maps,
+            catalog_path,
         })
     }
 }
 
+impl RawMacroArgs {
+    /// Expands the invocation with the translations of the `@path` argument,
+    /// if any, see [`with_path_override`]
+    pub fn expand<T>(self, expansion: impl FnOnce(Self) -> T) -> T {
+        let path = self.catalog_path.as_ref().map(LitStr::value);
+
+        with_path_override(path, || expansion(self))
+    }
+
+    /// Escapes the format argument values as HTML, unless an `escape`
+    /// argument selects otherwise
+    #[cfg(feature = "markdown")]
+    pub fn escape_html(mut self) -> Self {
+        if !self.format_kwargs.iter().any(|pair| pair.path.is_ident(ESCAPE_ARGUMENT)) {
+            self.format_kwargs.push(parse_quote!(escape = "html"));
+        }
+
+        self
+    }
+}
+
+/// Meta argument selecting the translations directory of an invocation
+pub const PATH_META_ARGUMENT: &str = "path";
+
+/// Parses the `@key = value` meta arguments ending the macro arguments
+///
+/// # Returns
+/// - `Ok(Option<LitStr>)` with the `@path` directory, if given
+/// - `Err(SynError)` if a meta argument is unknown, repeated or not a string
+///   literal
+fn parse_meta_args(input: ParseStream) -> SynResult<Option<LitStr>> {
+    let mut catalog_path = None;
+
+    while input.peek(Token![@]) {
+        input.parse::<Token![@]>()?;
+        let key = input.parse::<Ident>()?;
+
+        if key != PATH_META_ARGUMENT {
+            return Err(SynError::new_spanned(
+                key,
+                "Unknown meta argument, only '@path = \"...\"' is supported.",
+            ));
+        }
+
+        if catalog_path.is_some() {
+            return Err(SynError::new_spanned(key, "The '@path' argument is given twice."));
+        }
+
+        input.parse::<Token![=]>()?;
+        catalog_path = Some(input.parse::<LitStr>()?);
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(catalog_path)
+}
+
+/// Format arguments of a macro, the named ones, the positional ones and the
+/// runtime collections
+type FormatArgs = (Punctuated<MetaNameValue, Token![,]>, Vec<Expr>, Vec<Expr>);
+
+/// Parses the format arguments after the translation path
+///
+/// Accepts `key = value` pairs, `key` shorthands, `..collection` runtime
+/// format argument collections and positional expressions before them,
+/// separated by commas. Bare identifiers are shorthands, so variables are
+/// passed positionally as other expressions, such as `&name`.
+fn parse_format_args(input: ParseStream) -> SynResult<FormatArgs> {
+    let mut format_kwargs = Punctuated::new();
+    let mut format_args = Vec::new();
+    let mut format_maps = Vec::new();
+
+    // Meta arguments come last
+    while !input.is_empty() && !input.peek(Token![@]) {
+        let lookahead = input.lookahead1();
+
+        // Named arguments are an identifier or a path followed by `=`, a
+        // comma or the end of the arguments
+        let named = {
+            let fork = input.fork();
+            fork.parse::<Path>().is_ok()
+                && (fork.is_empty()
+                    || fork.peek(Token![,])
+                    || (fork.peek(Token![=]) && !fork.peek(Token![==])))
+        };
+
+        // Handle runtime collections, identifier-based and arbitrary key-value
+        // pairs, then positional arguments
+        if lookahead.peek(Token![..]) {
+            input.parse::<Token![..]>()?;
+            format_maps.push(input.parse()?);
+        } else if !named {
+            let value = input.parse::<Expr>()?;
+
+            if !format_kwargs.is_empty() || !format_maps.is_empty() {
+                return Err(SynError::new_spanned(
+                    value,
+                    "Positional arguments should come before the named ones.",
+                ));
+            }
+
+            format_args.push(value);
+        } else if lookahead.peek(Ident) {
+            let key: Ident = input.parse()?;
+            let eq_token: Token![=] = input.parse().unwrap_or(Token![=](key.span()));
+            let mut value = input.parse::<Expr>();
+
+            if let Ok(value) = &mut value {
+                let key_string = key.to_string();
+                if key_string == value.to_token_stream().to_string() {
+                    // let warning = format!(
+                    //     "redundant field initialier, use
+                    //  `{key_string}` instead of `{key_string} = {key_string}`"
+                    // );
+
+                    // Generate warning for redundant initializer
+                    *value = parse_quote! {{
+                        // compile_warn!(#warning);
+                        // !!! https://internals.rust-lang.org/t/pre-rfc-add-compile-warning-macro/9370 !!!
+                        #value
+                    }}
+                }
+            }
+
+            let value = value.unwrap_or(parse_quote!(#key));
+
+            format_kwargs.push(MetaNameValue { path: Path::from(key), eq_token, value });
+        } else {
+            format_kwargs.push(input.parse()?);
+        }
+
+        // Continue parsing while commas are present
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        } else {
+            break;
+        }
+    }
+
+    // The escaping is selected at compile time
+    let invalid_escape = format_kwargs.iter().find(|pair| {
+        pair.path.is_ident(ESCAPE_ARGUMENT)
+            && escape_literal(pair.value.to_token_stream()).is_none()
+    });
+
+    if let Some(pair) = invalid_escape {
+        return Err(SynError::new_spanned(
+            &pair.value,
+            "The 'escape' argument should be either \"none\" or \"html\".",
+        ));
+    }
+
+    Ok((format_kwargs, format_args, format_maps))
+}
+
+/// Named argument selecting the escaping of the format argument values
+const ESCAPE_ARGUMENT: &str = "escape";
+
+/// Parses the value of an `escape` argument, a string literal
+fn escape_literal(value: TokenStream) -> Option<Escape> {
+    parse2::<LitStr>(value).ok()?.value().parse().ok()
+}
+
+/// Escapes the format argument values as selected by the `escape` argument,
+/// which is removed, or as configured otherwise
+///
+/// Values are wrapped in `translatable::HtmlEscaped`, so only the replaced
+/// values are escaped and the markup of the translations is kept as it is.
+/// The `variant` argument selects a translation, so it's never escaped.
+fn escape_arguments(
+    format_kwargs: &mut BTreeMap<String, TokenStream>,
+    format_maps: &mut [TokenStream],
+) {
+    let escape = match format_kwargs.remove(ESCAPE_ARGUMENT) {
+        Some(escape) => escape_literal(escape).unwrap_or_default(),
+        // Configuration errors are reported when loading the translations
+        None => load_config().map(|config| config.escape()).unwrap_or_default(),
+    };
+
+    if escape == Escape::None {
+        return;
+    }
+
+    for (key, value) in format_kwargs.iter_mut() {
+        if key != VARIANT_ARGUMENT && !value.is_empty() {
+            *value = quote! { translatable::HtmlEscaped(&(#value)) };
+        }
+    }
+
+    for map in format_maps {
+        *map = quote! {
+            (#map).into_iter().map(|(key, value)| (key, translatable::HtmlEscaped(value)))
+        };
+    }
+}
+
+/// Checks the format argument values where they are written
+///
+/// Values are wrapped in `translatable::internal::format_argument`, spanned
+/// to the value expression, so a value not implementing `Display` is reported
+/// there with the argument name and the translation path, which are given by
+/// the marker types returned.
+///
+/// # Arguments
+/// * `format_kwargs` - Format arguments, the `escape` one not removed yet
+/// * `path` - Dot-separated translation path, if known at compile time
+///
+/// # Returns
+/// The marker type declarations, which have to be in scope of the values
+fn check_arguments(format_kwargs: &mut BTreeMap<String, TokenStream>, path: &str) -> TokenStream {
+    let path_marker =
+        marker_ident(path).unwrap_or_else(|| Ident::new("dynamic_path", Span::call_site()));
+    let mut markers = Vec::new();
+
+    for (key, value) in format_kwargs.iter_mut() {
+        if key == VARIANT_ARGUMENT || key == ESCAPE_ARGUMENT || value.is_empty() {
+            continue;
+        }
+
+        // Arguments without a valid identifier are only checked when replaced
+        let Some(marker) = marker_ident(key) else {
+            continue;
+        };
+
+        *value = quote_spanned! { value.span()=>
+            translatable::internal::format_argument::<
+                translatable_arguments::#marker,
+                translatable_path::#path_marker,
+                _,
+            >(&(#value))
+        };
+        markers.push(marker);
+    }
+
+    if markers.is_empty() {
+        return TokenStream::new();
+    }
+
+    markers.sort();
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_camel_case_types)]
+        mod translatable_arguments {
+            #(pub struct #markers;)*
+        }
+
+        #[doc(hidden)]
+        #[allow(dead_code, non_camel_case_types)]
+        mod translatable_path {
+            pub struct #path_marker;
+        }
+    }
+}
+
+/// Binds the format argument values, so each of them is evaluated once
+///
+/// A value may be used by several formatters and by the replacements, so
+/// the values are replaced by a dereference of their binding, spanned to the
+/// value expression to keep the argument checks reported there. The
+/// `variant` argument selects a translation, so it's left as it is.
+///
+/// # Returns
+/// The `let` statements binding the values, which have to be in scope of
+/// the generated code
+fn bind_arguments(format_kwargs: &mut BTreeMap<String, TokenStream>) -> TokenStream {
+    let mut bindings = Vec::new();
+
+    for (index, (key, value)) in format_kwargs.iter_mut().enumerate() {
+        if key == VARIANT_ARGUMENT || key == ESCAPE_ARGUMENT || value.is_empty() {
+            continue;
+        }
+
+        let span = value.span();
+        let binding = Ident::new(&format!("__arg_{index}"), Span::mixed_site().located_at(span));
+
+        bindings.push(quote! {
+            #[doc(hidden)]
+            let #binding = &(#value);
+        });
+        *value = quote_spanned! { span=> *#binding };
+    }
+
+    quote! { #(#bindings)* }
+}
+
+/// Converts an argument name or a translation path to a marker type name,
+/// such as `common·greeting` or `_0` for the positional arguments
+fn marker_ident(name: &str) -> Option<Ident> {
+    let name = name.replace('.', "\u{b7}");
+    let name =
+        if name.starts_with(|c: char| c.is_ascii_digit()) { format!("_{name}") } else { name };
+
+    syn::parse_str(&name).ok()
+}
+
+/// Converts format arguments to a map with string keys
+///
+/// Positional arguments are keyed by their index, such as `0` for the value
+/// replacing the `{0}` templates.
+fn format_kwargs_map(
+    format_kwargs: &Punctuated<MetaNameValue, Token![,]>,
+    format_args: &[Expr],
+) -> BTreeMap<String, TokenStream> {
+    format_args
+        .iter()
+        .enumerate()
+        .map(|(index, value)| (index.to_string(), value.to_token_stream()))
+        .chain(format_kwargs.iter().map(|pair| {
+            (
+                // Extract key as identifier or stringified path
+                pair.path
+                    .get_ident()
+                    .map(|i| i.to_string())
+                    .unwrap_or_else(|| pair.path.to_token_stream().to_string()),
+                // Store value as token stream
+                pair.value.to_token_stream(),
+            )
+        }))
+        .collect()
+}
+
+impl Parse for RawInlineArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let language = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        let content;
+        braced!(content in input);
+        let translations = Punctuated::parse_terminated(&content)?;
+
+        let (format_kwargs, format_args, format_maps) = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            parse_format_args(input)?
+        } else {
+            Default::default()
+        };
+
+        Ok(RawInlineArgs {
+            language,
+            translations,
+            format_kwargs,
+            format_args,
+            format_maps,
+        })
+    }
+}
+
+impl Parse for InterpolatedPath {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut prefix = Vec::new();
+        let mut segment = None;
+        let mut suffix = Vec::new();
+
+        loop {
+            if input.peek(Brace) {
+                let content;
+                braced!(content in input);
+
+                if segment.is_some() {
+                    return Err(content.error("Only a single runtime path segment is supported."));
+                }
+
+                segment = Some(content.parse()?);
+            } else if segment.is_none() {
+                prefix.push(input.parse()?);
+            } else {
+                suffix.push(input.parse()?);
+            }
+
+            if input.peek(Token![::]) {
+                input.parse::<Token![::]>()?;
+            } else {
+                break;
+            }
+        }
+
+        match segment {
+            Some(segment) if !prefix.is_empty() => Ok(Self { prefix, segment, suffix }),
+            _ => Err(input.error("Expected a static path with a runtime segment.")),
+        }
+    }
+}
+
+/// Joins identifiers to a dot-separated string
+fn ident_path_string(idents: &[Ident]) -> String {
+    idents.iter().map(|ident| ident.to_string()).collect::<Vec<_>>().join(".")
+}
+
+impl Parse for RawBatchArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let language = input.parse()?;
+        let mut paths = Vec::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            // Allow a trailing comma
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Static>()?;
+            paths.push(input.parse()?);
+        }
+
+        Ok(RawBatchArgs { language, paths })
+    }
+}
+
+impl Parse for RawCompletenessArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut args = RawCompletenessArgs { prefix: None, languages: None };
+
+        for pair in Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)? {
+            let string = |expr: &Expr| match expr {
+                Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Ok(lit.clone()),
+                other => Err(SynError::new_spanned(other, "Expected a string literal.")),
+            };
+
+            match (pair.path.get_ident().map(|ident| ident.to_string()).as_deref(), &pair.value) {
+                (Some("prefix"), value) => args.prefix = Some(string(value)?),
+                (Some("languages"), Expr::Array(array)) => {
+                    args.languages = Some(array.elems.iter().map(string).collect::<SynResult<_>>()?)
+                },
+                (Some("languages"), other) => {
+                    return Err(SynError::new_spanned(other, "Expected an array of languages."));
+                },
+                _ => {
+                    return Err(SynError::new_spanned(
+                        pair.path,
+                        "Expected either 'prefix' or 'languages'.",
+                    ));
+                },
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// Converts path segments to a dot-separated string
+fn static_path_string(path: &Path) -> String {
+    path.segments.iter().map(|s| s.ident.to_string()).fold(String::new(), |mut acc, s| {
+        if !acc.is_empty() {
+            acc.push('.');
+        }
+        acc.push_str(&s);
+        acc
+    })
+}
+
+/// Evaluates an expression that is constant despite not being a literal
+///
+/// Supports string literals and `concat!` invocations of literals, possibly
+/// nested or parenthesized. Named constants are not visible to procedural
+/// macros, so they are still resolved at runtime.
+pub fn constant_string(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Some(lit.value()),
+            Lit::Char(lit) => Some(lit.value().to_string()),
+            Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            Lit::Bool(lit) => Some(lit.value.to_string()),
+            _ => None,
+        },
+
+        Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) => {
+            constant_string(expr)
+        },
+
+        Expr::Macro(ExprMacro { mac, .. }) if mac.path.is_ident("concat") => mac
+            .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            .ok()?
+            .iter()
+            .map(constant_string)
+            .collect(),
+
+        _ => None,
+    }
+}
+
+/// Converts a language expression to its resolution type
+fn language_type(language: Expr) -> LanguageType {
+    match language {
+        // Handle string literals for compile-time validation
+        Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
+            LanguageType::CompileTimeLiteral(lit_str.value())
+        },
+        // Preserve other expressions for runtime resolution
+        other => LanguageType::OnScopeExpression(quote!(#other)),
+    }
+}
+
 impl From<RawMacroArgs> for TranslationArgs {
     fn from(val: RawMacroArgs) -> Self {
         let is_path_static = val.static_marker.is_some();
 
-        TranslationArgs {
+        // Convert format arguments to BTreeMap with string keys
+        let mut format_kwargs = format_kwargs_map(&val.format_kwargs, &val.format_args);
+
+        // The `default` key is reserved for the fallback
+        let fallback = format_kwargs.remove("default");
+
+        let format_maps =
+            val.format_maps.iter().map(|map| map.to_token_stream()).collect::<Vec<_>>();
+
+        let mut folded = false;
+
+        let language_span = val.language.span();
+        let path_span = match &val.path {
+            RawPath::Interpolated(path) => path.segment.span(),
+            RawPath::Expr(path) => path.span(),
+        };
+
+        let mut args = TranslationArgs {
             // Extract language specification
-            language: match val.language {
-                // Handle string literals for compile-time validation
-                Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
-                    LanguageType::CompileTimeLiteral(lit_str.value())
+            language: match (&val.language, constant_string(&val.language)) {
+                (Expr::Lit(ExprLit { lit: Lit::Str(_), .. }), _) => language_type(val.language),
+                // Fold constant expressions for compile-time validation
+                (_, Some(lang)) => {
+                    folded = true;
+                    LanguageType::CompileTimeLiteral(lang)
                 },
-                // Preserve other expressions for runtime resolution
-                other => LanguageType::OnScopeExpression(quote!(#other)),
+                (_, None) => language_type(val.language),
             },
 
             // Extract path specification
             path: match val.path {
+                // Keep the runtime segment for nested lookup
+                RawPath::Interpolated(InterpolatedPath { prefix, segment, suffix }) => {
+                    PathType::Interpolated(
+                        ident_path_string(&prefix),
+                        segment.to_token_stream(),
+                        ident_path_string(&suffix),
+                    )
+                },
+
                 // Convert path expressions to strings when static marker present
-                Expr::Path(ExprPath { path, .. }) if is_path_static => {
-                    // Convert path segments to a dot-separated string
-                    let path_str = path.segments.iter().map(|s| s.ident.to_string()).fold(
-                        String::new(),
-                        |mut acc, s| {
-                            if !acc.is_empty() {
-                                acc.push('.');
-                            }
-                            acc.push_str(&s);
-                            acc
-                        },
-                    );
-                    PathType::CompileTimePath(path_str)
+                RawPath::Expr(Expr::Path(ExprPath { path, .. })) if is_path_static => {
+                    PathType::CompileTimePath(static_path_string(&path))
                 },
 
-                // Preserve dynamic path expressions
-                path => PathType::OnScopeExpression(quote!(#path)),
+                // Plain string literals are resolved at runtime
+                RawPath::Expr(path @ Expr::Lit(ExprLit { lit: Lit::Str(_), .. })) => {
+                    PathType::OnScopeExpression(quote!(#path))
+                },
+
+                // Fold constant expressions for compile-time validation
+                RawPath::Expr(path) => match constant_string(&path) {
+                    Some(path) => {
+                        folded = true;
+                        PathType::CompileTimePath(path)
+                    },
+                    // Preserve dynamic path expressions
+                    None => PathType::OnScopeExpression(quote!(#path)),
+                },
             },
 
-            // Convert format arguments to HashMap with string keys
-            format_kwargs: val
-                .format_kwargs
-                .iter()
-                .map(|pair| {
-                    (
-                        // Extract key as identifier or stringified path
-                        pair.path
-                            .get_ident()
-                            .map(|i| i.to_string())
-                            .unwrap_or_else(|| pair.path.to_token_stream().to_string()),
-                        // Store value as token stream
-                        pair.value.to_token_stream(),
-                    )
-                })
-                .collect(),
-        }
+            format_kwargs,
+
+            // Runtime collections are preserved as token streams
+            format_maps,
+
+            fallback,
+
+            folded,
+
+            argument_markers: TokenStream::new(),
+
+            language_span,
+
+            path_span,
+        };
+
+        // Values are checked before being escaped, so the checks are spanned
+        // to the values as written
+        let path = match &args.path {
+            PathType::CompileTimePath(path) => path.as_str(),
+            _ => "",
+        };
+        let bindings = bind_arguments(&mut args.format_kwargs);
+        args.argument_markers = check_arguments(&mut args.format_kwargs, path);
+        escape_arguments(&mut args.format_kwargs, &mut args.format_maps);
+        args.argument_markers.extend(bindings);
+
+        args
+    }
+}
+
+impl TranslationArgs {
+    /// Whether any of the arguments is resolved at runtime, meaning the
+    /// generated code evaluates to a `Result`
+    ///
+    /// Folded arguments count as runtime ones, so folding never changes the
+    /// call site type.
+    fn is_dynamic(&self) -> bool {
+        self.folded || !self.is_static()
+    }
+
+    /// Whether all the arguments are resolved at compile time
+    fn is_static(&self) -> bool {
+        matches!(self.language, LanguageType::CompileTimeLiteral(_))
+            && matches!(self.path, PathType::CompileTimePath(_))
     }
 }
 
@@ -196,9 +850,25 @@ impl From<RawMacroArgs> for TranslationArgs {
 /// TokenStream with either:
 /// - Compiled translation string
 /// - Runtime translation resolution logic
+/// - Runtime translation resolution logic falling back to `default`
 /// - Compile errors for invalid inputs
 pub fn translation_macro(args: TranslationArgs) -> TokenStream {
-    let TranslationArgs { language, path, format_kwargs } = args;
+    // Only runtime resolutions return a `Result` to fall back from
+    let is_dynamic = args.is_dynamic();
+    // Fully folded resolutions are wrapped in `Ok` to keep the runtime type
+    let is_folded = args.folded && args.is_static();
+
+    let TranslationArgs {
+        language,
+        path,
+        format_kwargs,
+        format_maps,
+        fallback,
+        argument_markers,
+        language_span,
+        path_span,
+        ..
+    } = args;
 
     // Process language specification
     let (lang_expr, static_lang) = match language {
@@ -206,30 +876,464 @@ pub fn translation_macro(args: TranslationArgs) -> TokenStream {
             None,
             match load_lang_static(&lang) {
                 Ok(lang) => Some(lang),
-                Err(e) => return error_token(&e),
+                Err(e) => return error_token(&e, language_span),
             },
         ),
         LanguageType::OnScopeExpression(lang) => {
-            (Some(load_lang_dynamic(lang).map_err(|e| error_token(&e))), None)
+            (Some(load_lang_dynamic(lang).map_err(|e| error_token(&e, language_span))), None)
         },
     };
 
     // Process translation path
     let translation_expr = match path {
-        PathType::CompileTimePath(p) => load_translation_static(static_lang, p, format_kwargs),
-        PathType::OnScopeExpression(p) => load_translation_dynamic(static_lang, p, format_kwargs),
+        PathType::CompileTimePath(p) => {
+            load_translation_static(static_lang, p, format_kwargs, format_maps)
+        },
+        PathType::OnScopeExpression(p) => {
+            load_translation_dynamic(static_lang, p, format_kwargs, format_maps)
+        },
+        PathType::Interpolated(prefix, segment, suffix) => load_translation_interpolated(
+            static_lang,
+            prefix,
+            segment,
+            suffix,
+            format_kwargs,
+            format_maps,
+        ),
     };
 
-    match (lang_expr, translation_expr) {
+    let translation = match (lang_expr, translation_expr) {
         (Some(Ok(lang)), Ok(trans)) => quote! {{ #lang #trans }},
-        (Some(Err(e)), _) => e,
+        (Some(Err(e)), _) => return e,
+        (None, Ok(trans)) if is_folded => quote! {
+            Ok::<std::borrow::Cow<'static, str>, translatable::Error>(std::borrow::Cow::from(#trans))
+        },
         (None, Ok(trans)) => trans,
-        (_, Err(e)) => error_token(&e),
+        (_, Err(e)) => return error_token(&e, error_span(&e, language_span, path_span)),
+    };
+
+    // Runtime failures are reported to the missing translation hook
+    let translation = if is_dynamic {
+        quote! { #translation.map_err(translatable::internal::missing_translation) }
+    } else {
+        translation
+    };
+
+    let translation = match fallback {
+        Some(fallback) if is_dynamic => quote! {
+            #translation.unwrap_or_else(|error| {
+                translatable::internal::trace_fallback(&error);
+                (#fallback).into()
+            })
+        },
+        _ => translation,
+    };
+
+    with_argument_markers(argument_markers, translation)
+}
+
+/// Declares the marker types of the format argument checks and binds the
+/// values in a block evaluating to the generated code
+fn with_argument_markers(argument_markers: TokenStream, translation: TokenStream) -> TokenStream {
+    if argument_markers.is_empty() {
+        translation
+    } else {
+        quote! {{
+            #argument_markers
+
+            #translation
+        }}
+    }
+}
+
+/// Generates translation code for translations authored in Markdown
+///
+/// The translations are rendered to sanitized HTML at compile time, both the
+/// static ones and the ones embedded for runtime resolution, and their
+/// templates are replaced afterwards just like with [`translation_macro`].
+#[cfg(feature = "markdown")]
+pub fn translation_markdown_macro(args: TranslationArgs) -> TokenStream {
+    with_markdown(|| translation_macro(args))
+}
+
+/// Generates translation code that always evaluates to a `Result`
+///
+/// Fully static resolutions are wrapped in `Ok`, so the call site type is
+/// `Result<Cow<'static, str>, translatable::Error>` regardless of the
+/// arguments.
+pub fn try_translation_macro(args: TranslationArgs) -> TokenStream {
+    if let Some(fallback) = &args.fallback {
+        return error_token(
+            &"The 'default' argument can't be used with try_translation!.",
+            fallback.span(),
+        );
+    }
+
+    let is_dynamic = args.is_dynamic();
+    let translation = translation_macro(args);
+
+    if is_dynamic {
+        translation
+    } else {
+        quote! {
+            Ok::<std::borrow::Cow<'static, str>, translatable::Error>(std::borrow::Cow::from(#translation))
+        }
+    }
+}
+
+/// Generates translation code that falls back to the translation path
+///
+/// Runtime resolution failures evaluate to the path itself, so the call site
+/// type is always `Cow<'static, str>`.
+pub fn translation_or_key_macro(mut args: TranslationArgs) -> TokenStream {
+    if let Some(fallback) = &args.fallback {
+        return error_token(
+            &"The 'default' argument can't be used with translation_or_key!.",
+            fallback.span(),
+        );
+    }
+
+    match args.path {
+        PathType::CompileTimePath(ref path) => {
+            args.fallback = Some(quote!(#path));
+
+            if args.is_dynamic() {
+                translation_macro(args)
+            } else {
+                let translation = translation_macro(args);
+                quote! { std::borrow::Cow::<'static, str>::from(#translation) }
+            }
+        },
+
+        // The runtime segment is evaluated once and reused in the fallback
+        PathType::Interpolated(ref prefix, ref segment, ref suffix) => {
+            let segment = segment.clone();
+            let fallback_suffix =
+                if suffix.is_empty() { String::new() } else { format!(".{suffix}") };
+
+            args.fallback =
+                Some(quote!(format!("{}.{}{}", #prefix, translation_segment, #fallback_suffix)));
+            args.path =
+                PathType::Interpolated(prefix.clone(), quote!(translation_segment), suffix.clone());
+            let translation = translation_macro(args);
+
+            quote! {{
+                #[doc(hidden)]
+                let translation_segment = (#segment).to_string();
+
+                #translation
+            }}
+        },
+
+        // The path expression is evaluated once and reused as the fallback
+        PathType::OnScopeExpression(path) => {
+            args.path = PathType::OnScopeExpression(quote!(translation_key.clone()));
+            args.fallback = Some(quote!(translation_key));
+            let translation = translation_macro(args);
+
+            quote! {{
+                #[doc(hidden)]
+                let translation_key: String = (#path).into();
+
+                #translation
+            }}
+        },
+    }
+}
+
+/// Generates translation code writing into a `fmt::Write` or `io::Write`
+/// target
+///
+/// The raw translation is resolved just like with [`translation_macro`], and
+/// the format arguments are replaced while writing it through
+/// `translatable::Formatted`, so no intermediate `String` is allocated. The
+/// call site type is the writer result, wrapped in a `Result` if any of the
+/// arguments is dynamic.
+pub fn translation_write_macro(writer: Expr, mut args: TranslationArgs) -> TokenStream {
+    if let Some(fallback) = &args.fallback {
+        return error_token(
+            &"The 'default' argument can't be used with translation_write!.",
+            fallback.span(),
+        );
+    }
+
+    if let Some(format_map) = args.format_maps.first() {
+        return error_token(
+            &"Runtime format argument collections can't be used with translation_write!.",
+            format_map.span(),
+        );
+    }
+
+    let is_dynamic = args.is_dynamic();
+    // The arguments are written outside of the translation code
+    let argument_markers = std::mem::take(&mut args.argument_markers);
+    let formatted = match load_formatted_arguments() {
+        Ok(formatted) => formatted,
+        Err(e) => return error_token(&e, Span::call_site()),
+    };
+
+    // Arguments are left without a value, so they are checked but not replaced,
+    // unless they are used in plural or select blocks or with filter pipes
+    let arguments = args
+        .format_kwargs
+        .iter_mut()
+        .filter(|(key, _)| !formatted.contains(*key))
+        .map(|(key, value)| {
+            let value = std::mem::take(value);
+            quote! { (#key, &(#value) as &dyn std::fmt::Display) }
+        })
+        .collect::<Vec<_>>();
+
+    let translation = translation_macro(args);
+    let write = quote! {
+        (#writer).write_fmt(format_args!(
+            "{}",
+            translatable::Formatted::new(&translation, &[#(#arguments),*])
+        ))
+    };
+
+    let write = if is_dynamic {
+        quote! {
+            #translation.map(|translation| #write)
+        }
+    } else {
+        quote! {{
+            #[doc(hidden)]
+            let translation = #translation;
+
+            #write
+        }}
+    };
+
+    with_argument_markers(argument_markers, write)
+}
+
+/// Generates a batch of static translations sharing a single language
+///
+/// The language is validated once for the whole batch, evaluating to a tuple
+/// of translations in the same order as the paths, wrapped in a `Result` if
+/// the language is resolved at runtime.
+pub fn translations_macro(args: RawBatchArgs) -> TokenStream {
+    let paths = args.paths.iter().map(static_path_string).collect();
+    let language_span = args.language.span();
+
+    let result = match language_type(args.language) {
+        LanguageType::CompileTimeLiteral(lang) => {
+            load_lang_static(&lang).and_then(|lang| load_translations_batch(Some(lang), paths))
+        },
+        LanguageType::OnScopeExpression(lang) => load_lang_dynamic(lang).and_then(|lang| {
+            load_translations_batch(None, paths).map(|trans| {
+                quote! {
+                    { #lang #trans }.map_err(translatable::internal::missing_translation)
+                }
+            })
+        }),
+    };
+
+    result.unwrap_or_else(|e| {
+        // Path errors are reported at the path they are about
+        let span = match &e {
+            TranslationError::PathNotFound(path, _) => args
+                .paths
+                .iter()
+                .find(|candidate| &static_path_string(candidate) == path)
+                .map_or(language_span, Spanned::span),
+            _ => language_span,
+        };
+
+        error_token(&e, span)
+    })
+}
+
+/// Generates translation code for a translation object declared inline
+///
+/// The translation object is validated like a translation file entry, the
+/// generated code follows the same rules as a `static` path.
+pub fn translation_inline_macro(args: RawInlineArgs) -> TokenStream {
+    // Build the object as a TOML table, reusing the translation file validation
+    let mut table = Table::new();
+
+    for pair in &args.translations {
+        let language = pair.path.to_token_stream().to_string();
+
+        match &pair.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(translation), .. }) => {
+                table.insert(language, Value::String(translation.value()));
+            },
+            other => {
+                return SynError::new_spanned(
+                    other,
+                    "Inline translations must be string literals.",
+                )
+                .to_compile_error();
+            },
+        }
+    }
+
+    let translation_object = match TranslationNode::try_from(table) {
+        Ok(TranslationNode::Translation(translation_object)) => translation_object,
+        Ok(TranslationNode::Object(_)) => unreachable!("only strings are inserted"),
+        Err(e) => {
+            return error_token(
+                &TranslationError::InvalidInlineTranslation(e),
+                args.translations.span(),
+            );
+        },
+    };
+
+    let mut format_kwargs = format_kwargs_map(&args.format_kwargs, &args.format_args);
+    let mut format_maps =
+        args.format_maps.iter().map(|map| map.to_token_stream()).collect::<Vec<_>>();
+    let fallback = format_kwargs.remove("default");
+    let path = String::from("inline");
+    let bindings = bind_arguments(&mut format_kwargs);
+    let mut argument_markers = check_arguments(&mut format_kwargs, &path);
+    escape_arguments(&mut format_kwargs, &mut format_maps);
+    argument_markers.extend(bindings);
+    let language_span = args.language.span();
+
+    let translation = match language_type(args.language) {
+        LanguageType::CompileTimeLiteral(lang) => load_lang_static(&lang).and_then(|lang| {
+            load_translation_object(
+                Some(lang),
+                &translation_object,
+                Vec::new(),
+                path,
+                format_kwargs,
+                format_maps,
+            )
+        }),
+        LanguageType::OnScopeExpression(lang) => load_lang_dynamic(lang).and_then(|lang| {
+            load_translation_object(
+                None,
+                &translation_object,
+                Vec::new(),
+                path,
+                format_kwargs,
+                format_maps,
+            )
+            .map(|trans| {
+                let trans = quote! {
+                    { #lang #trans }.map_err(translatable::internal::missing_translation)
+                };
+
+                match fallback {
+                    Some(fallback) => quote! {
+                        #trans.unwrap_or_else(|error| {
+                            translatable::internal::trace_fallback(&error);
+                            (#fallback).into()
+                        })
+                    },
+                    None => trans,
+                }
+            })
+        }),
+    };
+
+    let translations_span = args.translations.span();
+    translation.map_or_else(
+        |e| error_token(&e, error_span(&e, language_span, translations_span)),
+        |translation| with_argument_markers(argument_markers, translation),
+    )
+}
+
+/// Generates the embedded collection of all the loaded translations
+pub fn embed_translations_macro() -> TokenStream {
+    match load_translations() {
+        Ok(translations) => translations.to_token_stream(),
+        Err(e) => error_token(&e, Span::call_site()),
+    }
+}
+
+/// Generates the embedded collection of a single translation file
+///
+/// The path is relative to the manifest directory of the crate invoking the
+/// macro, so libraries can embed their own translation files regardless of
+/// the application configuration.
+pub fn include_translation_file_macro(path: LitStr) -> TokenStream {
+    let full_path = PathBuf::from(var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".into()))
+        .join(path.value())
+        .to_string_lossy()
+        .to_string();
+
+    match load_translation_file(&full_path) {
+        Ok(translation) => {
+            // The embedded path is kept relative to the manifest directory
+            let translation =
+                AssociatedTranslation::new(path.value(), translation.translation_table().clone())
+                    .with_priority(translation.priority())
+                    .with_version(translation.version())
+                    .with_deprecations(translation.deprecations().clone())
+                    .with_locations(translation.locations().clone());
+            let translations = TranslationNodeCollection::new(vec![translation]);
+
+            // `include_str!` makes cargo rebuild when the file changes
+            quote! {{
+                const _: &str = include_str!(#full_path);
+                #translations
+            }}
+        },
+        Err(e) => error_token(&e, path.span()),
+    }
+}
+
+/// Generates typed accessor functions for every translation path
+pub fn translation_accessors_macro() -> TokenStream {
+    load_translation_accessors().unwrap_or_else(|e| item_error(&format!("{e:#}")))
+}
+
+/// Generates the coverage statistics of the loaded translations
+pub fn translation_stats_macro() -> TokenStream {
+    load_translation_stats().unwrap_or_else(|e| error_token(&e, Span::call_site()))
+}
+
+/// Generates the localized slugs of the routes nested under a prefix
+pub fn translation_slugs_macro(prefix: LitStr) -> TokenStream {
+    load_translation_slugs(prefix.value()).unwrap_or_else(|e| error_token(&e, prefix.span()))
+}
+
+/// Generates a completeness assertion over the translation files
+///
+/// The pairs are checked at compile time, the generated code panics with
+/// the missing pairs if there are any, meant to be used inside tests.
+pub fn assert_translations_complete_macro(args: RawCompletenessArgs) -> TokenStream {
+    let languages = args.languages.map(|languages| {
+        languages
+            .iter()
+            .map(|language| {
+                load_lang_static(&language.value()).map_err(|e| error_token(&e, language.span()))
+            })
+            .collect()
+    });
+
+    let prefix_span = args.prefix.as_ref().map_or_else(Span::call_site, LitStr::span);
+    let result = languages.transpose().and_then(|languages| {
+        load_translations_complete(args.prefix.map(|p| p.value()), languages)
+            .map_err(|e| error_token(&e, prefix_span))
+    });
+
+    result.unwrap_or_else(|e| e)
+}
+
+/// Gets the span of the argument an error is about
+///
+/// Errors loading the translation files aren't about any argument, so they
+/// are reported at the macro call site.
+fn error_span(error: &TranslationError, language_span: Span, path_span: Span) -> Span {
+    match error {
+        TranslationError::InvalidLanguage(_)
+        | TranslationError::LanguageNotConfigured(..)
+        | TranslationError::LanguageNotAvailable(..) => language_span,
+        TranslationError::PathNotFound(..)
+        | TranslationError::PositionalArity(..)
+        | TranslationError::MigratedPath(..)
+        | TranslationError::DeprecatedPath(..) => path_span,
+        _ => Span::call_site(),
     }
 }
 
-/// Helper function to create compile error tokens
-fn error_token(e: &impl Display) -> TokenStream {
-    let msg = format!("{e:#}");
-    quote! { compile_error!(#msg) }
+/// Helper function to create compile error tokens, reported at the span of
+/// the argument they are about
+fn error_token(e: &impl Display, span: Span) -> TokenStream {
+    error(&format!("{e:#}"), span)
 }
@@ -0,0 +1,258 @@
+//! Path prefixing for translation macros inside an item
+//!
+//! This module implements the `translation_prefix` attribute, which rewrites
+//! the translation macro invocations inside the annotated item so their paths
+//! resolve relative to a prefix.
+
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+use syn::parse::{ParseStream, Parser};
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Attribute, Error as SynError, Expr, ImplItemFn, Item, ItemFn, ItemImpl, ItemMod, LitStr, Macro,
+    Token,
+};
+
+use crate::macros::constant_string;
+
+/// Translation macros taking a single path
+const PATH_MACROS: [&str; 3] = ["translation", "try_translation", "translation_or_key"];
+
+/// Translation macros taking a list of static paths
+const BATCH_MACROS: [&str; 1] = ["translations"];
+
+/// Translation macros taking a writer before a single path
+const WRITE_MACROS: [&str; 1] = ["translation_write"];
+
+/// Attribute name, consumed on nested items to compose the prefixes
+const ATTRIBUTE_NAME: &str = "translation_prefix";
+
+/// Visitor prefixing the translation macro invocations
+///
+/// Keeps a stack of prefixes, so nested `translation_prefix` attributes are
+/// resolved relative to their parent instead of being expanded on their own.
+struct PrefixVisitor {
+    /// Dot-separated prefixes from the outermost item
+    prefixes: Vec<String>,
+    /// First invalid nested attribute found
+    error: Option<SynError>,
+}
+
+impl PrefixVisitor {
+    /// Full dot-separated prefix for the current item
+    fn prefix(&self) -> String {
+        self.prefixes.join(".")
+    }
+
+    /// Visits an item, consuming its own `translation_prefix` attribute
+    fn with_attributes(&mut self, attrs: &mut Vec<Attribute>, visit: impl FnOnce(&mut Self)) {
+        let mut nested = None;
+
+        attrs.retain(|attr| {
+            let is_prefix = attr.path().segments.last().is_some_and(|s| s.ident == ATTRIBUTE_NAME);
+
+            if is_prefix {
+                match attr.parse_args::<LitStr>().and_then(|prefix| validate_prefix(&prefix)) {
+                    Ok(prefix) => nested = Some(prefix),
+                    Err(e) => {
+                        self.error.get_or_insert(e);
+                    },
+                }
+            }
+
+            !is_prefix
+        });
+
+        let pushed = nested.is_some();
+
+        if let Some(prefix) = nested {
+            self.prefixes.push(prefix);
+        }
+
+        visit(self);
+
+        if pushed {
+            self.prefixes.pop();
+        }
+    }
+}
+
+impl VisitMut for PrefixVisitor {
+    fn visit_item_fn_mut(&mut self, node: &mut ItemFn) {
+        let mut attrs = std::mem::take(&mut node.attrs);
+        self.with_attributes(&mut attrs, |v| visit_mut::visit_item_fn_mut(v, node));
+        node.attrs = attrs;
+    }
+
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        let mut attrs = std::mem::take(&mut node.attrs);
+        self.with_attributes(&mut attrs, |v| visit_mut::visit_item_mod_mut(v, node));
+        node.attrs = attrs;
+    }
+
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        let mut attrs = std::mem::take(&mut node.attrs);
+        self.with_attributes(&mut attrs, |v| visit_mut::visit_item_impl_mut(v, node));
+        node.attrs = attrs;
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, node: &mut ImplItemFn) {
+        let mut attrs = std::mem::take(&mut node.attrs);
+        self.with_attributes(&mut attrs, |v| visit_mut::visit_impl_item_fn_mut(v, node));
+        node.attrs = attrs;
+    }
+
+    fn visit_macro_mut(&mut self, node: &mut Macro) {
+        let name = node.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+        let prefix = self.prefix();
+
+        node.tokens = macro_tokens(&name, node.tokens.clone(), &prefix);
+    }
+}
+
+/// Validates that a prefix is a dot-separated list of identifiers
+fn validate_prefix(prefix: &LitStr) -> Result<String, SynError> {
+    let value = prefix.value();
+
+    if value.split('.').all(|segment| syn::parse_str::<syn::Ident>(segment).is_ok()) {
+        Ok(value)
+    } else {
+        Err(SynError::new(prefix.span(), "The prefix must be a dot-separated path of identifiers."))
+    }
+}
+
+/// Rewrites the tokens of a macro invocation
+///
+/// Translation macros get their arguments prefixed, any other macro is
+/// scanned for nested translation macro invocations, such as the ones
+/// inside `assert!` or `println!`.
+fn macro_tokens(name: &str, tokens: TokenStream, prefix: &str) -> TokenStream {
+    if PATH_MACROS.contains(&name) || BATCH_MACROS.contains(&name) {
+        prefix_arguments(tokens.clone(), prefix, BATCH_MACROS.contains(&name)).unwrap_or(tokens)
+    } else if WRITE_MACROS.contains(&name) {
+        prefix_write_arguments(tokens.clone(), prefix).unwrap_or(tokens)
+    } else {
+        nested_tokens(tokens, prefix)
+    }
+}
+
+/// Scans a token stream for `name!(...)` translation macro invocations
+fn nested_tokens(tokens: TokenStream, prefix: &str) -> TokenStream {
+    let mut result = Vec::<TokenTree>::new();
+
+    for token in tokens {
+        let token = match token {
+            TokenTree::Group(group) => {
+                // A preceding `name!` makes this group a macro invocation
+                let name = match result.as_slice() {
+                    [.., TokenTree::Ident(name), TokenTree::Punct(bang)]
+                        if bang.as_char() == '!' =>
+                    {
+                        name.to_string()
+                    },
+                    _ => String::new(),
+                };
+
+                let mut rewritten =
+                    Group::new(group.delimiter(), macro_tokens(&name, group.stream(), prefix));
+                rewritten.set_span(group.span());
+                TokenTree::Group(rewritten)
+            },
+            other => other,
+        };
+
+        result.push(token);
+    }
+
+    result.into_iter().collect()
+}
+
+/// Prefixes the paths of a translation macro invocation
+///
+/// Every `static` path gets the prefix segments prepended. For single path
+/// macros, dynamic paths are joined with the prefix at runtime, or folded if
+/// constant.
+///
+/// # Returns
+/// - `Ok(TokenStream)` with the rewritten arguments
+/// - `Err(SynError)` if the arguments are malformed, in which case they are
+///   left for the macro itself to report
+fn prefix_arguments(
+    tokens: TokenStream,
+    prefix: &str,
+    batch: bool,
+) -> Result<TokenStream, SynError> {
+    let segments = prefix.split('.').map(|segment| syn::Ident::new(segment, Span::call_site()));
+    let static_prefix = quote! { #(#segments ::)* };
+
+    let parser = |input: ParseStream| {
+        let language: Expr = input.parse()?;
+        let comma: Token![,] = input.parse()?;
+
+        // Dynamic paths are prefixed as an expression
+        let path = if !batch && !input.peek(Token![static]) {
+            let path: Expr = input.parse()?;
+
+            Some(match constant_string(&path) {
+                Some(path) => {
+                    LitStr::new(&format!("{prefix}.{path}"), Span::call_site()).into_token_stream()
+                },
+                None => quote! { format!("{}.{}", #prefix, #path) },
+            })
+        } else {
+            None
+        };
+
+        let mut rest = TokenStream::new();
+
+        // Static markers are only found at the top level of the arguments
+        for token in input.parse::<TokenStream>()? {
+            let is_static = matches!(&token, TokenTree::Ident(ident) if ident == "static");
+            rest.extend([token]);
+
+            if is_static {
+                rest.extend(static_prefix.clone());
+            }
+        }
+
+        Ok(quote! { #language #comma #path #rest })
+    };
+
+    parser.parse2(tokens)
+}
+
+/// Prefixes the path of a translation macro invocation taking a writer
+///
+/// The writer is kept as is, the rest of arguments are prefixed like
+/// [`prefix_arguments`].
+fn prefix_write_arguments(tokens: TokenStream, prefix: &str) -> Result<TokenStream, SynError> {
+    let parser = |input: ParseStream| {
+        let writer: Expr = input.parse()?;
+        let comma: Token![,] = input.parse()?;
+        let rest = prefix_arguments(input.parse()?, prefix, false)?;
+
+        Ok(quote! { #writer #comma #rest })
+    };
+
+    parser.parse2(tokens)
+}
+
+/// Rewrites the translation macro invocations inside an item
+///
+/// # Arguments
+/// * `prefix` - Dot-separated prefix for the paths
+/// * `item` - Item the attribute is applied to
+pub fn translation_prefix_macro(prefix: LitStr, mut item: Item) -> TokenStream {
+    let prefix = match validate_prefix(&prefix) {
+        Ok(prefix) => prefix,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let mut visitor = PrefixVisitor { prefixes: vec![prefix], error: None };
+    visitor.visit_item_mut(&mut item);
+
+    match visitor.error {
+        Some(e) => e.to_compile_error(),
+        None => item.into_token_stream(),
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use translatable_shared::{Language, TranslationNode, VARIANT_SEPARATOR, templates};
+
+use super::errors::TranslationError;
+use super::generation::{kwarg_formatted, kwarg_formatters, kwarg_static_replaces, random_entry};
+use crate::data::translations::load_translations;
+
+/// Translation hierarchy merged across all the translation files
+///
+/// Sorted maps keep the generated items in a stable order.
+enum MergedNode<'a> {
+    /// Nested namespace, generated as a module
+    Object(BTreeMap<&'a str, MergedNode<'a>>),
+    /// Translation object, generated as a function
+    Translation(&'a HashMap<Language, String>),
+}
+
+impl<'a> MergedNode<'a> {
+    /// Merges a translation file into the hierarchy
+    ///
+    /// Files must be merged in lookup priority order, existing translation
+    /// objects are never replaced, just like the path lookup.
+    fn merge(&mut self, node: &'a TranslationNode) {
+        let (Self::Object(merged), TranslationNode::Object(nested)) = (self, node) else {
+            return;
+        };
+
+        // Named variants are selected with the `variant` argument instead
+        for (key, value) in nested.iter().filter(|(key, _)| !key.contains(VARIANT_SEPARATOR)) {
+            let entry = merged.entry(key).or_insert_with(|| match value {
+                TranslationNode::Object(_) => Self::Object(BTreeMap::new()),
+                TranslationNode::Translation(translation) => Self::Translation(translation),
+            });
+
+            entry.merge(value);
+        }
+    }
+}
+
+/// Converts a translation key or template name to a valid Rust identifier
+///
+/// Invalid characters are replaced with `_` and keywords are escaped as raw
+/// identifiers.
+fn accessor_ident(name: &str) -> Ident {
+    let mut sanitized = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+        .collect::<String>();
+
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_numeric()) {
+        sanitized.insert(0, '_');
+    }
+
+    syn::parse_str::<Ident>(&sanitized).unwrap_or_else(|_| {
+        match sanitized.as_str() {
+            // these keywords can't be raw identifiers
+            "self" | "Self" | "super" | "crate" | "_" => {
+                Ident::new(&format!("{sanitized}_"), Span::call_site())
+            },
+            _ => Ident::new_raw(&sanitized, Span::call_site()),
+        }
+    })
+}
+
+/// Generates the accessor function for a translation object
+///
+/// # Arguments
+/// * `key` - Last segment of the translation path, used as function name
+/// * `path` - Full dot-separated translation path
+/// * `translation` - Translations per language
+fn accessor_function(
+    key: &str,
+    path: &str,
+    translation: &HashMap<Language, String>,
+) -> TokenStream {
+    let name = accessor_ident(key);
+
+    // Templates from every language are required, sorted by name
+    let template_names =
+        translation.values().flat_map(|value| templates(value)).collect::<BTreeSet<_>>();
+
+    let parameters = template_names.iter().map(|template| {
+        let parameter = accessor_ident(template);
+        quote! { #parameter: impl std::fmt::Display }
+    });
+
+    let replaces = template_names.iter().map(|template| {
+        let parameter = accessor_ident(template);
+        kwarg_static_replaces(template, &quote!(#parameter))
+    });
+
+    let arguments = template_names
+        .iter()
+        .map(|template| {
+            let parameter = accessor_ident(template);
+            (template.clone(), quote!(#parameter))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    // Blocks and filters are formatted before the replacements
+    let formatters = kwarg_formatters(translation.values(), &arguments);
+    let formatted = (!formatters.is_empty()).then(|| {
+        let formatted = kwarg_formatted(
+            &quote!(&language),
+            quote!(std::borrow::Cow::Borrowed(translation)),
+            &formatters,
+        );
+
+        quote! {
+            #[doc(hidden)]
+            let translation = #formatted;
+        }
+    });
+
+    let mut languages = translation.iter().collect::<Vec<_>>();
+    languages.sort_by_key(|(language, _)| language.code());
+
+    let arms = languages.iter().map(|(language, value)| {
+        let value = random_entry(value);
+        quote! { #language => #value, }
+    });
+    let available =
+        languages.iter().map(|(language, _)| language.code()).collect::<Vec<_>>().join(", ");
+    let available_languages = languages.iter().map(|(language, _)| language);
+    let doc = format!("Translation for `{path}`, available in: {available}.");
+
+    quote! {
+        #[doc = #doc]
+        #[allow(unreachable_patterns)]
+        pub fn #name(
+            language: translatable::Language,
+            #(#parameters),*
+        ) -> Result<std::borrow::Cow<'static, str>, translatable::Error> {
+            translatable::internal::trace_lookup(language.code(), #path);
+
+            #[doc(hidden)]
+            let translation = match language {
+                #(#arms)*
+                _ => return Err(translatable::internal::missing_translation(
+                    translatable::Error::LanguageNotAvailable {
+                        language,
+                        path: #path.to_string(),
+                        available: vec![#(#available_languages),*],
+                    }
+                )),
+            };
+
+            #formatted
+
+            Ok(std::borrow::Cow::from(translation #(#replaces)*))
+        }
+    }
+}
+
+/// Generates a module or function for every entry of a merged namespace
+fn accessor_items(nested: &BTreeMap<&str, MergedNode>, prefix: &str) -> Vec<TokenStream> {
+    nested
+        .iter()
+        .map(|(key, value)| {
+            let path = if prefix.is_empty() { key.to_string() } else { format!("{prefix}.{key}") };
+
+            match value {
+                MergedNode::Object(nested) => {
+                    let name = accessor_ident(key);
+                    let items = accessor_items(nested, &path);
+
+                    quote! {
+                        #[allow(non_snake_case)]
+                        pub mod #name {
+                            #(#items)*
+                        }
+                    }
+                },
+
+                MergedNode::Translation(translation) => accessor_function(key, &path, translation),
+            }
+        })
+        .collect()
+}
+
+/// Generates typed accessor functions for every translation path
+///
+/// The translation hierarchy is generated as nested modules, where every
+/// translation object is a function taking the language and one
+/// `impl Display` parameter per template, so missing or misnamed arguments
+/// are caught by regular type checking.
+///
+/// # Returns
+/// TokenStream with the module items, meant to be expanded inside a module
+pub fn load_translation_accessors() -> Result<TokenStream, TranslationError> {
+    let mut merged = MergedNode::Object(BTreeMap::new());
+
+    for association in load_translations()?.iter() {
+        merged.merge(association.translation_table());
+    }
+
+    let MergedNode::Object(nested) = merged else { unreachable!("the root is always an object") };
+    let items = accessor_items(&nested, "");
+
+    Ok(quote! { #(#items)* })
+}
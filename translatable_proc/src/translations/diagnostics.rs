@@ -0,0 +1,147 @@
+//! Compile errors and warnings of the macros
+//!
+//! This module turns the errors into compile errors spanned to the macro
+//! argument they are about, and the findings configured as `"warn"` into
+//! compile warnings. On nightly with the `nightly` feature they are emitted
+//! through `proc_macro::Diagnostic`, with their `note:` and `help:` lines as
+//! children, otherwise through `compile_error!` and a deprecated item the
+//! generated code uses, which is the only warning available on stable.
+
+use std::sync::Mutex;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use translatable_shared::Severity;
+
+use super::errors::TranslationError;
+
+/// Warnings found while loading the translation files, emitted by the next
+/// macro expanded
+static PENDING_WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Generates a compile warning at the macro call site
+///
+/// The tokens are valid both as items and as statements, so they can be
+/// prepended to the generated code of any macro.
+pub fn warning(message: &str) -> TokenStream {
+    #[cfg(feature = "nightly")]
+    {
+        proc_macro::Span::call_site().warning(message).emit();
+        TokenStream::new()
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            struct TranslatableWarning;
+
+            let _ = TranslatableWarning;
+        };
+    }
+}
+
+/// Generates a compile error at a span of the macro arguments
+///
+/// The lines of the message starting with `note: ` or `help: ` become the
+/// children of the error on nightly, they are part of the message otherwise.
+/// The tokens are valid as expressions, use [`item_error`] for items.
+pub fn error(message: &str, span: Span) -> TokenStream {
+    #[cfg(feature = "nightly")]
+    {
+        emit_error(message, span);
+        // The type of the expression is inferred from its surroundings, and
+        // constant contexts evaluating it report the same message
+        let message = message.lines().next().unwrap_or_default();
+        quote_spanned! { span => ::core::panic!(#message) }
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    quote_spanned! { span => compile_error!(#message) }
+}
+
+/// Generates a compile error at the macro call site, valid as items
+pub fn item_error(message: &str) -> TokenStream {
+    #[cfg(feature = "nightly")]
+    {
+        emit_error(message, Span::call_site());
+        TokenStream::new()
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    quote! { compile_error!(#message); }
+}
+
+/// Emits a compile error through `proc_macro::Diagnostic`, splitting its
+/// `note:` and `help:` lines into children
+#[cfg(feature = "nightly")]
+fn emit_error(message: &str, span: Span) {
+    use proc_macro::{Diagnostic, Level};
+
+    let mut primary = Vec::new();
+    let mut children = Vec::new();
+
+    for line in message.lines() {
+        match (line.strip_prefix("note: "), line.strip_prefix("help: ")) {
+            (Some(note), _) => children.push((Level::Note, note)),
+            (_, Some(help)) => children.push((Level::Help, help)),
+            _ => primary.push(line),
+        }
+    }
+
+    let diagnostic = Diagnostic::spanned(span.unwrap(), Level::Error, primary.join("\n"));
+
+    children
+        .into_iter()
+        .fold(diagnostic, |diagnostic, (level, child)| match level {
+            Level::Note => diagnostic.note(child),
+            _ => diagnostic.help(child),
+        })
+        .emit();
+}
+
+/// Applies a severity to a finding
+///
+/// # Returns
+/// - `Ok(warning)` with the warning tokens, empty if the finding is allowed
+/// - `Err(error)` if the finding is denied
+pub fn check(severity: Severity, error: TranslationError) -> Result<TokenStream, TranslationError> {
+    match severity {
+        Severity::Deny => Err(error),
+        Severity::Warn => Ok(warning(&format!("{error:#}"))),
+        Severity::Allow => Ok(TokenStream::new()),
+    }
+}
+
+/// Records a warning found outside of a macro expansion
+pub fn defer_warning(error: TranslationError) {
+    PENDING_WARNINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(format!("{error:#}"));
+}
+
+/// Generates the recorded warnings, which are only emitted once
+///
+/// The warnings are valid as items, prepend them to generated items.
+pub fn pending_warnings() -> TokenStream {
+    let warnings = std::mem::take(
+        &mut *PENDING_WARNINGS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+
+    warnings.iter().map(|message| warning(message)).collect()
+}
+
+/// Prepends the recorded warnings to a generated expression
+pub fn with_pending_warnings(expression: TokenStream) -> TokenStream {
+    let warnings = pending_warnings();
+
+    if warnings.is_empty() {
+        expression
+    } else {
+        quote! {{
+            #warnings
+            #expression
+        }}
+    }
+}
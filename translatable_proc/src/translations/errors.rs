@@ -3,10 +3,9 @@ use std::io::Error as IoError;
 use syn::Error as SynError;
 use thiserror::Error;
 use toml::de::Error as TomlError;
+use translatable_shared::{Language, LintFinding, TransformError};
 
 use crate::data::config::ConfigError;
-use crate::data::translations::TransformError;
-use crate::languages::Iso639a;
 
 /// Errors that can occur during translation processing.
 #[derive(Error, Debug)]
@@ -35,39 +34,128 @@ pub enum TranslationError {
 
     /// Invalid language code error with suggestions
     #[error(
-        "'{0}' is not valid ISO 639-1. {similarities}",
-        similarities = {
-            let similarities = Iso639a::get_similarities(.0, 10);
-            let similarities_format = similarities
-                .similarities()
-                .join("\n");
-
-            if similarities_format.is_empty() {
-                "".into()
-            } else {
-                let including_format = format!("These are some valid languages including '{}':\n{similarities_format}", .0);
+        "'{0}' is not valid ISO 639-1.{help}",
+        help = {
+            let suggestions = Language::suggestions(.0, 10)
+                .iter()
+                .map(|language| format!("{language:#} ({language:?})"))
+                .collect::<Vec<_>>();
 
-                if similarities.overflow_by() > 0 {
-                    format!("{including_format}\n... and {} more.", similarities.overflow_by())
-                } else {
-                    including_format
-                }
+            if suggestions.is_empty() {
+                String::new()
+            } else {
+                format!("\nhelp: similar languages are {}", suggestions.join(", "))
             }
         }
     )]
     InvalidLanguage(String),
 
+    /// Valid language outside the configured `languages`
+    #[error(
+        "The language '{0:?}' ({0:#}) is not one of the configured languages.\nhelp: the \
+         configured languages are {configured}",
+        configured = .1
+            .iter()
+            .map(|language| format!("'{language:?}' ({language:#})"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )]
+    LanguageNotConfigured(Language, Vec<Language>),
+
+    /// Translation file declaring an older catalog version than the
+    /// configured `min_catalog_version`
+    #[error(
+        "The translation file '{0}' is version {1} of its catalog, but version {2} or newer is \
+         required.\nhelp: update the translations, or lower `min_catalog_version` in the \
+         configuration"
+    )]
+    OutdatedCatalog(String, u64, u64),
+
+    /// CSV parsing error
+    #[cfg(feature = "csv")]
+    #[error("CSV parse error in {1}: {0}")]
+    ParseCsv(String, String),
+
+    /// XLIFF parsing error
+    #[cfg(feature = "xliff")]
+    #[error("XLIFF parse error in {1}: {0}")]
+    ParseXliff(String, String),
+
     /// Invalid TOML structure in specific file
     #[error("Invalid TOML structure in file {1}: {0}")]
     InvalidTomlFormat(TransformError, String),
 
-    /// Path not found in any translation file
-    #[error("The path '{0}' is not found in any of the translation files as a translation object.")]
-    PathNotFound(String),
+    /// Invalid inline translation object
+    #[error("Invalid inline translation: {0}")]
+    InvalidInlineTranslation(TransformError),
+
+    /// Path not found in any translation file, with the closest existing one
+    #[error(
+        "The path '{0}' is not found in any of the translation files as a translation object.{help}",
+        help = match .1 {
+            Some(closest) => format!("\nhelp: a path with a similar name exists: '{closest}'"),
+            None => String::new(),
+        }
+    )]
+    PathNotFound(String, Option<String>),
+
+    /// Language not available for the specified path, with the languages it
+    /// is available in and the files defining it in lookup priority order
+    #[error(
+        "The language '{0:?}' ({0:#}) is not available for the '{1}' translation.{available}{note}",
+        available = {
+            let available = .2
+                .iter()
+                .map(|language| format!("'{language:?}' ({language:#})"))
+                .collect::<Vec<_>>();
+
+            if available.is_empty() {
+                String::new()
+            } else {
+                format!("\nhelp: the translation is available in {}", available.join(", "))
+            }
+        },
+        note = match .3.split_first() {
+            Some((source, [])) => format!("\nnote: defined in {source}"),
+            Some((source, overridden)) => format!(
+                "\nnote: defined in {source}, which takes precedence over {}",
+                overridden.join(", ")
+            ),
+            None => String::new(),
+        }
+    )]
+    LanguageNotAvailable(Language, String, Vec<Language>, Vec<String>),
+
+    /// Positional arguments not matching the `{0}`, `{1}`... templates of a
+    /// translation
+    #[error(
+        "The '{0}' translation takes {2} positional arguments in '{1:?}' ({1:#}), but {3} were \
+         given."
+    )]
+    PositionalArity(String, Language, usize, usize),
+
+    /// Static path migrated to another one by the configuration
+    #[error("The translation path '{0}' was migrated to '{1}', use it instead.")]
+    MigratedPath(String, String),
+
+    /// Static path deprecated by a `_deprecated` key, with its note
+    #[error("The translation path '{0}' is deprecated: {1}")]
+    DeprecatedPath(String, String),
+
+    /// Quality lint found in a translation
+    #[error("Translation lint {0}")]
+    Lint(LintFinding),
+
+    /// Slug that can't be used as a path segment
+    #[error(
+        "The slug '{0}' of the '{1}' route can't be used as a path segment, slugs can't be empty \
+         nor contain '/'."
+    )]
+    InvalidSlug(String, String),
 
-    /// Language not available for the specified path
-    #[error("The language '{0:?}' ({0:#}) is not available for the '{1}' translation.")]
-    LanguageNotAvailable(Iso639a, String),
+    /// Slug used by more than one route in the same language
+    #[error("The slug '{0}' is used in '{1:?}' ({1:#}) by both the '{2}' and '{3}' routes.")]
+    DuplicateSlug(String, Language, String, String),
 
     /// Error parsing macro.
     #[error("Error parsing macro.")]
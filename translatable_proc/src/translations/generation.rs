@@ -1,13 +1,19 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use phf_generator::generate_hash;
 use proc_macro2::TokenStream;
-use quote::quote;
-use strum::IntoEnumIterator;
-use syn::{Expr, parse2};
+use quote::{ToTokens, quote};
+use syn::{Expr, LitStr, parse2};
+use translatable_shared::{
+    Language, MissingArguments, TranslationNode, TranslationNodeCollection, choice_arguments,
+    edit_distance, filter_arguments, has_random_entries, sorted_translations, templates,
+    variant_path,
+};
 
+use super::diagnostics::{check, warning};
 use super::errors::TranslationError;
+use crate::data::config::load_config;
 use crate::data::translations::load_translations;
-use crate::languages::Iso639a;
 
 /// Generates compile-time string replacement logic for a single format
 /// argument.
@@ -20,7 +26,8 @@ use crate::languages::Iso639a;
 /// 3. Restore original `{key}` syntax from temporary markers
 ///
 /// # Arguments
-/// * `key` - Template placeholder name (without braces)
+/// * `key` - Template placeholder name (without braces), either a literal or an
+///   expression implementing `std::fmt::Display`
 /// * `value` - Expression to substitute, must implement `std::fmt::Display`
 ///
 /// # Example
@@ -33,7 +40,7 @@ use crate::languages::Iso639a;
 ///     .replace("{name}", &format!("{:#}", "Juan"))
 ///     .replace("\x01{name}\x01", "{name}");
 /// ```
-fn kwarg_static_replaces(key: &str, value: &TokenStream) -> TokenStream {
+pub fn kwarg_static_replaces(key: &impl ToTokens, value: &TokenStream) -> TokenStream {
     quote! {
         .replace(
             format!("{{{{{}}}}}", #key).as_str(), // Replace {{key}} -> a temporary placeholder
@@ -61,125 +68,883 @@ fn kwarg_static_replaces(key: &str, value: &TokenStream) -> TokenStream {
 ///   - Key: Template placeholder name
 ///   - Value: Runtime expression implementing `Display`
 ///
+/// Arguments without a value are only declared, they are checked for
+/// missing arguments but written later by `translation_write!`.
+///
 /// # Note
 /// The replacement order is important to prevent accidental substitution in
-/// nested templates. All replacements are wrapped in `Option::map` to handle
-/// potential `None` values from translation lookup.
-fn kwarg_dynamic_replaces(format_kwargs: &HashMap<String, TokenStream>) -> Vec<TokenStream> {
+/// nested templates. All replacements are wrapped in `Result::map` to handle
+/// potential errors from translation lookup, and only allocate once a
+/// replacement actually happens, yielding `Cow::Owned`. Translations without
+/// the template of an argument are kept as they are, borrowed if they were.
+fn kwarg_dynamic_replaces(format_kwargs: &BTreeMap<String, TokenStream>) -> Vec<TokenStream> {
     format_kwargs
         .iter()
+        .filter(|(_, value)| !value.is_empty())
         .map(|(key, value)| {
             let static_replaces = kwarg_static_replaces(key, value);
             quote! {
-                .map(|translation| translation
-                    #static_replaces
-                )
+                .map(|translation| {
+                    if translation.contains(format!("{{{}}}", #key).as_str()) {
+                        std::borrow::Cow::<'static, str>::Owned(translation
+                            #static_replaces
+                        )
+                    } else {
+                        translation
+                    }
+                })
             }
         })
         .collect::<Vec<_>>()
 }
 
-/// Parses a static language string into an Iso639a enum instance with
+/// Generates a runtime substitution loop for a format argument collection.
+///
+/// The collection is iterated at runtime and every `(key, value)` pair is
+/// replaced over a mutable `translation` binding with the same strategy as
+/// [`kwarg_static_replaces`].
+///
+/// # Arguments
+/// * `map` - Expression iterable as `(key, value)` pairs where both implement
+///   `Display`, such as `&HashMap<&str, String>` or `&[(&str, &dyn Display)]`
+/// * `track` - Whether replaced keys are removed from the pending `templates`
+///   declared by [`missing_arguments_check`]
+fn kwarg_map_loop(map: &TokenStream, track: bool) -> TokenStream {
+    let replaces = kwarg_static_replaces(&quote!(key), &quote!(value));
+    let track = track.then(|| {
+        quote! {
+            #[doc(hidden)]
+            let replaced = key.to_string();
+            templates.retain(|template| *template != replaced);
+        }
+    });
+
+    quote! {
+        for (key, value) in #map {
+            #track
+            translation = translation
+                #replaces;
+        }
+    }
+}
+
+/// Generates runtime-safe template substitution chain for format argument
+/// collections.
+///
+/// Collections are applied after the named arguments, wrapped in
+/// `Result::map` just like [`kwarg_dynamic_replaces`].
+fn kwarg_map_replaces(format_maps: &[TokenStream], track: bool) -> Vec<TokenStream> {
+    format_maps
+        .iter()
+        .map(|map| {
+            let map_loop = kwarg_map_loop(map, track);
+
+            quote! {
+                .map(|translation| {
+                    #[doc(hidden)]
+                    let mut translation = translation.into_owned();
+                    #map_loop
+                    std::borrow::Cow::<'static, str>::Owned(translation)
+                })
+            }
+        })
+        .collect()
+}
+
+/// Collects the arguments a translation uses with a formatter
+type UsedArguments = fn(&str) -> Vec<String>;
+
+/// Generates the runtime formatters applied before the replacements.
+///
+/// Plural and select blocks are selected first, as their arms may contain
+/// any template, then the templates of the named arguments are wrapped in
+/// bidi isolation characters if `bidi_isolation` is configured, and finally
+/// the templates with filter pipes are replaced. Only the named arguments
+/// some translation uses this way are passed.
+///
+/// # Arguments
+/// * `translations` - Translations the resolved one is one of
+/// * `format_kwargs` - Named format arguments
+///
+/// # Returns
+/// The formatter functions, taking a `&Language`, a `Cow<str>` and their
+/// arguments expression
+pub fn kwarg_formatters<'a>(
+    translations: impl IntoIterator<Item = &'a String>,
+    format_kwargs: &BTreeMap<String, TokenStream>,
+) -> Vec<(TokenStream, TokenStream)> {
+    let translations = translations.into_iter().collect::<Vec<_>>();
+
+    // The named arguments the translations use with a formatter
+    let used_arguments = |used: UsedArguments| {
+        let used =
+            translations.iter().flat_map(|translation| used(translation)).collect::<BTreeSet<_>>();
+
+        let mut arguments = format_kwargs
+            .iter()
+            .filter(|(key, value)| !value.is_empty() && used.contains(*key))
+            .collect::<Vec<_>>();
+        arguments.sort_by_key(|(key, _)| *key);
+
+        let arguments = arguments
+            .into_iter()
+            .map(|(key, value)| quote! { (#key, &(#value) as &dyn std::fmt::Display) })
+            .collect::<Vec<_>>();
+
+        (!arguments.is_empty()).then(|| quote! { &[#(#arguments),*] })
+    };
+
+    let mut formatters = Vec::new();
+
+    if let Some(arguments) = used_arguments(choice_arguments) {
+        formatters.push((quote!(translatable::internal::format_choices), arguments));
+    }
+
+    // The configuration was already loaded along with the translations.
+    // Declared arguments are isolated too, as they're written afterwards
+    if load_config().is_ok_and(|config| config.bidi_isolation()) {
+        let isolated = translations
+            .iter()
+            .flat_map(|translation| templates(translation))
+            .filter(|template| format_kwargs.contains_key(template))
+            .collect::<BTreeSet<_>>();
+
+        if !isolated.is_empty() {
+            let isolated = isolated.iter();
+
+            formatters.push((
+                quote!(translatable::internal::isolate_templates),
+                quote! { &[#(#isolated),*] },
+            ));
+        }
+    }
+
+    if let Some(arguments) = used_arguments(filter_arguments) {
+        formatters.push((quote!(translatable::internal::format_filters), arguments));
+    }
+
+    formatters
+}
+
+/// Applies the formatters generated by [`kwarg_formatters`] to a
+/// `Cow<str>` expression
+///
+/// # Arguments
+/// * `language` - Expression evaluating to the resolved `&Language`
+/// * `translation` - Expression evaluating to the `Cow<str>` translation
+pub fn kwarg_formatted(
+    language: &TokenStream,
+    translation: TokenStream,
+    formatters: &[(TokenStream, TokenStream)],
+) -> TokenStream {
+    formatters.iter().fold(translation, |translation, (function, arguments)| {
+        quote! { #function(#language, #translation, #arguments) }
+    })
+}
+
+/// Generates the runtime formatting steps, chained right after the
+/// translation is resolved
+///
+/// # Arguments
+/// * `language` - Expression evaluating to the resolved `&Language`
+/// * `formatters` - Formatters generated by [`kwarg_formatters`]
+fn kwarg_formatter_steps(
+    language: TokenStream,
+    formatters: &[(TokenStream, TokenStream)],
+) -> Vec<TokenStream> {
+    formatters
+        .iter()
+        .map(|(function, arguments)| {
+            quote! {
+                .map(|translation| #function(#language, translation, #arguments))
+            }
+        })
+        .collect()
+}
+
+/// Collects the arguments the loaded translations use in plural or select
+/// blocks or with filter pipes
+pub fn load_formatted_arguments() -> Result<BTreeSet<String>, TranslationError> {
+    Ok(flatten_paths(load_translations()?)
+        .values()
+        .flat_map(|translation_object| translation_object.values())
+        .flat_map(|translation| {
+            let mut arguments = choice_arguments(translation);
+            arguments.extend(filter_arguments(translation));
+            arguments
+        })
+        .collect())
+}
+
+/// Generates the runtime check for templates left without an argument.
+///
+/// Only generates code when `missing_arguments = "error"` is configured,
+/// otherwise all the fragments are empty.
+///
+/// The templates are collected from the resolved translation before any
+/// replacement, so escaped templates and templates inside argument values
+/// are never reported.
+///
+/// # Arguments
+/// * `format_kwargs` - Named format arguments, which are always replaced
+/// * `path` - Expression evaluating to the translation path as a `String`
+///
+/// # Returns
+/// - The `templates` binding declaration
+/// - The collection step, chained right after the translation is resolved
+/// - The check step, chained after all the replacements
+fn missing_arguments_check(
+    format_kwargs: &BTreeMap<String, TokenStream>,
+    path: TokenStream,
+) -> Result<(TokenStream, TokenStream, TokenStream), TranslationError> {
+    if let MissingArguments::Ignore = load_config()?.missing_arguments() {
+        return Ok((TokenStream::new(), TokenStream::new(), TokenStream::new()));
+    }
+
+    let named = format_kwargs.keys();
+
+    Ok((
+        quote! {
+            #[doc(hidden)]
+            let mut templates: Vec<String> = Vec::new();
+        },
+        quote! {
+            .inspect(|translation| {
+                templates = translatable::internal::templates(translation);
+                templates.retain(|template| ![#(#named),*].contains(&template.as_str()));
+            })
+        },
+        quote! {
+            .and_then(|translation| match templates.into_iter().next() {
+                Some(template) => Err(translatable::Error::MissingArgument { template, path: #path }),
+                None => Ok(translation),
+            })
+        },
+    ))
+}
+
+/// Generates a perfect hash map literal from its entries
+///
+/// The hash is computed at macro expansion time, so the generated
+/// `phf::Map` is built in a `static` without any runtime allocation.
+///
+/// # Arguments
+/// * `entries` - Keys and value expressions, keys must be unique
+fn phf_map(entries: Vec<(&str, TokenStream)>) -> TokenStream {
+    let state = generate_hash(&entries.iter().map(|(key, _)| *key).collect::<Vec<_>>());
+
+    let key = state.key;
+    let disps = state.disps.iter().map(|(d1, d2)| quote! { (#d1, #d2) });
+    let entries = state.map.iter().map(|index| {
+        let (key, value) = &entries[*index];
+        quote! { (#key, #value) }
+    });
+
+    quote! {
+        translatable::internal::phf::Map {
+            key: #key,
+            disps: &[#(#disps),*],
+            entries: &[#(#entries),*],
+        }
+    }
+}
+
+/// Generates a perfect hash map from language codes to translations
+///
+/// The languages are hashed sorted by code, so the generated map doesn't
+/// change between expansions.
+fn phf_language_map(translation_object: &HashMap<Language, String>) -> TokenStream {
+    phf_map(
+        sorted_translations(translation_object)
+            .into_iter()
+            .map(|(language, translation)| (language.code(), quote!(#translation)))
+            .collect(),
+    )
+}
+
+/// Generates a translation literal, one of its random entries being picked
+/// at runtime if it has any
+pub fn random_entry(translation: &str) -> TokenStream {
+    if has_random_entries(translation) {
+        quote!(translatable::internal::pick_entry(#translation))
+    } else {
+        quote!(#translation)
+    }
+}
+
+/// Generates the closure converting a `&&'static str` looked up at runtime
+/// to a `Cow<str>`, picking a random entry if any translation has entries
+fn borrowed_lookup<'a>(translations: impl IntoIterator<Item = &'a String>) -> TokenStream {
+    if translations.into_iter().any(|translation| has_random_entries(translation)) {
+        quote!(|translation| std::borrow::Cow::Borrowed(translatable::internal::pick_entry(
+            translation
+        )))
+    } else {
+        quote!(|translation| std::borrow::Cow::Borrowed(*translation))
+    }
+}
+
+/// Flattens the translation objects of a collection by their full paths
+///
+/// Paths are flattened in lookup priority order, so the first translation
+/// file containing a path wins, just like `TranslationNodeCollection`.
+pub fn flatten_paths(
+    translations: &TranslationNodeCollection,
+) -> BTreeMap<String, &HashMap<Language, String>> {
+    /// Collects the translation objects of a node with their full paths
+    fn flatten<'a>(
+        node: &'a TranslationNode,
+        prefix: String,
+        result: &mut BTreeMap<String, &'a HashMap<Language, String>>,
+    ) {
+        match node {
+            TranslationNode::Object(nested) => {
+                for (key, value) in nested {
+                    let path =
+                        if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                    flatten(value, path, result);
+                }
+            },
+            TranslationNode::Translation(translation) => {
+                result.entry(prefix).or_insert(translation);
+            },
+        }
+    }
+
+    let mut paths = BTreeMap::new();
+    for association in translations.iter() {
+        flatten(association.translation_table(), String::new(), &mut paths);
+    }
+
+    paths
+}
+
+/// Generates a perfect hash map from dot-separated paths to language maps
+fn phf_path_map(translations: &TranslationNodeCollection) -> TokenStream {
+    phf_map(
+        flatten_paths(translations)
+            .iter()
+            .map(|(path, translation_object)| (path.as_str(), phf_language_map(translation_object)))
+            .collect(),
+    )
+}
+
+/// Sorts the languages of a translation object by their code
+///
+/// The languages are reported in `Error::LanguageNotAvailable`, and in the
+/// compile time error of the same name.
+pub fn available_languages(translation_object: &HashMap<Language, String>) -> Vec<&Language> {
+    let mut available = translation_object.keys().collect::<Vec<_>>();
+    available.sort_by_key(|language| language.code());
+    available
+}
+
+/// Parses a static language string into a Language enum instance with
 /// compile-time validation.
 ///
+/// Configured language aliases are consulted before ISO 639-1 parsing, and
+/// the language must be one of the configured `languages`, if any.
+///
 /// # Arguments
 /// * `lang` - A string slice representing the language code to parse
 ///
 /// # Returns
-/// - `Ok(Iso639a)` if valid language code
-/// - `Err(TranslationError)` if parsing fails
-pub fn load_lang_static(lang: &str) -> Result<Iso639a, TranslationError> {
-    lang.parse::<Iso639a>().map_err(|_| TranslationError::InvalidLanguage(lang.to_string()))
+/// - `Ok(Language)` if valid language code or alias
+/// - `Err(TranslationError)` if parsing fails or the language isn't configured
+pub fn load_lang_static(lang: &str) -> Result<Language, TranslationError> {
+    let config = load_config()?;
+
+    let language = match config.aliases().get(&lang.to_lowercase()) {
+        Some(language) => language.clone(),
+        None => lang
+            .parse::<Language>()
+            .map_err(|_| TranslationError::InvalidLanguage(lang.to_string()))?,
+    };
+
+    match config.languages() {
+        Some(languages) if !languages.contains(&language) => {
+            Err(TranslationError::LanguageNotConfigured(language, languages.to_vec()))
+        },
+        _ => Ok(language),
+    }
 }
 
 /// Generates runtime validation for a dynamic language expression.
 ///
+/// The expression is parsed into a `language` binding of type
+/// `Result<Language, translatable::Error>`, with `FromStr` after consulting
+/// the configured aliases, so the generated lookups match on the enum. A
+/// `Language`, or a reference to one, is already valid, letting a language
+/// parsed once be reused.
+///
+/// If `languages` are configured, only their codes and names are matched
+/// instead, and only they are suggested for invalid languages.
+///
 /// # Arguments
 /// * `lang` - TokenStream representing an expression that implements
-///   `Into<String>`
+///   `Into<String>`, or a `Language`
 ///
 /// # Returns
 /// TokenStream with code to validate language at runtime
 pub fn load_lang_dynamic(lang: TokenStream) -> Result<TokenStream, TranslationError> {
     let lang: Expr = parse2(lang)?;
 
-    // Generate list of available language codes
-    let available_langs = Iso639a::iter().map(|language| {
-        let language = format!("{language:?}");
+    let config = load_config()?;
+    let configured = |language: &Language| config.languages().is_none_or(|l| l.contains(language));
 
-        quote! { #language, }
-    });
+    // Map configured aliases to their languages
+    let mut patterns = config
+        .aliases()
+        .iter()
+        .filter(|(_, language)| configured(language))
+        .map(|(alias, language)| (alias.clone(), language))
+        .collect::<BTreeMap<_, _>>();
 
-    // The `String` explicit type serves as
-    // expression type checking, we accept `impl Into<String>`
-    // for any expression that's not static.
-    Ok(quote! {
-        #[doc(hidden)]
-        let language: String = (#lang).into();
-        #[doc(hidden)]
-        let language = language.to_lowercase();
+    // A language subset is matched by its codes and names, skipping the
+    // parsing and suggestions of every other language. Aliases win over the
+    // codes and names they repeat, so no pattern is unreachable
+    let fallback = match config.languages() {
+        Some(languages) => {
+            for language in languages {
+                patterns.entry(language.code().to_string()).or_insert(language);
+                patterns.entry(language.to_string().to_lowercase()).or_insert(language);
+            }
 
+            quote! {
+                _ => Err(translatable::internal::invalid_language_among(
+                    language,
+                    &[#(#languages),*],
+                )),
+            }
+        },
+        None => quote! {
+            _ => language
+                .parse()
+                .map_err(|_| translatable::internal::invalid_language(language)),
+        },
+    };
+
+    let arms = patterns.iter().map(|(pattern, language)| quote! { #pattern => Ok(#language), });
+
+    // The conversion trait serves as expression type checking, we accept
+    // `impl Into<String>` and `Language` for any expression that's not static.
+    Ok(quote! {
         #[doc(hidden)]
-        let valid_lang = vec![#(#available_langs)*]
-            .iter()
-            .any(|lang| lang.eq_ignore_ascii_case(&language));
+        let language: Result<translatable::Language, translatable::Error> =
+            match translatable::internal::IntoLanguageArgument::into_language_argument(#lang) {
+                translatable::internal::LanguageArgument::Validated(language) => Ok(language),
+                translatable::internal::LanguageArgument::Code(language) => {
+                    match language.to_lowercase().as_str() {
+                        #(#arms)*
+                        #fallback
+                    }
+                },
+            };
     })
 }
 
+/// Named argument selecting a named variant of a static path
+pub const VARIANT_ARGUMENT: &str = "variant";
+
 /// Loads translations for static language resolution
 ///
+/// The `variant` argument selects a named variant of the path, see
+/// [`load_translation_variant`].
+///
 /// # Arguments
 /// * `static_lang` - Optional predefined language
 /// * `path` - Translation key path as dot-separated string
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
 ///
 /// # Returns
 /// TokenStream with either direct translation or language lookup logic
 pub fn load_translation_static(
-    static_lang: Option<Iso639a>,
+    static_lang: Option<Language>,
+    path: String,
+    mut format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
+) -> Result<TokenStream, TranslationError> {
+    let translations = load_translations()?;
+    let mut warnings = TokenStream::new();
+
+    let path = migrate_path(translations, path, &mut warnings);
+    warn_deprecation(translations, &path, &mut warnings);
+
+    let translation = match format_kwargs.remove(VARIANT_ARGUMENT) {
+        Some(variant) => {
+            load_translation_variant(static_lang, path, variant, format_kwargs, format_maps)?
+        },
+        None => {
+            let translation_object =
+                translations.find_path(&path).ok_or_else(|| path_not_found(translations, &path))?;
+
+            load_translation_object(
+                static_lang,
+                translation_object,
+                path_sources(translations, &path),
+                path,
+                format_kwargs,
+                format_maps,
+            )?
+        },
+    };
+
+    if warnings.is_empty() {
+        Ok(translation)
+    } else {
+        Ok(quote! {{
+            #warnings
+            #translation
+        }})
+    }
+}
+
+/// Resolves the new path of a migrated static path, warning the call site
+///
+/// # Arguments
+/// * `translations` - Loaded translations holding the migrations
+/// * `path` - Translation key path as dot-separated string
+/// * `warnings` - Warnings prepended to the generated code
+///
+/// # Returns
+/// The new path if the path was migrated, the path itself otherwise
+fn migrate_path(
+    translations: &TranslationNodeCollection,
     path: String,
-    format_kwargs: HashMap<String, TokenStream>,
+    warnings: &mut TokenStream,
+) -> String {
+    match translations.migrations().get(&path) {
+        Some(migrated) => {
+            let error = TranslationError::MigratedPath(path, migrated.clone());
+            warnings.extend(warning(&error.to_string()));
+            migrated.clone()
+        },
+        None => path,
+    }
+}
+
+/// Warns the call site if a static path is deprecated, deprecated paths
+/// still resolve
+///
+/// # Arguments
+/// * `translations` - Loaded translations holding the deprecations
+/// * `path` - Translation key path as dot-separated string
+/// * `warnings` - Warnings prepended to the generated code
+fn warn_deprecation(
+    translations: &TranslationNodeCollection,
+    path: &str,
+    warnings: &mut TokenStream,
+) {
+    if let Some(note) = translations.find_deprecation(path) {
+        let error = TranslationError::DeprecatedPath(path.to_string(), note.to_string());
+        warnings.extend(warning(&error.to_string()));
+    }
+}
+
+/// Loads translations for a static path and one of its named variants
+///
+/// String literal variants are selected at compile time, other expressions
+/// implementing `AsRef<str>` are matched at runtime against the variants of
+/// the path, the resolution evaluating to a `Cow<'static, str>` if the
+/// language is static. Variants the path doesn't have, or that lack the
+/// language, resolve the path itself.
+///
+/// # Arguments
+/// * `static_lang` - Optional predefined language
+/// * `path` - Translation key path as dot-separated string
+/// * `variant` - Expression evaluating to the variant name
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
+fn load_translation_variant(
+    static_lang: Option<Language>,
+    path: String,
+    variant: TokenStream,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
 ) -> Result<TokenStream, TranslationError> {
-    let translation_object = load_translations()?
+    let translations = load_translations()?;
+
+    // Variants without the static language fall back to the path too
+    let has_variant = |variant: &str| {
+        translations.find_path(variant).is_some_and(|translation_object| {
+            static_lang.as_ref().is_none_or(|language| translation_object.contains_key(language))
+        })
+    };
+
+    if let Ok(variant) = parse2::<LitStr>(variant.clone()) {
+        let variant = variant_path(&path, &variant.value());
+        let path = if has_variant(&variant) { variant } else { path };
+
+        return load_translation_static(static_lang, path, format_kwargs, format_maps);
+    }
+
+    let prefix = variant_path(&path, "");
+    let names = flatten_paths(translations)
+        .into_keys()
+        .filter(|found| has_variant(found))
+        .filter_map(|found| found.strip_prefix(&prefix).map(str::to_string))
+        .collect::<Vec<_>>();
+
+    let wrap = |translation: TokenStream| match static_lang {
+        Some(_) => quote!(std::borrow::Cow::<'static, str>::from(#translation)),
+        None => translation,
+    };
+
+    let arms = names
         .iter()
-        .find_map(|association| association.translation_table().get_path(path.split('.').collect()))
-        .ok_or(TranslationError::PathNotFound(path.to_string()))?;
+        .map(|name| {
+            let variant = variant_path(&path, name);
+
+            // Runtime languages the variant lacks fall back to the path
+            let guard = match static_lang {
+                Some(_) => TokenStream::new(),
+                None => {
+                    let languages = translations
+                        .find_path(&variant)
+                        .map(available_languages)
+                        .unwrap_or_default();
+
+                    quote! {
+                        if language.as_ref().is_ok_and(|requested| {
+                            [#(#languages),*].contains(requested)
+                        })
+                    }
+                },
+            };
+
+            let translation = wrap(load_translation_static(
+                static_lang.clone(),
+                variant,
+                format_kwargs.clone(),
+                format_maps.clone(),
+            )?);
+
+            Ok(quote!(#name #guard => #translation))
+        })
+        .collect::<Result<Vec<_>, TranslationError>>()?;
+
+    let translation =
+        wrap(load_translation_static(static_lang.clone(), path, format_kwargs, format_maps)?);
+
+    Ok(quote! {
+        match AsRef::<str>::as_ref(&(#variant)) {
+            #(#arms,)*
+            _ => #translation,
+        }
+    })
+}
+
+/// Checks the positional arguments of a call against the `{0}`, `{1}`...
+/// templates of a translation object
+///
+/// Calls without positional arguments aren't checked, so the templates can
+/// still be replaced by runtime collections.
+///
+/// # Returns
+/// - `Ok(())` if the arguments match the templates of every language
+/// - `Err(TranslationError)` if a language takes another number of them
+fn positional_arity_check(
+    translation_object: &HashMap<Language, String>,
+    path: &str,
+    format_kwargs: &BTreeMap<String, TokenStream>,
+) -> Result<(), TranslationError> {
+    let given =
+        (0..).take_while(|index: &usize| format_kwargs.contains_key(&index.to_string())).count();
+
+    if given == 0 {
+        return Ok(());
+    }
+
+    let mut languages = translation_object.iter().collect::<Vec<_>>();
+    languages.sort_by_key(|(language, _)| language.code());
+
+    for (language, translation) in languages {
+        let expected = templates(translation)
+            .iter()
+            .filter_map(|template| template.parse::<usize>().ok())
+            .max()
+            .map_or(0, |index| index + 1);
+
+        if expected != given {
+            return Err(TranslationError::PositionalArity(
+                path.to_string(),
+                language.clone(),
+                expected,
+                given,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects the files defining a translation path, in lookup priority order,
+/// for the notes of errors
+///
+/// The files are followed by the line the path is written at, such as
+/// `./translations/app.toml:12`, if they were parsed from TOML.
+fn path_sources(translations: &TranslationNodeCollection, path: &str) -> Vec<String> {
+    translations
+        .path_locations(path)
+        .into_iter()
+        .map(|(file, location)| match location {
+            Some(location) => format!("{file}:{}", location.line()),
+            None => file.to_string(),
+        })
+        .collect()
+}
+
+/// Builds the error of a path not found, suggesting the closest path with a
+/// typo every four characters at most
+pub fn path_not_found(translations: &TranslationNodeCollection, path: &str) -> TranslationError {
+    let max_distance = (path.chars().count() / 4).max(1);
+
+    let closest = flatten_paths(translations)
+        .into_keys()
+        .flat_map(|candidate| {
+            // Nestings are suggested too, for the prefix arguments
+            let mut prefixes = candidate
+                .match_indices('.')
+                .map(|(index, _)| candidate[..index].to_string())
+                .collect::<Vec<_>>();
+            prefixes.push(candidate);
+            prefixes
+        })
+        .map(|candidate| (edit_distance(path, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate);
+
+    TranslationError::PathNotFound(path.to_string(), closest)
+}
+
+/// Loads a resolved translation object, either from the translation files
+/// or declared inline
+///
+/// # Arguments
+/// * `static_lang` - Optional predefined language
+/// * `translation_object` - Translations per language
+/// * `sources` - Files defining the translation object, used in errors
+/// * `path` - Translation key path or description, used in errors
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
+///
+/// # Returns
+/// TokenStream with either direct translation or language lookup logic
+pub fn load_translation_object(
+    static_lang: Option<Language>,
+    translation_object: &HashMap<Language, String>,
+    sources: Vec<String>,
+    path: String,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
+) -> Result<TokenStream, TranslationError> {
+    positional_arity_check(translation_object, &path, &format_kwargs)?;
+
     let replaces = kwarg_dynamic_replaces(&format_kwargs);
+    let track = matches!(load_config()?.missing_arguments(), MissingArguments::Error);
+    let map_replaces = kwarg_map_replaces(&format_maps, track);
+    let (templates_declaration, templates_collect, templates_check) =
+        missing_arguments_check(&format_kwargs, quote!(#path.to_string()))?;
 
     Ok(match static_lang {
         Some(language) => {
-            let translation = translation_object
-                .get(&language)
-                .ok_or(TranslationError::LanguageNotAvailable(language, path))?;
+            // Non denied missing languages evaluate to the path
+            let (translation, warning) = match translation_object.get(&language) {
+                Some(translation) => (translation, TokenStream::new()),
+                None => {
+                    let error = TranslationError::LanguageNotAvailable(
+                        language.clone(),
+                        path.clone(),
+                        available_languages(translation_object).into_iter().cloned().collect(),
+                        sources,
+                    );
+                    (&path, check(load_config()?.on_missing_language(), error)?)
+                },
+            };
 
             let static_replaces = format_kwargs
                 .iter()
+                .filter(|(_, value)| !value.is_empty())
                 .map(|(key, value)| kwarg_static_replaces(key, value))
                 .collect::<Vec<_>>();
 
-            quote! {{
-                #translation
-                #(#static_replaces)*
-            }}
+            // Blocks and filters are formatted before the replacements
+            let formatters = kwarg_formatters([translation], &format_kwargs);
+            let translation = random_entry(translation);
+            let translation = if formatters.is_empty() {
+                quote!(#translation)
+            } else {
+                let translation = kwarg_formatted(
+                    &quote!(&#language),
+                    quote!(std::borrow::Cow::Borrowed(#translation)),
+                    &formatters,
+                );
+
+                quote!(#translation.into_owned())
+            };
+
+            if format_maps.is_empty() {
+                quote! {{
+                    #warning
+
+                    #translation
+                    #(#static_replaces)*
+                }}
+            } else {
+                let map_loops = format_maps.iter().map(|map| kwarg_map_loop(map, false));
+
+                quote! {{
+                    #warning
+
+                    #[doc(hidden)]
+                    let mut translation = String::from(
+                        #translation
+                        #(#static_replaces)*
+                    );
+
+                    #(#map_loops)*
+                    translation
+                }}
+            }
         },
 
         None => {
-            let translation_object = translation_object.iter().map(|(key, value)| {
-                let key = format!("{key:?}").to_lowercase();
-                quote! { (#key, #value) }
-            });
+            let available = available_languages(translation_object);
+            let formatters = kwarg_formatter_steps(
+                quote!(&requested),
+                &kwarg_formatters(translation_object.values(), &format_kwargs),
+            );
+            let borrowed = borrowed_lookup(translation_object.values());
+            let translation_object = phf_language_map(translation_object);
 
             quote! {{
-                if valid_lang {
-                    vec![#(#translation_object),*]
-                        .into_iter()
-                        .collect::<std::collections::HashMap<_, _>>()
-                        .get(language.as_str())
-                        .ok_or(translatable::Error::LanguageNotAvailable(language, #path.to_string()))
-                        .cloned()
-                        .map(|translation| translation.to_string())
+                #templates_declaration
+
+                #[doc(hidden)]
+                static TRANSLATION: translatable::internal::phf::Map<&'static str, &'static str> =
+                    #translation_object;
+
+                match language {
+                    Ok(requested) => {
+                        translatable::internal::trace_lookup(requested.code(), #path);
+
+                    TRANSLATION
+                        .get(requested.code())
+                        .ok_or_else(|| translatable::Error::LanguageNotAvailable {
+                            language: requested.clone(),
+                            path: #path.to_string(),
+                            available: vec![#(#available),*],
+                        })
+                        .map(#borrowed)
+                        #templates_collect
+                        #(#formatters)*
                         #(#replaces)*
-                } else {
-                    Err(translatable::Error::InvalidLanguage(language))
+                        #(#map_replaces)*
+                        #templates_check
+                    },
+                    Err(error) => Err(error),
                 }
             }}
         },
@@ -191,75 +956,310 @@ pub fn load_translation_static(
 /// # Arguments
 /// * `static_lang` - Optional predefined language
 /// * `path` - TokenStream representing dynamic path expression
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
 ///
 /// # Returns
 /// TokenStream with runtime translation resolution logic
 pub fn load_translation_dynamic(
-    static_lang: Option<Iso639a>,
+    static_lang: Option<Language>,
     path: TokenStream,
-    format_kwargs: HashMap<String, TokenStream>,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
+) -> Result<TokenStream, TranslationError> {
+    let translations = load_translations()?;
+
+    load_translation_nested(
+        static_lang,
+        translations,
+        quote!(#path.into()),
+        None,
+        format_kwargs,
+        format_maps,
+    )
+}
+
+/// Loads translations for a static path with a single runtime segment
+///
+/// The static prefix is validated at compile time, and only the nested
+/// objects at that prefix are embedded for runtime selection.
+///
+/// # Arguments
+/// * `static_lang` - Optional predefined language
+/// * `prefix` - Static dot-separated path before the runtime segment
+/// * `segment` - TokenStream representing the segment expression
+/// * `suffix` - Static dot-separated path after the runtime segment
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
+///
+/// # Returns
+/// TokenStream with runtime translation resolution logic
+pub fn load_translation_interpolated(
+    static_lang: Option<Language>,
+    prefix: String,
+    segment: TokenStream,
+    suffix: String,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
 ) -> Result<TokenStream, TranslationError> {
-    let nestings = load_translations()?
+    let translations = load_translations()?;
+    let translations =
+        translations.subtree(&prefix).ok_or_else(|| path_not_found(translations, &prefix))?;
+
+    let suffix = if suffix.is_empty() { suffix } else { format!(".{suffix}") };
+
+    load_translation_nested(
+        static_lang,
+        &translations,
+        quote!(format!("{}{}", #segment, #suffix)),
+        Some(prefix),
+        format_kwargs,
+        format_maps,
+    )
+}
+
+/// Generates runtime translation resolution over embedded translations
+///
+/// # Arguments
+/// * `static_lang` - Optional predefined language
+/// * `translations` - Translations embedded for runtime lookup
+/// * `path` - Expression evaluating to the path inside `translations`
+/// * `prefix` - Path of `translations` from the root, used in errors
+/// * `format_kwargs` - Named format arguments
+/// * `format_maps` - Runtime format argument collections
+fn load_translation_nested(
+    static_lang: Option<Language>,
+    translations: &TranslationNodeCollection,
+    path: TokenStream,
+    prefix: Option<String>,
+    format_kwargs: BTreeMap<String, TokenStream>,
+    format_maps: Vec<TokenStream>,
+) -> Result<TokenStream, TranslationError> {
+    let flattened = flatten_paths(translations);
+    let flattened = flattened.values().flat_map(|translation_object| translation_object.values());
+    let formatters = kwarg_formatters(flattened.clone(), &format_kwargs);
+    let borrowed = borrowed_lookup(flattened);
+    let migrations = translations
+        .migrations()
         .iter()
-        .map(|association| association.translation_table().clone().into())
-        .collect::<Vec<TokenStream>>();
+        .map(|(migrated, new)| quote!(#migrated => #new.to_string(),))
+        .collect::<Vec<_>>();
+    let translations = phf_path_map(translations);
+
+    // Migrated paths are looked up at their new path
+    let migrate = (!migrations.is_empty()).then(|| {
+        quote! {
+            #[doc(hidden)]
+            let path = match path.as_str() {
+                #(#migrations)*
+                _ => path,
+            };
+        }
+    });
+
+    // Errors report the full path from the root
+    let full_path = prefix.map(|prefix| {
+        quote! {
+            #[doc(hidden)]
+            let path = format!("{}.{}", #prefix, path);
+        }
+    });
 
     let translation_quote = quote! {
         #[doc(hidden)]
-        let path: String = #path.into();
+        let path: String = #path;
+
+        #migrate
 
         #[doc(hidden)]
-        let nested_translations = vec![#(#nestings),*];
+        static TRANSLATIONS: translatable::internal::phf::Map<
+            &'static str,
+            translatable::internal::phf::Map<&'static str, &'static str>,
+        > = #translations;
 
         #[doc(hidden)]
-        let translation = nested_translations
-            .iter()
-            .find_map(|nesting| nesting.get_path(
-                path
-                    .split('.')
-                    .collect()
-            ));
+        let translation = TRANSLATIONS.get(path.as_str());
+
+        #full_path
     };
 
     let replaces = kwarg_dynamic_replaces(&format_kwargs);
+    let track = matches!(load_config()?.missing_arguments(), MissingArguments::Error);
+    let map_replaces = kwarg_map_replaces(&format_maps, track);
+    let (templates_declaration, templates_collect, templates_check) =
+        missing_arguments_check(&format_kwargs, quote!(path))?;
 
     Ok(match static_lang {
         Some(language) => {
-            let language = format!("{language:?}").to_lowercase();
+            let language_code = language.code();
+            let formatters = kwarg_formatter_steps(quote!(&#language), &formatters);
 
             quote! {{
                 #translation_quote
+                #templates_declaration
+
+                translatable::internal::trace_lookup(#language_code, path.as_str());
 
                 if let Some(translation) = translation {
                     translation
-                        .get(#language)
-                        .ok_or(translatable::Error::LanguageNotAvailable(#language.to_string(), path))
-                        .cloned()
+                        .get(#language_code)
+                        .ok_or_else(|| translatable::Error::LanguageNotAvailable {
+                            language: #language,
+                            path: path.clone(),
+                            available: translatable::internal::available_languages(translation),
+                        })
+                        .map(#borrowed)
+                        #templates_collect
+                        #(#formatters)*
                         #(#replaces)*
+                        #(#map_replaces)*
+                        #templates_check
                 } else {
-                    Err(translatable::Error::PathNotFound(path))
+                    Err(translatable::Error::PathNotFound { path })
                 }
             }}
         },
 
         None => {
+            let formatters = kwarg_formatter_steps(quote!(&requested), &formatters);
+
             quote! {{
                 #translation_quote
+                #templates_declaration
 
-                if valid_lang {
-                    if let Some(translation) = translation {
-                        translation
-                            .get(&language)
-                            .ok_or(translatable::Error::LanguageNotAvailable(language, path))
-                            .cloned()
-                            #(#replaces)*
-                    } else {
-                        Err(translatable::Error::PathNotFound(path))
-                    }
-                } else {
-                    Err(translatable::Error::InvalidLanguage(language))
+                match language {
+                    Ok(requested) => {
+                        translatable::internal::trace_lookup(requested.code(), path.as_str());
+
+                        if let Some(translation) = translation {
+                            translation
+                                .get(requested.code())
+                                .ok_or_else(|| translatable::Error::LanguageNotAvailable {
+                                    language: requested.clone(),
+                                    path: path.clone(),
+                                    available: translatable::internal::available_languages(translation),
+                                })
+                                .map(#borrowed)
+                                #templates_collect
+                                #(#formatters)*
+                                #(#replaces)*
+                                #(#map_replaces)*
+                                #templates_check
+                        } else {
+                            Err(translatable::Error::PathNotFound { path })
+                        }
+                    },
+                    Err(error) => Err(error),
                 }
             }}
         },
     })
 }
+
+/// Loads a batch of static translation paths sharing a single language
+///
+/// # Arguments
+/// * `static_lang` - Optional predefined language
+/// * `paths` - Translation key paths as dot-separated strings
+///
+/// # Returns
+/// TokenStream with either a tuple of direct translations or a single
+/// language lookup evaluating to a `Result` with the tuple
+pub fn load_translations_batch(
+    static_lang: Option<Language>,
+    paths: Vec<String>,
+) -> Result<TokenStream, TranslationError> {
+    let translations = load_translations()?;
+    let mut path_warnings = TokenStream::new();
+    let translation_objects = paths
+        .into_iter()
+        .map(|path| {
+            let path = migrate_path(translations, path, &mut path_warnings);
+            warn_deprecation(translations, &path, &mut path_warnings);
+
+            translations
+                .find_path(&path)
+                .map(|translation_object| (path.clone(), translation_object))
+                .ok_or_else(|| path_not_found(translations, &path))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let translation = match static_lang {
+        Some(language) => {
+            let severity = load_config()?.on_missing_language();
+            let mut warnings = Vec::new();
+
+            // Non denied missing languages evaluate to the path
+            let translations = translation_objects
+                .into_iter()
+                .map(|(path, translation_object)| match translation_object.get(&language) {
+                    Some(translation) => Ok(random_entry(translation)),
+                    None => {
+                        let error = TranslationError::LanguageNotAvailable(
+                            language.clone(),
+                            path.clone(),
+                            available_languages(translation_object).into_iter().cloned().collect(),
+                            path_sources(translations, &path),
+                        );
+                        warnings.push(check(severity, error)?);
+                        Ok(quote!(#path))
+                    },
+                })
+                .collect::<Result<Vec<_>, TranslationError>>()?;
+
+            quote! {{
+                #(#warnings)*
+
+                (#(#translations,)*)
+            }}
+        },
+
+        None => {
+            let paths =
+                translation_objects.iter().map(|(path, _)| path.clone()).collect::<Vec<_>>();
+            let translations = translation_objects.into_iter().map(|(path, translation_object)| {
+                let available = available_languages(translation_object);
+                let mut arms = translation_object.iter().collect::<Vec<_>>();
+                arms.sort_by_key(|(language, _)| language.code());
+
+                let arms = arms.into_iter().map(|(language, value)| {
+                    let value = random_entry(value);
+                    quote! { #language => #value, }
+                });
+
+                quote! {
+                    std::borrow::Cow::<'static, str>::Borrowed(match requested {
+                        #(#arms)*
+                        _ => break 'translations Err(translatable::Error::LanguageNotAvailable {
+                            language: requested.clone(),
+                            path: #path.to_string(),
+                            available: vec![#(#available),*],
+                        }),
+                    })
+                }
+            });
+
+            quote! {
+                'translations: {
+                    let requested = match language {
+                        Ok(requested) => requested,
+                        Err(error) => break 'translations Err(error),
+                    };
+
+                    #(translatable::internal::trace_lookup(requested.code(), #paths);)*
+
+                    Ok::<_, translatable::Error>((#(#translations,)*))
+                }
+            }
+        },
+    };
+
+    if path_warnings.is_empty() {
+        Ok(translation)
+    } else {
+        Ok(quote! {{
+            #path_warnings
+            #translation
+        }})
+    }
+}
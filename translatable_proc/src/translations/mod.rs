@@ -1,2 +1,6 @@
+pub mod accessors;
+pub mod diagnostics;
 pub mod errors;
 pub mod generation;
+pub mod slugs;
+pub mod stats;
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::errors::TranslationError;
+use super::generation::{flatten_paths, path_not_found};
+use crate::data::translations::load_translations;
+
+/// Generates the localized slugs of the routes nested under a prefix
+///
+/// Every translation object under the prefix is a route keyed by its path
+/// relative to the prefix, its translations are the slugs per language.
+///
+/// # Errors
+/// If the prefix has no translation objects, a slug is empty or contains a
+/// `/`, or the same slug is used by two routes in a language.
+///
+/// # Returns
+/// TokenStream evaluating to a `translatable::Slugs` built in a constant
+pub fn load_translation_slugs(prefix: String) -> Result<TokenStream, TranslationError> {
+    let paths = flatten_paths(load_translations()?);
+
+    let routes = paths
+        .iter()
+        .filter_map(|(path, translation_object)| {
+            let key = path.strip_prefix(prefix.as_str())?.strip_prefix('.')?;
+            Some((key, *translation_object))
+        })
+        .collect::<Vec<_>>();
+
+    if routes.is_empty() {
+        return Err(path_not_found(load_translations()?, &prefix));
+    }
+
+    let mut keys = HashMap::new();
+    let mut generated = Vec::with_capacity(routes.len());
+
+    for (key, translation_object) in routes {
+        let mut slugs = translation_object.iter().collect::<Vec<_>>();
+        slugs.sort_by_key(|(language, _)| language.code());
+
+        for (language, slug) in &slugs {
+            if slug.is_empty() || slug.contains('/') {
+                return Err(TranslationError::InvalidSlug(
+                    slug.to_string(),
+                    format!("{prefix}.{key}"),
+                ));
+            }
+
+            if let Some(other) = keys.insert((language.code(), slug.as_str()), key) {
+                return Err(TranslationError::DuplicateSlug(
+                    slug.to_string(),
+                    (*language).clone(),
+                    format!("{prefix}.{other}"),
+                    format!("{prefix}.{key}"),
+                ));
+            }
+        }
+
+        let slugs = slugs.iter().map(|(language, slug)| quote! { (#language, #slug) });
+        generated.push(quote! { (#key, &[#(#slugs),*]) });
+    }
+
+    Ok(quote! {{
+        #[doc(hidden)]
+        const SLUGS: translatable::Slugs = translatable::Slugs::new(&[#(#generated),*]);
+
+        SLUGS
+    }})
+}
@@ -0,0 +1,154 @@
+use std::collections::{BTreeMap, HashMap};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use translatable_shared::{Language, TranslationNodeCollection, VARIANT_SEPARATOR};
+
+use super::errors::TranslationError;
+use super::generation::{flatten_paths, path_not_found};
+use crate::data::translations::load_translations;
+
+/// Translation coverage computed over a translation collection
+pub struct Coverage {
+    /// Number of translation paths
+    pub total_keys: usize,
+    /// Languages in use with their translated path count, sorted by code
+    pub languages: Vec<(Language, usize)>,
+    /// Missing language and path pairs, sorted by path and language code
+    pub missing: Vec<(Language, String)>,
+}
+
+/// Computes the coverage of a translation collection
+///
+/// The languages in use are the ones found in any translation object, every
+/// path missing one of them is reported.
+pub fn coverage(translations: &TranslationNodeCollection) -> Coverage {
+    let paths = key_paths(translations);
+    let languages = languages_in_use(&paths);
+
+    Coverage {
+        total_keys: paths.len(),
+        missing: missing_pairs(&paths, languages.iter().map(|(language, _)| language)),
+        languages,
+    }
+}
+
+/// Flattens the translation objects of a collection by their full paths,
+/// leaving out the named variants as they fall back to their path
+fn key_paths(
+    translations: &TranslationNodeCollection,
+) -> BTreeMap<String, &HashMap<Language, String>> {
+    let mut paths = flatten_paths(translations);
+    paths.retain(|path, _| !path.contains(VARIANT_SEPARATOR));
+    paths
+}
+
+/// Collects the languages found in any translation object with their
+/// translated path count, sorted by code
+fn languages_in_use(
+    paths: &BTreeMap<String, &HashMap<Language, String>>,
+) -> Vec<(Language, usize)> {
+    let mut languages = BTreeMap::new();
+
+    for translation_object in paths.values() {
+        for language in translation_object.keys() {
+            languages.entry(language.code()).or_insert((language.clone(), 0)).1 += 1;
+        }
+    }
+
+    languages.into_values().collect()
+}
+
+/// Collects the language and path pairs without a translation
+fn missing_pairs<'a>(
+    paths: &BTreeMap<String, &HashMap<Language, String>>,
+    languages: impl Iterator<Item = &'a Language> + Clone,
+) -> Vec<(Language, String)> {
+    paths
+        .iter()
+        .flat_map(|(path, translation_object)| {
+            languages
+                .clone()
+                .filter(|language| !translation_object.contains_key(language))
+                .map(|language| (language.clone(), path.clone()))
+        })
+        .collect()
+}
+
+/// Generates the coverage statistics of the loaded translations
+///
+/// # Returns
+/// TokenStream evaluating to a `translatable::TranslationStats` built in a
+/// constant, so the statistics have no runtime cost
+pub fn load_translation_stats() -> Result<TokenStream, TranslationError> {
+    let Coverage { total_keys, languages, missing } = coverage(load_translations()?);
+
+    let languages = languages.iter().map(|(language, translated)| {
+        quote! { translatable::LanguageStats::new(#language, #translated, #total_keys) }
+    });
+
+    let missing = missing.iter().map(|(language, path)| quote! { (#language, #path) });
+
+    Ok(quote! {{
+        #[doc(hidden)]
+        const STATS: translatable::TranslationStats = translatable::TranslationStats::new(
+            #total_keys,
+            &[#(#languages),*],
+            &[#(#missing),*],
+        );
+
+        STATS
+    }})
+}
+
+/// Generates a completeness assertion over the loaded translations
+///
+/// The check is done at compile time, the generated code only panics with
+/// the missing pairs grouped by path if there are any.
+///
+/// # Arguments
+/// * `prefix` - Dot-separated path the check is scoped to, every path by
+///   default
+/// * `languages` - Languages every path must have, the languages in use by
+///   default
+pub fn load_translations_complete(
+    prefix: Option<String>,
+    languages: Option<Vec<Language>>,
+) -> Result<TokenStream, TranslationError> {
+    let mut paths = key_paths(load_translations()?);
+
+    if let Some(prefix) = &prefix {
+        paths.retain(|path, _| {
+            path == prefix || path.strip_prefix(prefix.as_str()).is_some_and(|p| p.starts_with('.'))
+        });
+
+        if paths.is_empty() {
+            return Err(path_not_found(load_translations()?, prefix));
+        }
+    }
+
+    let languages = languages.unwrap_or_else(|| {
+        languages_in_use(&paths).into_iter().map(|(language, _)| language).collect()
+    });
+
+    let missing = missing_pairs(&paths, languages.iter());
+
+    if missing.is_empty() {
+        return Ok(quote! { () });
+    }
+
+    let mut grouped = BTreeMap::<&str, Vec<&str>>::new();
+    for (language, path) in &missing {
+        grouped.entry(path).or_default().push(language.code());
+    }
+
+    let lines = grouped
+        .iter()
+        .map(|(path, languages)| format!("  - {path}: {}", languages.join(", ")))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let message = format!("The translations are incomplete, {} missing:\n{lines}", missing.len());
+
+    Ok(quote! { panic!("{}", #message) })
+}
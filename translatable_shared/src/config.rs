@@ -0,0 +1,70 @@
+//! Values of the macro configuration
+//!
+//! This module holds the enumerated values of `translatable.toml`, parsed by
+//! the macros and written by the configuration builder of the runtime crate,
+//! both in lowercase.
+
+use strum::{EnumString, IntoStaticStr};
+
+/// File search order strategy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum SeekMode {
+    /// Alphabetical order (default)
+    #[default]
+    Alphabetical,
+
+    /// Reverse alphabetical order
+    Unalphabetical,
+}
+
+/// Translation conflict resolution strategy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum TranslationOverlap {
+    /// Last found translation overwrites previous ones (default)
+    #[default]
+    Overwrite,
+
+    /// First found translation is preserved
+    Ignore,
+}
+
+/// Runtime strategy for templates left without an argument
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum MissingArguments {
+    /// The raw template is kept in the translation (default)
+    #[default]
+    Ignore,
+
+    /// Dynamic resolutions return `Error::MissingArgument`
+    Error,
+}
+
+/// Escaping of the argument values replaced in translations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum Escape {
+    /// Values are replaced as they are (default)
+    #[default]
+    None,
+
+    /// The HTML special characters of values are escaped
+    Html,
+}
+
+/// Severity of a translation finding
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum Severity {
+    /// The finding fails the build (default)
+    #[default]
+    Deny,
+
+    /// The finding is emitted as a compile warning
+    Warn,
+
+    /// The finding is ignored
+    Allow,
+}
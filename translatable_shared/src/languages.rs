@@ -0,0 +1,765 @@
+use std::sync::LazyLock;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{ToTokens, quote};
+use strum::{Display, EnumIter, EnumProperty, EnumString, IntoEnumIterator};
+
+/// ISO 639-1 language code implementation with validation
+///
+/// Provides two-way mapping between language codes and names with:
+/// - Case-insensitive parsing
+/// - Strict validation
+/// - Complete ISO 639-1 coverage
+/// - Native names (autonyms) and writing direction metadata
+#[derive(Debug, Clone, EnumIter, Display, EnumString, EnumProperty, Eq, Hash, PartialEq)]
+#[strum(ascii_case_insensitive)]
+pub enum Language {
+    #[strum(serialize = "Abkhazian", serialize = "ab", props(native_name = "Аԥсуа"))]
+    AB,
+    #[strum(serialize = "Afar", serialize = "aa", props(native_name = "Afaraf"))]
+    AA,
+    #[strum(serialize = "Afrikaans", serialize = "af", props(native_name = "Afrikaans"))]
+    AF,
+    #[strum(serialize = "Akan", serialize = "ak", props(native_name = "Akan"))]
+    AK,
+    #[strum(serialize = "Albanian", serialize = "sq", props(native_name = "Shqip"))]
+    SQ,
+    #[strum(serialize = "Amharic", serialize = "am", props(native_name = "አማርኛ"))]
+    AM,
+    #[strum(serialize = "Arabic", serialize = "ar", props(native_name = "العربية"))]
+    AR,
+    #[strum(serialize = "Aragonese", serialize = "an", props(native_name = "Aragonés"))]
+    AN,
+    #[strum(serialize = "Armenian", serialize = "hy", props(native_name = "Հայերեն"))]
+    HY,
+    #[strum(serialize = "Assamese", serialize = "as", props(native_name = "অসমীয়া"))]
+    AS,
+    #[strum(serialize = "Avaric", serialize = "av", props(native_name = "Авар мацӀ"))]
+    AV,
+    #[strum(serialize = "Avestan", serialize = "ae", props(native_name = "Avesta"))]
+    AE,
+    #[strum(serialize = "Aymara", serialize = "ay", props(native_name = "Aymar aru"))]
+    AY,
+    #[strum(serialize = "Azerbaijani", serialize = "az", props(native_name = "Azərbaycan dili"))]
+    AZ,
+    #[strum(serialize = "Bambara", serialize = "bm", props(native_name = "Bamanankan"))]
+    BM,
+    #[strum(serialize = "Bashkir", serialize = "ba", props(native_name = "Башҡорт теле"))]
+    BA,
+    #[strum(serialize = "Basque", serialize = "eu", props(native_name = "Euskara"))]
+    EU,
+    #[strum(serialize = "Belarusian", serialize = "be", props(native_name = "Беларуская"))]
+    BE,
+    #[strum(serialize = "Bengali", serialize = "bn", props(native_name = "বাংলা"))]
+    BN,
+    #[strum(serialize = "Bislama", serialize = "bi", props(native_name = "Bislama"))]
+    BI,
+    #[strum(serialize = "Bosnian", serialize = "bs", props(native_name = "Bosanski"))]
+    BS,
+    #[strum(serialize = "Breton", serialize = "br", props(native_name = "Brezhoneg"))]
+    BR,
+    #[strum(serialize = "Bulgarian", serialize = "bg", props(native_name = "Български"))]
+    BG,
+    #[strum(serialize = "Burmese", serialize = "my", props(native_name = "မြန်မာဘာသာ"))]
+    MY,
+    #[strum(serialize = "Catalan", serialize = "ca", props(native_name = "Català"))]
+    CA,
+    #[strum(serialize = "Chamorro", serialize = "ch", props(native_name = "Chamoru"))]
+    CH,
+    #[strum(serialize = "Chechen", serialize = "ce", props(native_name = "Нохчийн мотт"))]
+    CE,
+    #[strum(serialize = "Chichewa", serialize = "ny", props(native_name = "Chicheŵa"))]
+    NY,
+    #[strum(serialize = "Chinese", serialize = "zh", props(native_name = "中文"))]
+    ZH,
+    #[strum(serialize = "Church Slavonic", serialize = "cu", props(native_name = "Словѣньскъ"))]
+    CU,
+    #[strum(serialize = "Chuvash", serialize = "cv", props(native_name = "Чӑваш чӗлхи"))]
+    CV,
+    #[strum(serialize = "Cornish", serialize = "kw", props(native_name = "Kernewek"))]
+    KW,
+    #[strum(serialize = "Corsican", serialize = "co", props(native_name = "Corsu"))]
+    CO,
+    #[strum(serialize = "Cree", serialize = "cr", props(native_name = "ᓀᐦᐃᔭᐍᐏᐣ"))]
+    CR,
+    #[strum(serialize = "Croatian", serialize = "hr", props(native_name = "Hrvatski"))]
+    HR,
+    #[strum(serialize = "Czech", serialize = "cs", props(native_name = "Čeština"))]
+    CS,
+    #[strum(serialize = "Danish", serialize = "da", props(native_name = "Dansk"))]
+    DA,
+    #[strum(serialize = "Divehi", serialize = "dv", props(native_name = "ދިވެހި"))]
+    DV,
+    #[strum(serialize = "Dutch", serialize = "nl", props(native_name = "Nederlands"))]
+    NL,
+    #[strum(serialize = "Dzongkha", serialize = "dz", props(native_name = "རྫོང་ཁ"))]
+    DZ,
+    #[strum(serialize = "English", serialize = "en", props(native_name = "English"))]
+    EN,
+    #[strum(serialize = "Esperanto", serialize = "eo", props(native_name = "Esperanto"))]
+    EO,
+    #[strum(serialize = "Estonian", serialize = "et", props(native_name = "Eesti"))]
+    ET,
+    #[strum(serialize = "Ewe", serialize = "ee", props(native_name = "Eʋegbe"))]
+    EE,
+    #[strum(serialize = "Faroese", serialize = "fo", props(native_name = "Føroyskt"))]
+    FO,
+    #[strum(serialize = "Fijian", serialize = "fj", props(native_name = "Vosa Vakaviti"))]
+    FJ,
+    #[strum(serialize = "Finnish", serialize = "fi", props(native_name = "Suomi"))]
+    FI,
+    #[strum(serialize = "French", serialize = "fr", props(native_name = "Français"))]
+    FR,
+    #[strum(serialize = "Western Frisian", serialize = "fy", props(native_name = "Frysk"))]
+    FY,
+    #[strum(serialize = "Fulah", serialize = "ff", props(native_name = "Fulfulde"))]
+    FF,
+    #[strum(serialize = "Gaelic", serialize = "gd", props(native_name = "Gàidhlig"))]
+    GD,
+    #[strum(serialize = "Galician", serialize = "gl", props(native_name = "Galego"))]
+    GL,
+    #[strum(serialize = "Ganda", serialize = "lg", props(native_name = "Luganda"))]
+    LG,
+    #[strum(serialize = "Georgian", serialize = "ka", props(native_name = "ქართული"))]
+    KA,
+    #[strum(serialize = "German", serialize = "de", props(native_name = "Deutsch"))]
+    DE,
+    #[strum(serialize = "Greek", serialize = "el", props(native_name = "Ελληνικά"))]
+    EL,
+    #[strum(serialize = "Kalaallisut", serialize = "kl", props(native_name = "Kalaallisut"))]
+    KL,
+    #[strum(serialize = "Guarani", serialize = "gn", props(native_name = "Avañe'ẽ"))]
+    GN,
+    #[strum(serialize = "Gujarati", serialize = "gu", props(native_name = "ગુજરાતી"))]
+    GU,
+    #[strum(serialize = "Haitian", serialize = "ht", props(native_name = "Kreyòl ayisyen"))]
+    HT,
+    #[strum(serialize = "Hausa", serialize = "ha", props(native_name = "Hausa"))]
+    HA,
+    #[strum(serialize = "Hebrew", serialize = "he", props(native_name = "עברית"))]
+    HE,
+    #[strum(serialize = "Herero", serialize = "hz", props(native_name = "Otjiherero"))]
+    HZ,
+    #[strum(serialize = "Hindi", serialize = "hi", props(native_name = "हिन्दी"))]
+    HI,
+    #[strum(serialize = "Hiri Motu", serialize = "ho", props(native_name = "Hiri Motu"))]
+    HO,
+    #[strum(serialize = "Hungarian", serialize = "hu", props(native_name = "Magyar"))]
+    HU,
+    #[strum(serialize = "Icelandic", serialize = "is", props(native_name = "Íslenska"))]
+    IS,
+    #[strum(serialize = "Ido", serialize = "io", props(native_name = "Ido"))]
+    IO,
+    #[strum(serialize = "Igbo", serialize = "ig", props(native_name = "Asụsụ Igbo"))]
+    IG,
+    #[strum(serialize = "Indonesian", serialize = "id", props(native_name = "Bahasa Indonesia"))]
+    ID,
+    #[strum(serialize = "Interlingua", serialize = "ia", props(native_name = "Interlingua"))]
+    IA,
+    #[strum(serialize = "Interlingue", serialize = "ie", props(native_name = "Interlingue"))]
+    IE,
+    #[strum(serialize = "Inuktitut", serialize = "iu", props(native_name = "ᐃᓄᒃᑎᑐᑦ"))]
+    IU,
+    #[strum(serialize = "Inupiaq", serialize = "ik", props(native_name = "Iñupiaq"))]
+    IK,
+    #[strum(serialize = "Irish", serialize = "ga", props(native_name = "Gaeilge"))]
+    GA,
+    #[strum(serialize = "Italian", serialize = "it", props(native_name = "Italiano"))]
+    IT,
+    #[strum(serialize = "Japanese", serialize = "ja", props(native_name = "日本語"))]
+    JA,
+    #[strum(serialize = "Javanese", serialize = "jv", props(native_name = "Basa Jawa"))]
+    JV,
+    #[strum(serialize = "Kannada", serialize = "kn", props(native_name = "ಕನ್ನಡ"))]
+    KN,
+    #[strum(serialize = "Kanuri", serialize = "kr", props(native_name = "Kanuri"))]
+    KR,
+    #[strum(serialize = "Kashmiri", serialize = "ks", props(native_name = "کٲشُر"))]
+    KS,
+    #[strum(serialize = "Kazakh", serialize = "kk", props(native_name = "Қазақ тілі"))]
+    KK,
+    #[strum(serialize = "Central Khmer", serialize = "km", props(native_name = "ខ្មែរ"))]
+    KM,
+    #[strum(serialize = "Kikuyu", serialize = "ki", props(native_name = "Gĩkũyũ"))]
+    KI,
+    #[strum(serialize = "Kinyarwanda", serialize = "rw", props(native_name = "Ikinyarwanda"))]
+    RW,
+    #[strum(serialize = "Kyrgyz", serialize = "ky", props(native_name = "Кыргызча"))]
+    KY,
+    #[strum(serialize = "Komi", serialize = "kv", props(native_name = "Коми кыв"))]
+    KV,
+    #[strum(serialize = "Kongo", serialize = "kg", props(native_name = "Kikongo"))]
+    KG,
+    #[strum(serialize = "Korean", serialize = "ko", props(native_name = "한국어"))]
+    KO,
+    #[strum(serialize = "Kuanyama", serialize = "kj", props(native_name = "Kuanyama"))]
+    KJ,
+    #[strum(serialize = "Kurdish", serialize = "ku", props(native_name = "Kurdî"))]
+    KU,
+    #[strum(serialize = "Lao", serialize = "lo", props(native_name = "ລາວ"))]
+    LO,
+    #[strum(serialize = "Latin", serialize = "la", props(native_name = "Latina"))]
+    LA,
+    #[strum(serialize = "Latvian", serialize = "lv", props(native_name = "Latviešu"))]
+    LV,
+    #[strum(serialize = "Limburgan", serialize = "li", props(native_name = "Limburgs"))]
+    LI,
+    #[strum(serialize = "Lingala", serialize = "ln", props(native_name = "Lingála"))]
+    LN,
+    #[strum(serialize = "Lithuanian", serialize = "lt", props(native_name = "Lietuvių"))]
+    LT,
+    #[strum(serialize = "Luba-Katanga", serialize = "lu", props(native_name = "Kiluba"))]
+    LU,
+    #[strum(serialize = "Luxembourgish", serialize = "lb", props(native_name = "Lëtzebuergesch"))]
+    LB,
+    #[strum(serialize = "Macedonian", serialize = "mk", props(native_name = "Македонски"))]
+    MK,
+    #[strum(serialize = "Malagasy", serialize = "mg", props(native_name = "Malagasy"))]
+    MG,
+    #[strum(serialize = "Malay", serialize = "ms", props(native_name = "Bahasa Melayu"))]
+    MS,
+    #[strum(serialize = "Malayalam", serialize = "ml", props(native_name = "മലയാളം"))]
+    ML,
+    #[strum(serialize = "Maltese", serialize = "mt", props(native_name = "Malti"))]
+    MT,
+    #[strum(serialize = "Manx", serialize = "gv", props(native_name = "Gaelg"))]
+    GV,
+    #[strum(serialize = "Maori", serialize = "mi", props(native_name = "Te reo Māori"))]
+    MI,
+    #[strum(serialize = "Marathi", serialize = "mr", props(native_name = "मराठी"))]
+    MR,
+    #[strum(serialize = "Marshallese", serialize = "mh", props(native_name = "Kajin M̧ajeļ"))]
+    MH,
+    #[strum(serialize = "Mongolian", serialize = "mn", props(native_name = "Монгол хэл"))]
+    MN,
+    #[strum(serialize = "Nauru", serialize = "na", props(native_name = "Dorerin Naoero"))]
+    NA,
+    #[strum(serialize = "Navajo", serialize = "nv", props(native_name = "Diné bizaad"))]
+    NV,
+    #[strum(serialize = "North Ndebele", serialize = "nd", props(native_name = "isiNdebele"))]
+    ND,
+    #[strum(serialize = "South Ndebele", serialize = "nr", props(native_name = "isiNdebele"))]
+    NR,
+    #[strum(serialize = "Ndonga", serialize = "ng", props(native_name = "Owambo"))]
+    NG,
+    #[strum(serialize = "Nepali", serialize = "ne", props(native_name = "नेपाली"))]
+    NE,
+    #[strum(serialize = "Norwegian", serialize = "no", props(native_name = "Norsk"))]
+    NO,
+    #[strum(serialize = "Norwegian Bokmål", serialize = "nb", props(native_name = "Norsk bokmål"))]
+    NB,
+    #[strum(
+        serialize = "Norwegian Nynorsk",
+        serialize = "nn",
+        props(native_name = "Norsk nynorsk")
+    )]
+    NN,
+    #[strum(serialize = "Occitan", serialize = "oc", props(native_name = "Occitan"))]
+    OC,
+    #[strum(serialize = "Ojibwa", serialize = "oj", props(native_name = "ᐊᓂᔑᓈᐯᒧᐎᓐ"))]
+    OJ,
+    #[strum(serialize = "Oriya", serialize = "or", props(native_name = "ଓଡ଼ିଆ"))]
+    OR,
+    #[strum(serialize = "Oromo", serialize = "om", props(native_name = "Afaan Oromoo"))]
+    OM,
+    #[strum(serialize = "Ossetian", serialize = "os", props(native_name = "Ирон æвзаг"))]
+    OS,
+    #[strum(serialize = "Pali", serialize = "pi", props(native_name = "पाऴि"))]
+    PI,
+    #[strum(serialize = "Pashto", serialize = "ps", props(native_name = "پښتو"))]
+    PS,
+    #[strum(serialize = "Persian", serialize = "fa", props(native_name = "فارسی"))]
+    FA,
+    #[strum(serialize = "Polish", serialize = "pl", props(native_name = "Polski"))]
+    PL,
+    #[strum(serialize = "Portuguese", serialize = "pt", props(native_name = "Português"))]
+    PT,
+    #[strum(serialize = "Punjabi", serialize = "pa", props(native_name = "ਪੰਜਾਬੀ"))]
+    PA,
+    #[strum(serialize = "Quechua", serialize = "qu", props(native_name = "Runa Simi"))]
+    QU,
+    #[strum(serialize = "Romanian", serialize = "ro", props(native_name = "Română"))]
+    RO,
+    #[strum(serialize = "Romansh", serialize = "rm", props(native_name = "Rumantsch"))]
+    RM,
+    #[strum(serialize = "Rundi", serialize = "rn", props(native_name = "Ikirundi"))]
+    RN,
+    #[strum(serialize = "Russian", serialize = "ru", props(native_name = "Русский"))]
+    RU,
+    #[strum(serialize = "North Sami", serialize = "se", props(native_name = "Davvisámegiella"))]
+    SE,
+    #[strum(serialize = "Samoan", serialize = "sm", props(native_name = "Gagana Samoa"))]
+    SM,
+    #[strum(serialize = "Sango", serialize = "sg", props(native_name = "Sängö"))]
+    SG,
+    #[strum(serialize = "Sanskrit", serialize = "sa", props(native_name = "संस्कृतम्"))]
+    SA,
+    #[strum(serialize = "Sardinian", serialize = "sc", props(native_name = "Sardu"))]
+    SC,
+    #[strum(serialize = "Serbian", serialize = "sr", props(native_name = "Српски"))]
+    SR,
+    #[strum(serialize = "Shona", serialize = "sn", props(native_name = "chiShona"))]
+    SN,
+    #[strum(serialize = "Sindhi", serialize = "sd", props(native_name = "سنڌي"))]
+    SD,
+    #[strum(serialize = "Sinhala", serialize = "si", props(native_name = "සිංහල"))]
+    SI,
+    #[strum(serialize = "Slovak", serialize = "sk", props(native_name = "Slovenčina"))]
+    SK,
+    #[strum(serialize = "Slovenian", serialize = "sl", props(native_name = "Slovenščina"))]
+    SL,
+    #[strum(serialize = "Somali", serialize = "so", props(native_name = "Soomaali"))]
+    SO,
+    #[strum(serialize = "Southern Sotho", serialize = "st", props(native_name = "Sesotho"))]
+    ST,
+    #[strum(serialize = "Spanish", serialize = "es", props(native_name = "Español"))]
+    ES,
+    #[strum(serialize = "Sundanese", serialize = "su", props(native_name = "Basa Sunda"))]
+    SU,
+    #[strum(serialize = "Swahili", serialize = "sw", props(native_name = "Kiswahili"))]
+    SW,
+    #[strum(serialize = "Swati", serialize = "ss", props(native_name = "SiSwati"))]
+    SS,
+    #[strum(serialize = "Swedish", serialize = "sv", props(native_name = "Svenska"))]
+    SV,
+    #[strum(serialize = "Tagalog", serialize = "tl", props(native_name = "Tagalog"))]
+    TL,
+    #[strum(serialize = "Tahitian", serialize = "ty", props(native_name = "Reo Tahiti"))]
+    TY,
+    #[strum(serialize = "Tajik", serialize = "tg", props(native_name = "Тоҷикӣ"))]
+    TG,
+    #[strum(serialize = "Tamil", serialize = "ta", props(native_name = "தமிழ்"))]
+    TA,
+    #[strum(serialize = "Tatar", serialize = "tt", props(native_name = "Татар теле"))]
+    TT,
+    #[strum(serialize = "Telugu", serialize = "te", props(native_name = "తెలుగు"))]
+    TE,
+    #[strum(serialize = "Thai", serialize = "th", props(native_name = "ไทย"))]
+    TH,
+    #[strum(serialize = "Tibetan", serialize = "bo", props(native_name = "བོད་ཡིག"))]
+    BO,
+    #[strum(serialize = "Tigrinya", serialize = "ti", props(native_name = "ትግርኛ"))]
+    TI,
+    #[strum(serialize = "Tonga", serialize = "to", props(native_name = "Lea faka-Tonga"))]
+    TO,
+    #[strum(serialize = "Tsonga", serialize = "ts", props(native_name = "Xitsonga"))]
+    TS,
+    #[strum(serialize = "Tswana", serialize = "tn", props(native_name = "Setswana"))]
+    TN,
+    #[strum(serialize = "Turkish", serialize = "tr", props(native_name = "Türkçe"))]
+    TR,
+    #[strum(serialize = "Turkmen", serialize = "tk", props(native_name = "Türkmençe"))]
+    TK,
+    #[strum(serialize = "Twi", serialize = "tw", props(native_name = "Twi"))]
+    TW,
+    #[strum(serialize = "Uighur", serialize = "ug", props(native_name = "ئۇيغۇرچە"))]
+    UG,
+    #[strum(serialize = "Ukrainian", serialize = "uk", props(native_name = "Українська"))]
+    UK,
+    #[strum(serialize = "Urdu", serialize = "ur", props(native_name = "اردو"))]
+    UR,
+    #[strum(serialize = "Uzbek", serialize = "uz", props(native_name = "Oʻzbekcha"))]
+    UZ,
+    #[strum(serialize = "Venda", serialize = "ve", props(native_name = "Tshivenḓa"))]
+    VE,
+    #[strum(serialize = "Vietnamese", serialize = "vi", props(native_name = "Tiếng Việt"))]
+    VI,
+    #[strum(serialize = "Volapük", serialize = "vo", props(native_name = "Volapük"))]
+    VO,
+    #[strum(serialize = "Walloon", serialize = "wa", props(native_name = "Walon"))]
+    WA,
+    #[strum(serialize = "Welsh", serialize = "cy", props(native_name = "Cymraeg"))]
+    CY,
+    #[strum(serialize = "Wolof", serialize = "wo", props(native_name = "Wollof"))]
+    WO,
+    #[strum(serialize = "Xhosa", serialize = "xh", props(native_name = "isiXhosa"))]
+    XH,
+    #[strum(serialize = "Sichuan Yi", serialize = "ii", props(native_name = "ꆈꌠꉙ"))]
+    II,
+    #[strum(serialize = "Yiddish", serialize = "yi", props(native_name = "ייִדיש"))]
+    YI,
+    #[strum(serialize = "Yoruba", serialize = "yo", props(native_name = "Yorùbá"))]
+    YO,
+    #[strum(serialize = "Zhuang", serialize = "za", props(native_name = "Saɯ cueŋƅ"))]
+    ZA,
+    #[strum(serialize = "Zulu", serialize = "zu", props(native_name = "isiZulu"))]
+    ZU,
+}
+
+/// Writing direction of a language's script
+///
+/// The `Display` implementation yields the value expected by the HTML `dir`
+/// attribute.
+#[derive(Debug, Clone, Copy, Display, Eq, Hash, PartialEq)]
+pub enum TextDirection {
+    /// Left to right, used by most scripts.
+    #[strum(serialize = "ltr")]
+    LeftToRight,
+
+    /// Right to left, used by scripts such as Arabic and Hebrew.
+    #[strum(serialize = "rtl")]
+    RightToLeft,
+}
+
+/// This struct represents a list of similar languages to the provided one.
+pub struct Similarities<T: Sized> {
+    /// Indicates how many languages are not included in the list.
+    overflow_by: usize,
+    /// List of similar languages.
+    similarities: Vec<T>,
+}
+
+impl<T: Sized> Similarities<T> {
+    pub fn overflow_by(&self) -> usize {
+        self.overflow_by
+    }
+
+    pub fn similarities(&self) -> &[T] {
+        &self.similarities
+    }
+}
+
+impl Language {
+    /// Finds a language by its English name, ignoring case.
+    ///
+    /// e.g. `Language::from_name("spanish")` returns `Some(Language::ES)`.
+    /// Unlike `FromStr`, ISO 639-1 codes are not accepted.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+
+        Self::iter().find(|language| language.to_string().to_lowercase() == name)
+    }
+
+    /// Finds a language by its native name, ignoring case.
+    ///
+    /// e.g. `Language::from_autonym("español")` returns `Some(Language::ES)`.
+    pub fn from_autonym(autonym: &str) -> Option<Self> {
+        let autonym = autonym.trim().to_lowercase();
+
+        Self::iter().find(|language| language.native_name().to_lowercase() == autonym)
+    }
+
+    /// Finds the language of a locale identifier, ignoring case.
+    ///
+    /// e.g. `Language::from_locale("es_ES.UTF-8")` and
+    /// `Language::from_locale("es-AR")` return `Some(Language::ES)`. Only the
+    /// primary ISO 639-1 code is considered, so the `C` and `POSIX` locales
+    /// return `None`.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale.trim().split(['_', '-', '.', '@']).next()?.to_lowercase();
+
+        Self::iter().find(|language| language.code() == code)
+    }
+
+    /// Finds the language of the process locale, from the first of the
+    /// `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables set.
+    ///
+    /// e.g. with `LANG=es_ES.UTF-8` it returns `Some(Language::ES)`. Empty
+    /// variables are skipped like in POSIX, see [`Language::from_locale`].
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Self::from_locale(&locale))
+    }
+
+    /// Finds the language of the operating system preferences, the first of
+    /// them with an ISO 639-1 code.
+    ///
+    /// The preferences are read through the platform APIs, and from the
+    /// environment on Unix systems other than macOS.
+    #[cfg(feature = "sys-locale")]
+    pub fn from_system() -> Option<Self> {
+        sys_locale::get_locales().find_map(|locale| Self::from_locale(&locale))
+    }
+
+    /// Returns the lowercase ISO 639-1 code of the language.
+    ///
+    /// e.g. `Language::ES.code()` returns `"es"`.
+    pub fn code(&self) -> &'static str {
+        // variants are named after the uppercase code, in declaration order.
+        static CODES: LazyLock<Vec<String>> = LazyLock::new(|| {
+            Language::iter().map(|language| format!("{language:?}").to_lowercase()).collect()
+        });
+
+        &CODES[self.clone() as usize]
+    }
+
+    /// Returns the name of the language in the language itself.
+    ///
+    /// e.g. `Language::ES.native_name()` returns `"Español"`.
+    pub fn native_name(&self) -> &'static str {
+        // every variant declares a `native_name` property.
+        self.get_str("native_name").unwrap_or_default()
+    }
+
+    /// Returns the writing direction of the language's predominant script.
+    pub fn direction(&self) -> TextDirection {
+        match self {
+            Self::AR
+            | Self::DV
+            | Self::FA
+            | Self::HE
+            | Self::KS
+            | Self::KU
+            | Self::PS
+            | Self::SD
+            | Self::UG
+            | Self::UR
+            | Self::YI => TextDirection::RightToLeft,
+
+            _ => TextDirection::LeftToRight,
+        }
+    }
+
+    /// Finds the languages whose name or code, formatted as `Spanish (ES)`,
+    /// contains the provided text.
+    pub fn similar_languages(lang: &str) -> impl Iterator<Item = Self> + '_ {
+        Self::iter().filter(move |variant| format!("{variant:#} ({variant:?})").contains(lang))
+    }
+
+    /// Suggests languages for a mistyped language code or name.
+    ///
+    /// The language of a locale identifier comes first, such as `ES` for
+    /// `"es-MX"`, then the [`similar_languages`] and the languages whose
+    /// English name is a few typos away, such as `ES` for `"spansh"`, the
+    /// closest ones first.
+    ///
+    /// [`similar_languages`]: Language::similar_languages
+    pub fn suggestions(lang: &str, max_amount: usize) -> Vec<Self> {
+        Self::suggestions_among(lang, max_amount, &Self::iter().collect::<Vec<_>>())
+    }
+
+    /// Suggests languages of a subset for a mistyped language code or name.
+    ///
+    /// Like [`suggestions`], but only the provided languages are suggested,
+    /// such as the ones an application ships.
+    ///
+    /// [`suggestions`]: Language::suggestions
+    pub fn suggestions_among(lang: &str, max_amount: usize, languages: &[Self]) -> Vec<Self> {
+        let input = lang.trim().to_lowercase();
+        // Allow a typo every four characters
+        let max_distance = (input.chars().count() / 4).max(1);
+
+        let mut close = languages
+            .iter()
+            .map(|variant| (edit_distance(&input, &variant.to_string().to_lowercase()), variant))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect::<Vec<_>>();
+        close.sort_by_key(|(distance, _)| *distance);
+
+        let similar =
+            languages.iter().filter(|variant| format!("{variant:#} ({variant:?})").contains(lang));
+
+        let mut suggestions = Vec::new();
+
+        let candidates = Self::from_locale(lang)
+            .filter(|language| languages.contains(language))
+            .into_iter()
+            .chain(similar.cloned())
+            .chain(close.into_iter().map(|(_, variant)| variant.clone()));
+
+        for candidate in candidates {
+            if suggestions.len() == max_amount {
+                break;
+            }
+
+            if !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+
+        suggestions
+    }
+
+    /// This method returns a list of similar languages to the provided one.
+    pub fn get_similarities(lang: &str, max_amount: usize) -> Similarities<String> {
+        let all_similarities = Self::similar_languages(lang)
+            .map(|variant| format!("{variant:#} ({variant:?})"))
+            .collect::<Vec<_>>();
+
+        let overflow_by = all_similarities.len() as i32 - max_amount as i32;
+
+        if overflow_by > 0 {
+            Similarities {
+                similarities: all_similarities.into_iter().take(max_amount).collect(),
+                overflow_by: overflow_by as usize,
+            }
+        } else {
+            Similarities {
+                similarities: all_similarities,
+                overflow_by: 0,
+            }
+        }
+    }
+}
+
+/// Counts the insertions, deletions, substitutions and transpositions of
+/// adjacent characters turning a text into another one, to suggest the
+/// closest of some keys or languages
+pub fn edit_distance(from: &str, to: &str) -> usize {
+    let from = from.chars().collect::<Vec<_>>();
+    let to = to.chars().collect::<Vec<_>>();
+
+    // Distances between the prefixes of both texts, a row for each of `from`
+    let mut distances = vec![(0..=to.len()).collect::<Vec<_>>(); from.len() + 1];
+
+    for i in 1..=from.len() {
+        distances[i][0] = i;
+
+        for j in 1..=to.len() {
+            let substitution = usize::from(from[i - 1] != to[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + substitution);
+
+            if i > 1 && j > 1 && from[i - 1] == to[j - 2] && from[i - 2] == to[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[from.len()][to.len()]
+}
+
+impl PartialEq<String> for Language {
+    fn eq(&self, other: &String) -> bool {
+        format!("{self:?}").to_lowercase() == other.to_lowercase()
+    }
+}
+
+/// Language of a runtime resolution, as passed to the macros
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageArgument {
+    /// Language validated beforehand, used as it is
+    Validated(Language),
+    /// Language code or alias, validated on every resolution
+    Code(String),
+}
+
+/// Conversion of the language expressions the macros accept
+///
+/// Expressions converting to `String` are validated on every resolution,
+/// while a [`Language`] is already valid, so it can be parsed once outside a
+/// loop and reused.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a language",
+    label = "expected an `impl Into<String>` or a `Language`"
+)]
+pub trait IntoLanguageArgument {
+    /// Converts the expression to a language or a code to validate
+    fn into_language_argument(self) -> LanguageArgument;
+}
+
+impl<T: Into<String>> IntoLanguageArgument for T {
+    fn into_language_argument(self) -> LanguageArgument {
+        LanguageArgument::Code(self.into())
+    }
+}
+
+impl IntoLanguageArgument for Language {
+    fn into_language_argument(self) -> LanguageArgument {
+        LanguageArgument::Validated(self)
+    }
+}
+
+impl IntoLanguageArgument for &Language {
+    fn into_language_argument(self) -> LanguageArgument {
+        LanguageArgument::Validated(self.clone())
+    }
+}
+
+impl ToTokens for Language {
+    /// Converts the language to a path to its variant for procedural macro
+    /// output
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = Ident::new(&format!("{self:?}"), Span::call_site());
+
+        tokens.extend(quote! { translatable::Language::#variant });
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for Language {
+    fn value_variants<'a>() -> &'a [Self] {
+        static VARIANTS: std::sync::OnceLock<Vec<Language>> = std::sync::OnceLock::new();
+
+        VARIANTS.get_or_init(|| Self::iter().collect())
+    }
+
+    /// Exposes the ISO 639-1 code as the CLI value and the English name as
+    /// help text.
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(self.code()).help(self.to_string()))
+    }
+}
+
+#[cfg(feature = "unic-langid")]
+impl From<Language> for unic_langid::LanguageIdentifier {
+    fn from(value: Language) -> Self {
+        // every ISO 639-1 code is a well-formed language subtag.
+        value.code().parse().expect("ISO 639-1 codes are valid language identifiers")
+    }
+}
+
+#[cfg(feature = "unic-langid")]
+impl TryFrom<&unic_langid::LanguageIdentifier> for Language {
+    type Error = strum::ParseError;
+
+    /// Converts the primary language subtag, discarding script, region and
+    /// variants.
+    fn try_from(value: &unic_langid::LanguageIdentifier) -> Result<Self, Self::Error> {
+        let subtag = value.language.as_str();
+
+        Self::iter()
+            .find(|language| language.code() == subtag)
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
+}
+
+#[cfg(feature = "language-tags")]
+impl From<Language> for language_tags::LanguageTag {
+    fn from(value: Language) -> Self {
+        // every ISO 639-1 code is a well-formed language tag.
+        Self::parse(value.code()).expect("ISO 639-1 codes are valid language tags")
+    }
+}
+
+#[cfg(feature = "language-tags")]
+impl TryFrom<&language_tags::LanguageTag> for Language {
+    type Error = strum::ParseError;
+
+    /// Converts the primary language subtag, discarding any other subtag.
+    fn try_from(value: &language_tags::LanguageTag) -> Result<Self, Self::Error> {
+        let subtag = value.primary_language().to_lowercase();
+
+        Self::iter()
+            .find(|language| language.code() == subtag)
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Language {
+    /// Serializes the language as its lowercase ISO 639-1 code.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Language {
+    /// Deserializes the language from an ISO 639-1 code, ignoring case.
+    ///
+    /// Language names are rejected, unlike with `FromStr`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let language = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        Self::iter().find(|variant| variant.code().eq_ignore_ascii_case(&language)).ok_or_else(
+            || serde::de::Error::custom(format!("'{language}' is not valid ISO 639-1.")),
+        )
+    }
+}
@@ -0,0 +1,41 @@
+//! Shared types for the translatable library
+//!
+//! This crate holds the types that both the procedural macros and the
+//! runtime crate need to agree on, such as the [`Language`] enum and the
+//! translation structures embedded by the macros.
+
+mod config;
+mod languages;
+mod translations;
+
+pub use config::{Escape, MissingArguments, SeekMode, Severity, TranslationOverlap};
+pub use languages::{
+    IntoLanguageArgument, Language, LanguageArgument, Similarities, TextDirection, edit_distance,
+};
+pub use translations::binary::FORMAT_VERSION;
+pub use translations::collection::{
+    AssociatedTranslation, PRIORITY_KEY, Precedence, TranslationNodeCollection, VERSION_KEY,
+    sort_by_priority, take_priority, take_version,
+};
+pub use translations::condition::{
+    CFG_KEY, TARGET_OS_VARIANTS, apply_conditions, apply_target_variants,
+};
+pub use translations::deprecation::{DEPRECATED_KEY, deprecation, take_deprecations};
+pub use translations::filter::{filter_arguments, filter_names, split_filters};
+pub use translations::lint::{Lint, LintFinding, lint_catalog};
+pub use translations::location::{SourceLocation, parse_with_locations};
+#[cfg(feature = "markdown")]
+pub use translations::markdown::render_markdown;
+pub use translations::message::{
+    Fragment, PluralVariant, choice_arguments, format_choices, has_choices, plural_category,
+    plural_variants, select_fragments, templates,
+};
+pub use translations::node::{
+    KeyKind, METADATA_PREFIX, Nested, TransformError, TranslationNode, TranslationObject,
+    sorted_translations,
+};
+pub use translations::random::{ENTRY_SEPARATOR, has_random_entries, random_entries};
+pub use translations::unicode::check_unicode;
+pub use translations::value::{VALUES_KEY, apply_values, array_elements};
+pub use translations::variant::{VARIANT_SEPARATOR, apply_named_variants, variant_path};
+pub use translations::whitespace::{TRIM_KEY, apply_trim, dedent};
@@ -0,0 +1,312 @@
+//! Compact binary encoding of the translation structures
+//!
+//! This module provides the format the procedural macros use to embed
+//! translations as a byte string, which is much cheaper to compile than the
+//! equivalent constructor code for big catalogs.
+//!
+//! # Format
+//! - The first byte is the format version
+//! - Lengths and indexes are LEB128 variable length integers
+//! - Strings are interned in a table, a length followed by length prefixed
+//!   UTF-8 strings, every other string is an index in that table
+//! - Priorities are zigzag encoded LEB128 variable length integers
+//! - Versions are LEB128 variable length integers, `0` for unversioned files
+//!   and the version plus one otherwise
+//! - A collection is a length followed by `(path, priority, version,
+//!   deprecations, locations, node)` tuples, then a length followed by the
+//!   `(path, new path)` pairs of the migrated paths
+//! - Deprecations are a length followed by `(path, note)` pairs
+//! - Locations are a length followed by `(path, offset, line, column)` tuples
+//! - A node is a tag, `0` for objects followed by a length and `(key, node)`
+//!   pairs, or `1` for translations followed by a length and `(language code,
+//!   translation)` pairs
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::collection::{AssociatedTranslation, TranslationNodeCollection};
+use super::location::SourceLocation;
+use super::node::{TranslationNode, sorted_translations};
+
+/// Format version, decoding any other version fails
+pub const FORMAT_VERSION: u8 = 7;
+
+/// Tag for [`TranslationNode::Object`]
+const OBJECT_TAG: u8 = 0;
+
+/// Tag for [`TranslationNode::Translation`]
+const TRANSLATION_TAG: u8 = 1;
+
+/// Appends a length as a LEB128 variable length integer
+fn encode_length(bytes: &mut Vec<u8>, mut length: usize) {
+    loop {
+        let byte = (length & 0x7f) as u8;
+        length >>= 7;
+
+        if length == 0 {
+            bytes.push(byte);
+            break;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Appends a signed integer zigzag encoded, so small negative ones stay short
+fn encode_priority(bytes: &mut Vec<u8>, priority: i64) {
+    encode_length(bytes, ((priority << 1) ^ (priority >> 63)) as u64 as usize);
+}
+
+/// Appends a length prefixed UTF-8 string
+fn encode_string(bytes: &mut Vec<u8>, value: &str) {
+    encode_length(bytes, value.len());
+    bytes.extend_from_slice(value.as_bytes());
+}
+
+/// Encodes the collection structure while interning its strings
+#[derive(Default)]
+struct Encoder<'a> {
+    /// Encoded structure, without the string table
+    bytes: Vec<u8>,
+    /// Interned strings in table order
+    strings: Vec<&'a str>,
+    /// Table index of every interned string
+    indexes: HashMap<&'a str, usize>,
+}
+
+impl<'a> Encoder<'a> {
+    /// Appends the table index of a string, interning it if new
+    fn string(&mut self, value: &'a str) {
+        let index = *self.indexes.entry(value).or_insert_with(|| {
+            self.strings.push(value);
+            self.strings.len() - 1
+        });
+
+        encode_length(&mut self.bytes, index);
+    }
+
+    /// Appends a translation node recursively
+    ///
+    /// Entries are appended sorted, so the same collection always encodes to
+    /// the same bytes.
+    fn node(&mut self, node: &'a TranslationNode) {
+        match node {
+            TranslationNode::Object(nested) => {
+                self.bytes.push(OBJECT_TAG);
+                encode_length(&mut self.bytes, nested.len());
+
+                for (key, value) in node.sorted() {
+                    self.string(key);
+                    self.node(value);
+                }
+            },
+
+            TranslationNode::Translation(translation) => {
+                self.bytes.push(TRANSLATION_TAG);
+                encode_length(&mut self.bytes, translation.len());
+
+                for (language, value) in sorted_translations(translation) {
+                    self.string(language.code());
+                    self.string(value);
+                }
+            },
+        }
+    }
+}
+
+/// Encodes a collection of translation files
+pub fn encode_collection(collection: &TranslationNodeCollection) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    let translations = collection.iter().collect::<Vec<_>>();
+
+    encode_length(&mut encoder.bytes, translations.len());
+
+    for association in translations {
+        encoder.string(association.original_path());
+        encode_priority(&mut encoder.bytes, association.priority());
+        encode_length(&mut encoder.bytes, association.version().map_or(0, |v| v as usize + 1));
+        encode_length(&mut encoder.bytes, association.deprecations().len());
+
+        for (path, note) in association.deprecations() {
+            encoder.string(path);
+            encoder.string(note);
+        }
+
+        encode_length(&mut encoder.bytes, association.locations().len());
+
+        for (path, location) in association.locations() {
+            encoder.string(path);
+            encode_length(&mut encoder.bytes, location.offset());
+            encode_length(&mut encoder.bytes, location.line());
+            encode_length(&mut encoder.bytes, location.column());
+        }
+
+        encoder.node(association.translation_table());
+    }
+
+    encode_length(&mut encoder.bytes, collection.migrations().len());
+
+    for (path, migrated) in collection.migrations() {
+        encoder.string(path);
+        encoder.string(migrated);
+    }
+
+    // The string table goes before the structure referencing it
+    let mut bytes = vec![FORMAT_VERSION];
+    encode_length(&mut bytes, encoder.strings.len());
+
+    for value in &encoder.strings {
+        encode_string(&mut bytes, value);
+    }
+
+    bytes.extend(encoder.bytes);
+    bytes
+}
+
+/// Reads values back from an encoded byte slice
+struct Decoder<'a> {
+    /// Bytes left to decode
+    bytes: &'a [u8],
+    /// Interned string table
+    strings: Vec<&'a str>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Reads a single byte
+    fn byte(&mut self) -> Option<u8> {
+        let (byte, rest) = self.bytes.split_first()?;
+        self.bytes = rest;
+        Some(*byte)
+    }
+
+    /// Reads a LEB128 variable length integer
+    fn length(&mut self) -> Option<usize> {
+        let mut length = 0usize;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.byte()?;
+            length |= usize::from(byte & 0x7f).checked_shl(shift)?;
+
+            if byte & 0x80 == 0 {
+                return Some(length);
+            }
+
+            shift += 7;
+        }
+    }
+
+    /// Reads a zigzag encoded signed integer
+    fn priority(&mut self) -> Option<i64> {
+        let encoded = self.length()? as u64;
+        Some((encoded >> 1) as i64 ^ -((encoded & 1) as i64))
+    }
+
+    /// Reads a length prefixed UTF-8 string
+    fn table_string(&mut self) -> Option<&'a str> {
+        let length = self.length()?;
+
+        if length > self.bytes.len() {
+            return None;
+        }
+
+        let (value, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        std::str::from_utf8(value).ok()
+    }
+
+    /// Reads an interned string by its table index
+    fn string(&mut self) -> Option<String> {
+        let index = self.length()?;
+        self.strings.get(index).map(|value| value.to_string())
+    }
+
+    /// Reads a translation node recursively
+    fn node(&mut self) -> Option<TranslationNode> {
+        match self.byte()? {
+            OBJECT_TAG => {
+                let length = self.length()?;
+                let mut nested = HashMap::with_capacity(length.min(self.bytes.len()));
+
+                for _ in 0..length {
+                    nested.insert(self.string()?, self.node()?);
+                }
+
+                Some(TranslationNode::Object(nested))
+            },
+
+            TRANSLATION_TAG => {
+                let length = self.length()?;
+                let mut translation = HashMap::with_capacity(length.min(self.bytes.len()));
+
+                for _ in 0..length {
+                    translation.insert(self.string()?.parse().ok()?, self.string()?);
+                }
+
+                Some(TranslationNode::Translation(translation))
+            },
+
+            _ => None,
+        }
+    }
+}
+
+/// Decodes a collection of translation files
+///
+/// # Returns
+/// The decoded collection, or `None` if the bytes are malformed
+pub fn decode_collection(bytes: &[u8]) -> Option<TranslationNodeCollection> {
+    let (version, bytes) = bytes.split_first()?;
+
+    if *version != FORMAT_VERSION {
+        return None;
+    }
+
+    let mut decoder = Decoder { bytes, strings: Vec::new() };
+
+    for _ in 0..decoder.length()? {
+        let value = decoder.table_string()?;
+        decoder.strings.push(value);
+    }
+
+    let length = decoder.length()?;
+    let mut translations = Vec::with_capacity(length.min(bytes.len()));
+
+    for _ in 0..length {
+        let original_path = decoder.string()?;
+        let priority = decoder.priority()?;
+        let version = decoder.length()?.checked_sub(1).map(|version| version as u64);
+        let mut deprecations = BTreeMap::new();
+
+        for _ in 0..decoder.length()? {
+            deprecations.insert(decoder.string()?, decoder.string()?);
+        }
+
+        let mut locations = BTreeMap::new();
+
+        for _ in 0..decoder.length()? {
+            let path = decoder.string()?;
+            let location =
+                SourceLocation::new(decoder.length()?, decoder.length()?, decoder.length()?);
+            locations.insert(path, location);
+        }
+
+        translations.push(
+            AssociatedTranslation::new(original_path, decoder.node()?)
+                .with_priority(priority)
+                .with_version(version)
+                .with_deprecations(deprecations)
+                .with_locations(locations),
+        );
+    }
+
+    let mut migrations = BTreeMap::new();
+
+    for _ in 0..decoder.length()? {
+        migrations.insert(decoder.string()?, decoder.string()?);
+    }
+
+    decoder
+        .bytes
+        .is_empty()
+        .then(|| TranslationNodeCollection::new(translations).with_migrations(migrations))
+}
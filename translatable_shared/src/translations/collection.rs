@@ -0,0 +1,674 @@
+//! Ordered collection of loaded translation files
+//!
+//! This module provides the association between a translation structure and
+//! the file it was loaded from, and the collection used for path lookup
+//! across all of them.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::slice::{Iter, IterMut};
+use std::vec::IntoIter;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, quote};
+use toml::{Table, Value};
+
+use super::binary::{decode_collection, encode_collection};
+use super::deprecation::deprecation;
+use super::location::SourceLocation;
+use super::node::{TransformError, TranslationNode, TranslationObject};
+use crate::Language;
+
+/// Top-level key of a translation file setting its lookup priority
+pub const PRIORITY_KEY: &str = "_priority";
+
+/// Top-level key of a translation file declaring its catalog version
+pub const VERSION_KEY: &str = "_version";
+
+/// Compression level for embedded translations, favoring binary size
+#[cfg(feature = "zstd")]
+const COMPRESSION_LEVEL: i32 = 19;
+
+/// Translation association with its source file
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssociatedTranslation {
+    /// Original file path of the translation
+    original_path: String,
+    /// Hierarchical translation data
+    translation_table: TranslationNode,
+    /// Lookup priority, files with a higher one are looked up first
+    priority: i64,
+    /// Catalog version declared by the file, if any
+    version: Option<u64>,
+    /// Note of every deprecated path, the whole file at the empty path
+    deprecations: BTreeMap<String, String>,
+    /// Location of every path as written in the file
+    locations: BTreeMap<String, SourceLocation>,
+}
+
+/// Translation files in lookup priority order
+///
+/// The first translation file containing a path wins, so overlap
+/// resolution is applied by ordering the collection beforehand.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TranslationNodeCollection {
+    /// Translation files in lookup priority order
+    translations: Vec<AssociatedTranslation>,
+    /// New path of every migrated path, consulted by lookups
+    migrations: BTreeMap<String, String>,
+}
+
+/// Lookup precedence between an application and inherited translations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Precedence {
+    /// Application translations are looked up first (default)
+    #[default]
+    Application,
+
+    /// Inherited translations are looked up first
+    Dependency,
+}
+
+impl AssociatedTranslation {
+    /// Creates an association between a translation and its source file
+    pub fn new(original_path: impl Into<String>, translation_table: TranslationNode) -> Self {
+        Self {
+            original_path: original_path.into(),
+            translation_table,
+            priority: 0,
+            version: None,
+            deprecations: BTreeMap::new(),
+            locations: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the lookup priority of the translation file, `0` by default
+    pub fn with_priority(mut self, priority: i64) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Gets the lookup priority of the translation file
+    pub fn priority(&self) -> i64 {
+        self.priority
+    }
+
+    /// Sets the catalog version the translation file declares, as taken by
+    /// [`take_version`]
+    pub fn with_version(mut self, version: Option<u64>) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Gets the catalog version the translation file declares, if any
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// Sets the deprecated paths of the translation file, as taken by
+    /// [`take_deprecations`](super::deprecation::take_deprecations)
+    pub fn with_deprecations(mut self, deprecations: BTreeMap<String, String>) -> Self {
+        self.deprecations = deprecations;
+        self
+    }
+
+    /// Gets the note of every deprecated path of the translation file
+    pub fn deprecations(&self) -> &BTreeMap<String, String> {
+        &self.deprecations
+    }
+
+    /// Sets the location of the paths of the translation file, as parsed by
+    /// [`parse_with_locations`](super::location::parse_with_locations)
+    pub fn with_locations(mut self, locations: BTreeMap<String, SourceLocation>) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Gets the location of every path of the translation file
+    pub fn locations(&self) -> &BTreeMap<String, SourceLocation> {
+        &self.locations
+    }
+
+    /// Gets the location of a dot-separated path in the translation file
+    ///
+    /// # Returns
+    /// The location of the path key, or `None` if the file wasn't parsed
+    /// from TOML or doesn't write the path
+    pub fn location(&self, path: &str) -> Option<&SourceLocation> {
+        self.locations.get(path)
+    }
+
+    /// Mounts the translations under a dot-separated namespace, such as
+    /// `billing`, so `invoice.title` is resolved at `billing.invoice.title`
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        for segment in namespace.rsplit('.') {
+            self.translation_table = TranslationNode::Object(HashMap::from([(
+                segment.to_string(),
+                self.translation_table,
+            )]));
+        }
+
+        self.deprecations = mount_paths(self.deprecations, namespace);
+        self.locations = mount_paths(self.locations, namespace);
+        self
+    }
+
+    /// Gets the original file path of the translation
+    pub fn original_path(&self) -> &str {
+        &self.original_path
+    }
+
+    /// Gets reference to the translation data structure
+    pub fn translation_table(&self) -> &TranslationNode {
+        &self.translation_table
+    }
+
+    /// Gets mutable reference to the translation data structure
+    pub fn translation_table_mut(&mut self) -> &mut TranslationNode {
+        &mut self.translation_table
+    }
+}
+
+impl TranslationNodeCollection {
+    /// Creates a collection from translation files in lookup priority order
+    pub fn new(translations: Vec<AssociatedTranslation>) -> Self {
+        Self {
+            translations,
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the paths migrated to other ones, such as renamed keys, which
+    /// lookups resolve to their new path
+    pub fn with_migrations(mut self, migrations: BTreeMap<String, String>) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
+    /// Mounts the translation files under a dot-separated namespace, such as
+    /// `billing`, along with their migrations
+    pub fn with_namespace(self, namespace: &str) -> Self {
+        Self {
+            translations: self
+                .translations
+                .into_iter()
+                .map(|association| association.with_namespace(namespace))
+                .collect(),
+            migrations: self
+                .migrations
+                .into_iter()
+                .map(|(migrated, new)| {
+                    (mount_path(&migrated, namespace), mount_path(&new, namespace))
+                })
+                .collect(),
+        }
+    }
+
+    /// Gets the new path of every migrated path
+    pub fn migrations(&self) -> &BTreeMap<String, String> {
+        &self.migrations
+    }
+
+    /// Gets the path a dot-separated path is looked up at, its new path if
+    /// it was migrated
+    pub fn migrate<'a>(&'a self, path: &'a str) -> &'a str {
+        self.migrations.get(path).map_or(path, String::as_str)
+    }
+
+    /// Inherits the translation files of a dependency crate
+    ///
+    /// Libraries export their catalog, usually embedded with
+    /// `include_translation_file!`, so applications resolve both their own
+    /// paths and the library ones.
+    ///
+    /// # Arguments
+    /// * `dependency` - Translations exported by the dependency
+    /// * `precedence` - Which translations win when both contain a path
+    pub fn inherit(
+        mut self,
+        dependency: TranslationNodeCollection,
+        precedence: Precedence,
+    ) -> Self {
+        match precedence {
+            Precedence::Application => self.translations.extend(dependency.translations),
+            Precedence::Dependency => {
+                let mut translations = dependency.translations;
+                translations.append(&mut self.translations);
+                self.translations = translations;
+            },
+        }
+
+        // The application migrations win over the dependency ones
+        for (path, migrated) in dependency.migrations {
+            self.migrations.entry(path).or_insert(migrated);
+        }
+
+        self
+    }
+
+    /// Encodes the collection in the compact binary embedding format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_collection(self)
+    }
+
+    /// Decodes a collection from the compact binary embedding format
+    ///
+    /// # Returns
+    /// The decoded collection, or `None` if the bytes are malformed
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        decode_collection(bytes)
+    }
+
+    /// Encodes the collection in the binary embedding format compressed
+    /// with zstd
+    #[cfg(feature = "zstd")]
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        zstd::encode_all(self.to_bytes().as_slice(), COMPRESSION_LEVEL)
+            .expect("compressing to memory can't fail")
+    }
+
+    /// Decodes a collection from the binary embedding format compressed
+    /// with zstd
+    ///
+    /// # Returns
+    /// The decoded collection, or `None` if the bytes are malformed
+    #[cfg(feature = "zstd")]
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes(&zstd::decode_all(bytes).ok()?)
+    }
+
+    /// Iterates over the translation files in lookup priority order
+    pub fn iter(&self) -> Iter<'_, AssociatedTranslation> {
+        self.translations.iter()
+    }
+
+    /// Iterates over the translation files in lookup priority order for
+    /// mutation
+    pub fn iter_mut(&mut self) -> IterMut<'_, AssociatedTranslation> {
+        self.translations.iter_mut()
+    }
+
+    /// Gets the number of translation files
+    pub fn len(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Checks whether the collection has no translation files
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+
+    /// Collects the dot-separated paths of the translation objects of every
+    /// translation file, sorted and without duplicates
+    pub fn keys(&self) -> Vec<String> {
+        self.translations
+            .iter()
+            .flat_map(|association| association.translation_table().objects())
+            .map(|(path, _)| path)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Resolves a dot-separated translation path in the first translation
+    /// file containing it
+    ///
+    /// Migrated paths are resolved at their new path.
+    ///
+    /// # Returns
+    /// Reference to translations if path exists and points to leaf node
+    pub fn find_path(&self, path: &str) -> Option<&HashMap<Language, String>> {
+        self.find_path_with_source(path).map(|(translation_object, _)| translation_object)
+    }
+
+    /// Resolves a dot-separated translation path like
+    /// [`find_path`](Self::find_path), along with the original path of the
+    /// translation file it was found in
+    ///
+    /// # Returns
+    /// Reference to translations and the file that won the overlap
+    /// resolution, if path exists and points to leaf node
+    pub fn find_path_with_source(&self, path: &str) -> Option<(&HashMap<Language, String>, &str)> {
+        let path = self.migrate(path);
+
+        self.translations.iter().find_map(|association| {
+            association
+                .translation_table()
+                .get_path(path.split('.').collect())
+                .map(|translation_object| (translation_object, association.original_path()))
+        })
+    }
+
+    /// Gets the note deprecating a dot-separated path, in the translation file
+    /// it is resolved from
+    ///
+    /// # Returns
+    /// The note of the path or of its closest deprecated nesting, if any
+    pub fn find_deprecation(&self, path: &str) -> Option<&str> {
+        self.translations
+            .iter()
+            .find(|association| {
+                association.translation_table().get_path(path.split('.').collect()).is_some()
+            })
+            .and_then(|association| deprecation(association.deprecations(), path))
+    }
+
+    /// Collects the translation objects whose dot-separated path matches a
+    /// pattern, such as `errors.*.title`
+    ///
+    /// A `*` segment matches any single segment, a `**` segment any number
+    /// of them, none included, and `*` inside a segment any characters, such
+    /// as `*_title`. Paths are resolved like with
+    /// [`find_path`](Self::find_path), the first file containing them
+    /// winning.
+    ///
+    /// # Returns
+    /// The matching translation objects with their full paths, sorted by path
+    pub fn find_matching(&self, pattern: &str) -> Vec<(String, &TranslationObject)> {
+        let pattern = pattern.split('.').collect::<Vec<_>>();
+        let mut matching = BTreeMap::new();
+
+        for association in &self.translations {
+            for (path, translation_object) in association.translation_table().objects() {
+                if path_matches(&pattern, &path.split('.').collect::<Vec<_>>()) {
+                    matching.entry(path).or_insert(translation_object);
+                }
+            }
+        }
+
+        matching.into_iter().collect()
+    }
+
+    /// Resolves the location of a dot-separated translation path in the
+    /// first translation file containing it, like
+    /// [`find_path_with_source`](Self::find_path_with_source)
+    ///
+    /// # Returns
+    /// The original path of the file that won the overlap resolution and
+    /// the location the path is written at, if it is located
+    pub fn find_location(&self, path: &str) -> Option<(&str, &SourceLocation)> {
+        let path = self.migrate(path);
+
+        self.translations
+            .iter()
+            .find(|association| {
+                association.translation_table().get_path(path.split('.').collect()).is_some()
+            })
+            .and_then(|association| {
+                Some((association.original_path(), association.location(path)?))
+            })
+    }
+
+    /// Collects the original paths of every translation file with a
+    /// translation object at a dot-separated path, along with the location
+    /// the path is written at in each of them, in lookup priority order
+    pub fn path_locations(&self, path: &str) -> Vec<(&str, Option<&SourceLocation>)> {
+        self.translations
+            .iter()
+            .filter(|association| {
+                association.translation_table().get_path(path.split('.').collect()).is_some()
+            })
+            .map(|association| (association.original_path(), association.location(path)))
+            .collect()
+    }
+
+    /// Collects the original paths of every translation file with a
+    /// translation object at a dot-separated path, in lookup priority order
+    pub fn path_sources(&self, path: &str) -> Vec<&str> {
+        self.translations
+            .iter()
+            .filter(|association| {
+                association.translation_table().get_path(path.split('.').collect()).is_some()
+            })
+            .map(AssociatedTranslation::original_path)
+            .collect()
+    }
+
+    /// Collects the nested objects at a dot-separated path from every
+    /// translation file containing it, preserving lookup priority order
+    ///
+    /// # Returns
+    /// Collection of subtrees, or `None` if no file has a nested object at
+    /// the path
+    pub fn subtree(&self, path: &str) -> Option<Self> {
+        let subtrees = self
+            .translations
+            .iter()
+            .filter_map(|association| {
+                match association.translation_table().get_node(path.split('.').collect()) {
+                    Some(node @ TranslationNode::Object(_)) => Some(
+                        AssociatedTranslation::new(association.original_path(), node.clone())
+                            .with_priority(association.priority())
+                            .with_version(association.version())
+                            .with_deprecations(subtree_deprecations(association, path))
+                            .with_locations(subtree_locations(association, path)),
+                    ),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Migrations within the path are kept relative to it
+        let migrations = self
+            .migrations
+            .iter()
+            .filter_map(|(migrated, new)| {
+                Some((
+                    nested_path(migrated, path)?.to_string(),
+                    nested_path(new, path)?.to_string(),
+                ))
+            })
+            .collect();
+
+        (!subtrees.is_empty()).then(|| Self::new(subtrees).with_migrations(migrations))
+    }
+}
+
+impl IntoIterator for TranslationNodeCollection {
+    type Item = AssociatedTranslation;
+    type IntoIter = IntoIter<AssociatedTranslation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.translations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TranslationNodeCollection {
+    type Item = &'a AssociatedTranslation;
+    type IntoIter = Iter<'a, AssociatedTranslation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.translations.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut TranslationNodeCollection {
+    type Item = &'a mut AssociatedTranslation;
+    type IntoIter = IterMut<'a, AssociatedTranslation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.translations.iter_mut()
+    }
+}
+
+impl FromIterator<AssociatedTranslation> for TranslationNodeCollection {
+    fn from_iter<T: IntoIterator<Item = AssociatedTranslation>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Gets the path a dot-separated path is at once mounted under a namespace,
+/// the namespace itself for the empty path
+fn mount_path(path: &str, namespace: &str) -> String {
+    if path.is_empty() { namespace.to_string() } else { format!("{namespace}.{path}") }
+}
+
+/// Mounts the keys of a map of dot-separated paths under a namespace
+fn mount_paths<T>(paths: BTreeMap<String, T>, namespace: &str) -> BTreeMap<String, T> {
+    paths.into_iter().map(|(path, value)| (mount_path(&path, namespace), value)).collect()
+}
+
+/// Gets a dot-separated path relative to a nesting, if it's under it
+fn nested_path<'a>(full: &'a str, nesting: &str) -> Option<&'a str> {
+    full.strip_prefix(nesting)?.strip_prefix('.')
+}
+
+/// Gets the deprecations of a translation file relative to a nesting, the
+/// ones of the nesting itself and its parents at the empty path
+fn subtree_deprecations(
+    association: &AssociatedTranslation,
+    path: &str,
+) -> BTreeMap<String, String> {
+    let mut deprecations = association
+        .deprecations()
+        .iter()
+        .filter_map(|(deprecated, note)| {
+            Some((nested_path(deprecated, path)?.to_string(), note.clone()))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    if let Some(note) = deprecation(association.deprecations(), path) {
+        deprecations.insert(String::new(), note.to_string());
+    }
+
+    deprecations
+}
+
+/// Gets the locations of a translation file relative to a nesting
+fn subtree_locations(
+    association: &AssociatedTranslation,
+    path: &str,
+) -> BTreeMap<String, SourceLocation> {
+    association
+        .locations()
+        .iter()
+        .filter_map(|(located, location)| {
+            Some((nested_path(located, path)?.to_string(), *location))
+        })
+        .collect()
+}
+
+/// Checks whether the segments of a path match the ones of a pattern
+fn path_matches(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (Some((&"**", rest)), _) => {
+            path_matches(rest, path) || (!path.is_empty() && path_matches(pattern, &path[1..]))
+        },
+        (Some((expected, pattern)), Some((segment, path))) => {
+            segment_matches(expected, segment) && path_matches(pattern, path)
+        },
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Checks whether a path segment matches a pattern segment, whose `*`
+/// match any characters
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == segment;
+    };
+
+    let Some(segment) = segment.strip_prefix(prefix) else { return false };
+
+    // Every position the rest of the pattern could start at
+    (0..=segment.len())
+        .filter(|index| segment.is_char_boundary(*index))
+        .any(|index| segment_matches(rest, &segment[index..]))
+}
+
+/// Removes the `_priority` key from the table of a translation file
+///
+/// Translation files are ordered by path, the priority overrides that order
+/// for overlap resolution, files with a higher priority being looked up
+/// first. Files without the key have a priority of `0`.
+///
+/// # Returns
+/// - `Ok(i64)` with the priority of the file
+/// - `Err(TransformError)` if the priority is not an integer
+pub fn take_priority(table: &mut Table) -> Result<i64, TransformError> {
+    match table.remove(PRIORITY_KEY) {
+        Some(Value::Integer(priority)) => Ok(priority),
+        Some(_) => Err(TransformError::InvalidPriority),
+        None => Ok(0),
+    }
+}
+
+/// Removes the `_version` key from the table of a translation file
+///
+/// Shared translation repositories declare the version of their catalog,
+/// so consumers can require a minimum one. Files without the key aren't
+/// versioned.
+///
+/// # Returns
+/// - `Ok(Option<u64>)` with the version of the file, if declared
+/// - `Err(TransformError)` if the version is not a non-negative integer
+pub fn take_version(table: &mut Table) -> Result<Option<u64>, TransformError> {
+    match table.remove(VERSION_KEY) {
+        Some(Value::Integer(version)) => {
+            u64::try_from(version).map(Some).map_err(|_| TransformError::InvalidVersion)
+        },
+        Some(_) => Err(TransformError::InvalidVersion),
+        None => Ok(None),
+    }
+}
+
+/// Orders translation files by descending priority
+///
+/// The sort is stable, so files with the same priority keep their order.
+pub fn sort_by_priority(translations: &mut [AssociatedTranslation]) {
+    translations.sort_by_key(|translation| std::cmp::Reverse(translation.priority()));
+}
+
+impl ToTokens for AssociatedTranslation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let original_path = &self.original_path;
+        let translation_table = &self.translation_table;
+        let priority = self.priority;
+        let version = match self.version {
+            Some(version) => quote!(Some(#version)),
+            None => quote!(None),
+        };
+        let (paths, notes): (Vec<_>, Vec<_>) = self.deprecations.iter().unzip();
+        let locations = self.locations.iter().map(|(path, location)| {
+            let (offset, line, column) = (location.offset(), location.line(), location.column());
+            quote! { (#path.to_string(), translatable::SourceLocation::new(#offset, #line, #column)) }
+        });
+
+        tokens.extend(quote! {
+            translatable::AssociatedTranslation::new(#original_path, #translation_table)
+                .with_priority(#priority)
+                .with_version(#version)
+                .with_deprecations(vec![#((#paths.to_string(), #notes.to_string())),*].into_iter().collect())
+                .with_locations(vec![#(#locations),*].into_iter().collect())
+        });
+    }
+}
+
+impl ToTokens for TranslationNodeCollection {
+    /// Embeds the collection as a compact byte string, decoded at runtime
+    /// the first time the expression is evaluated
+    ///
+    /// The decoded collection is kept in a static local to the expression,
+    /// which every evaluation clones. With the `zstd` feature the byte string
+    /// is compressed as well.
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        #[cfg(not(feature = "zstd"))]
+        let (bytes, decode) = (self.to_bytes(), quote!(from_bytes));
+        #[cfg(feature = "zstd")]
+        let (bytes, decode) = (self.to_compressed_bytes(), quote!(from_compressed_bytes));
+
+        let bytes = Literal::byte_string(&bytes);
+
+        tokens.extend(quote! {{
+            #[doc(hidden)]
+            static EMBEDDED: std::sync::LazyLock<translatable::TranslationNodeCollection> =
+                std::sync::LazyLock::new(|| {
+                    translatable::internal::embedded_translations(
+                        translatable::TranslationNodeCollection::#decode(#bytes)
+                    )
+                });
+
+            translatable::TranslationNodeCollection::clone(&EMBEDDED)
+        }});
+    }
+}
@@ -0,0 +1,176 @@
+//! Feature gated and platform specific translations
+//!
+//! This module applies the `_cfg` key of translation file tables, which
+//! gates a nesting, a translation object or a whole file behind a `cfg`
+//! predicate on cargo features, such as `_cfg = "feature = \"premium\""`.
+//! Gated tables of disabled predicates are removed before the translations
+//! are validated, so they are neither validated nor embedded.
+//!
+//! It also selects the target operating system variants of translation
+//! objects, such as the `macos` table of a keyboard shortcut.
+
+use proc_macro2::{TokenStream, TokenTree};
+use toml::{Table, Value};
+
+use super::node::TransformError;
+
+/// Key of a translation file table gating it behind a `cfg` predicate
+pub const CFG_KEY: &str = "_cfg";
+
+/// Applies the `_cfg` predicates of a translation file table
+///
+/// The tables whose predicate is disabled are removed, along with the
+/// nestings left empty, and the `_cfg` keys of the enabled ones are removed
+/// so the table can be converted to a [`TranslationNode`].
+///
+/// Predicates are `feature = "name"`, combined with `all(..)`, `any(..)` and
+/// `not(..)`. Feature names are compared like cargo compares them in
+/// `CARGO_FEATURE_<NAME>` variables, case insensitively and with `-` and `_`
+/// being the same.
+///
+/// # Arguments
+/// * `table` - TOML table of the translation file
+/// * `features` - Enabled cargo features, `None` keeps every table while still
+///   validating the predicates
+///
+/// # Returns
+/// - `Ok(true)` if the table is kept, `Ok(false)` if the file itself is gated
+///   behind a disabled predicate
+/// - `Err(TransformError)` if a predicate is not valid
+///
+/// [`TranslationNode`]: super::node::TranslationNode
+pub fn apply_conditions(
+    table: &mut Table,
+    features: Option<&[String]>,
+) -> Result<bool, TransformError> {
+    if let Some(predicate) = table.remove(CFG_KEY) {
+        let Value::String(predicate) = predicate else {
+            return Err(TransformError::InvalidCondition(predicate.to_string()));
+        };
+
+        let tokens = predicate
+            .parse::<TokenStream>()
+            .map_err(|_| TransformError::InvalidCondition(predicate.clone()))?
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let enabled = evaluate(&tokens, features.unwrap_or_default())
+            .ok_or(TransformError::InvalidCondition(predicate))?;
+
+        if !enabled && features.is_some() {
+            return Ok(false);
+        }
+    }
+
+    let mut removed = Vec::new();
+
+    for (key, value) in table.iter_mut() {
+        if let Value::Table(nested) = value {
+            let was_empty = nested.is_empty();
+
+            if !apply_conditions(nested, features)? || (!was_empty && nested.is_empty()) {
+                removed.push(key.clone());
+            }
+        }
+    }
+
+    for key in removed {
+        table.remove(&key);
+    }
+
+    Ok(true)
+}
+
+/// Evaluates a `cfg` predicate against the enabled features
+///
+/// # Returns
+/// Whether the predicate holds, or `None` if it is not valid
+fn evaluate(tokens: &[TokenTree], features: &[String]) -> Option<bool> {
+    match tokens {
+        [TokenTree::Ident(key), TokenTree::Punct(equals), TokenTree::Literal(name)]
+            if key == "feature" && equals.as_char() == '=' =>
+        {
+            let name = name.to_string();
+            let name = feature_name(name.strip_prefix('"')?.strip_suffix('"')?);
+
+            Some(features.iter().any(|feature| feature_name(feature) == name))
+        },
+
+        [TokenTree::Ident(operator), TokenTree::Group(arguments)] => {
+            let arguments = arguments.stream().into_iter().collect::<Vec<_>>();
+            let arguments = arguments
+                .split(|token| matches!(token, TokenTree::Punct(comma) if comma.as_char() == ','))
+                .filter(|argument| !argument.is_empty())
+                .map(|argument| evaluate(argument, features))
+                .collect::<Option<Vec<_>>>()?;
+
+            match (operator.to_string().as_str(), arguments.as_slice()) {
+                ("all", arguments) => Some(arguments.iter().all(|enabled| *enabled)),
+                ("any", arguments) => Some(arguments.iter().any(|enabled| *enabled)),
+                ("not", [enabled]) => Some(!enabled),
+                _ => None,
+            }
+        },
+
+        _ => None,
+    }
+}
+
+/// Normalizes a feature name the way cargo does for its variables
+fn feature_name(name: &str) -> String {
+    name.to_lowercase().replace('-', "_")
+}
+
+/// Target operating systems translation objects can have variants for,
+/// named like the `target_os` values of `cfg`
+pub const TARGET_OS_VARIANTS: [&str; 10] = [
+    "android",
+    "dragonfly",
+    "freebsd",
+    "ios",
+    "linux",
+    "macos",
+    "netbsd",
+    "openbsd",
+    "wasi",
+    "windows",
+];
+
+/// Selects the target operating system variants of the translation objects
+/// of a translation file table
+///
+/// A translation object can hold a table per target operating system, such
+/// as `macos.en = "Press Cmd+C"`, whose languages replace the ones of the
+/// translation object when building for that system. The variant tables are
+/// removed either way, the ones of other systems without being validated.
+///
+/// # Arguments
+/// * `table` - TOML table of the translation file
+/// * `target_os` - Operating system the translations are selected for, as in
+///   `cfg(target_os = "..")`
+pub fn apply_target_variants(table: &mut Table, target_os: &str) {
+    // Only translation objects have strings, nestings can be named like systems
+    if table.values().all(Value::is_table) {
+        for (_, value) in table.iter_mut() {
+            if let Value::Table(nested) = value {
+                apply_target_variants(nested, target_os);
+            }
+        }
+
+        return;
+    }
+
+    let variants = TARGET_OS_VARIANTS
+        .iter()
+        .filter_map(|variant| match table.get(*variant) {
+            Some(Value::Table(_)) => table.remove(*variant).map(|value| (*variant, value)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (variant, value) in variants {
+        if let (true, Value::Table(languages)) = (variant == target_os, value) {
+            table.extend(languages);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Deprecated translation paths
+//!
+//! This module takes the `_deprecated` key of translation file tables, such
+//! as `_deprecated = "use common.greeting_v2"`, so the macros can warn the
+//! call sites still referencing a path while a catalog is being refactored.
+
+use std::collections::BTreeMap;
+
+use toml::{Table, Value};
+
+use super::node::TransformError;
+
+/// Key of a translation file table deprecating it, holding the note shown at
+/// the call sites, such as the path to use instead
+pub const DEPRECATED_KEY: &str = "_deprecated";
+
+/// Removes the `_deprecated` keys of a translation file table
+///
+/// A key deprecates the translation object holding it, or every path under
+/// the nesting or file holding it.
+///
+/// # Returns
+/// - `Ok(BTreeMap)` with the note of every deprecated path, the whole file
+///   being deprecated at the empty path
+/// - `Err(TransformError)` if a `_deprecated` key is not a string
+pub fn take_deprecations(table: &mut Table) -> Result<BTreeMap<String, String>, TransformError> {
+    let mut deprecations = BTreeMap::new();
+    collect_deprecations(table, "", &mut deprecations)?;
+    Ok(deprecations)
+}
+
+/// Removes the `_deprecated` keys of a table and its nestings, recursively
+fn collect_deprecations(
+    table: &mut Table,
+    path: &str,
+    deprecations: &mut BTreeMap<String, String>,
+) -> Result<(), TransformError> {
+    match table.remove(DEPRECATED_KEY) {
+        Some(Value::String(note)) => {
+            deprecations.insert(path.to_string(), note);
+        },
+        Some(_) => return Err(TransformError::InvalidDeprecation),
+        None => {},
+    }
+
+    for (key, value) in table.iter_mut() {
+        if let Value::Table(nested) = value {
+            let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+            collect_deprecations(nested, &path, deprecations)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the note deprecating a path, the one of the closest deprecated
+/// nesting if the path itself isn't
+pub fn deprecation<'a>(deprecations: &'a BTreeMap<String, String>, path: &str) -> Option<&'a str> {
+    let mut prefix = path;
+
+    loop {
+        if let Some(note) = deprecations.get(prefix) {
+            return Some(note);
+        }
+
+        if prefix.is_empty() {
+            return None;
+        }
+
+        prefix = prefix.rsplit_once('.').map_or("", |(parent, _)| parent);
+    }
+}
@@ -0,0 +1,52 @@
+//! Template filter pipes
+//!
+//! This module parses the filters of templates such as `{name|upper}` or
+//! `{name|lower|capitalize}`, which are applied to the argument value before
+//! it's written. The filters themselves are resolved by the runtime crate.
+
+/// Splits a template into its argument name and its filter pipes
+///
+/// # Returns
+/// The trimmed argument name, and the filters after the first `|` if any
+pub fn split_filters(template: &str) -> (&str, Option<&str>) {
+    match template.split_once('|') {
+        Some((name, filters)) => (name.trim(), Some(filters)),
+        None => (template, None),
+    }
+}
+
+/// Iterates over the names of some filter pipes, such as `"lower|capitalize"`
+pub fn filter_names(filters: &str) -> impl Iterator<Item = &str> {
+    filters.split('|').map(str::trim).filter(|filter| !filter.is_empty())
+}
+
+/// Collects the arguments of the templates with filters in a translation
+///
+/// Escaped templates (`{{name|upper}}`) and plural or select block headers
+/// are skipped.
+pub fn filter_arguments(translation: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut rest = translation;
+
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start..];
+
+        if let Some(escaped) = rest.strip_prefix("{{") {
+            rest = escaped;
+            continue;
+        }
+
+        let Some(end) = rest.find('}') else { break };
+        let template = &rest[1..end];
+
+        if let (name, Some(_)) = split_filters(template)
+            && !template.contains('{')
+        {
+            arguments.push(name.to_string());
+        }
+
+        rest = &rest[1..];
+    }
+
+    arguments
+}
@@ -0,0 +1,217 @@
+//! Quality lints over translation catalogs
+//!
+//! This module finds the translations likely to be mistakes, such as double
+//! spaces or a question translated to a statement, so they can be reported
+//! as compile warnings before reaching a reviewer.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use strum::{EnumIter, EnumString, IntoStaticStr};
+
+use super::collection::TranslationNodeCollection;
+use super::message::{has_choices, templates};
+use super::random::{has_random_entries, random_entries};
+use crate::Language;
+
+/// Quality lint over the translations of a catalog
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Lint {
+    /// Translations with two or more consecutive spaces
+    DoubleSpaces,
+
+    /// Translations with whitespace at the end of a line
+    TrailingWhitespace,
+
+    /// Translations ending with other punctuation than the source language
+    /// one, such as a question translated without a question mark
+    TerminalPunctuation,
+
+    /// Translations identical to the source language one
+    Untranslated,
+
+    /// Translations made of templates only, such as `"{name} "`
+    PlaceholderOnly,
+}
+
+impl Lint {
+    /// Gets the name of the lint, as configured
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Describes the issue the lint found
+    fn description(&self) -> &'static str {
+        match self {
+            Self::DoubleSpaces => "has double spaces",
+            Self::TrailingWhitespace => "has trailing whitespace",
+            Self::TerminalPunctuation => {
+                "ends with other punctuation than the source language translation"
+            },
+            Self::Untranslated => "is a copy of the source language translation",
+            Self::PlaceholderOnly => "only has templates",
+        }
+    }
+}
+
+/// Translation a lint was found in
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintFinding {
+    /// Lint found
+    lint: Lint,
+    /// Dot-separated path of the translation
+    path: String,
+    /// Language of the translation
+    language: Language,
+    /// Original path of the file the translation is resolved from
+    file: String,
+}
+
+impl LintFinding {
+    /// Gets the lint found
+    pub fn lint(&self) -> Lint {
+        self.lint
+    }
+
+    /// Gets the dot-separated path of the translation
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Gets the language of the translation
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Gets the original path of the file the translation is resolved from
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+}
+
+/// Writes the lint and the location of the translation
+impl Display for LintFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}: the '{}' translation of '{}' {}, in {}",
+            self.lint.name(),
+            self.language.code(),
+            self.path,
+            self.lint.description(),
+            self.file
+        )
+    }
+}
+
+/// Finds the translations of a catalog some lints apply to
+///
+/// Paths are linted in the translation they are looked up to, the first
+/// file containing a path winning. Random entries are linted one by one,
+/// and aren't compared with the source language.
+///
+/// # Arguments
+/// * `collection` - Catalog to lint
+/// * `source` - Language the other ones are translated from
+/// * `lints` - Lints to look for
+///
+/// # Returns
+/// The findings, sorted by path and language code
+pub fn lint_catalog(
+    collection: &TranslationNodeCollection,
+    source: &Language,
+    lints: &[Lint],
+) -> Vec<LintFinding> {
+    // The first file containing a path wins
+    let mut entries = BTreeMap::new();
+
+    for association in collection {
+        for (path, translations) in association.translation_table().objects() {
+            entries.entry(path).or_insert((association.original_path(), translations));
+        }
+    }
+
+    let mut findings = Vec::new();
+
+    for (path, (file, translations)) in entries {
+        let mut languages = translations.iter().collect::<Vec<_>>();
+        languages.sort_by_key(|(language, _)| language.code());
+
+        for (language, translation) in languages {
+            let original =
+                translations.get(source).filter(|_| language != source).filter(|original| {
+                    !has_random_entries(original) && !has_random_entries(translation)
+                });
+
+            for lint in lints {
+                let found = match (lint, original) {
+                    (Lint::TerminalPunctuation, Some(original)) => {
+                        terminal_punctuation(original) != terminal_punctuation(translation)
+                    },
+                    (Lint::Untranslated, Some(original)) => {
+                        original == translation && translation.chars().any(char::is_alphabetic)
+                    },
+                    (Lint::TerminalPunctuation | Lint::Untranslated, None) => false,
+                    (lint, _) => random_entries(translation).any(|entry| lint_text(*lint, entry)),
+                };
+
+                if found {
+                    findings.push(LintFinding {
+                        lint: *lint,
+                        path: path.clone(),
+                        language: language.clone(),
+                        file: file.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    findings
+}
+
+/// Checks whether a lint about the text itself applies to a translation
+fn lint_text(lint: Lint, translation: &str) -> bool {
+    match lint {
+        Lint::DoubleSpaces => translation.contains("  "),
+        Lint::TrailingWhitespace => translation.lines().any(|line| line != line.trim_end()),
+        // The arms of blocks are text
+        Lint::PlaceholderOnly if has_choices(translation) => false,
+        Lint::PlaceholderOnly => {
+            let mut nestings = 0;
+            let mut text = translation.chars().filter(|character| {
+                match character {
+                    '{' => nestings += 1,
+                    '}' => nestings -= 1,
+                    _ => return nestings == 0,
+                }
+
+                false
+            });
+
+            !templates(translation).is_empty() && !text.any(char::is_alphanumeric)
+        },
+        Lint::TerminalPunctuation | Lint::Untranslated => false,
+    }
+}
+
+/// Gets the terminal punctuation of a translation, the full width and
+/// script specific marks being the same as their ASCII counterparts
+fn terminal_punctuation(translation: &str) -> Option<char> {
+    let translation = translation.trim_end().trim_end_matches(['"', '\'', '»', '”', '’', ')']);
+
+    if translation.ends_with("...") {
+        return Some('…');
+    }
+
+    match translation.chars().last()? {
+        '.' | '。' | '।' | '۔' => Some('.'),
+        '!' | '！' => Some('!'),
+        '?' | '？' | '؟' => Some('?'),
+        ':' | '：' => Some(':'),
+        ';' | '；' => Some(';'),
+        '…' => Some('…'),
+        _ => None,
+    }
+}
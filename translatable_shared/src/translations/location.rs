@@ -0,0 +1,123 @@
+//! Source locations of translation file entries
+//!
+//! This module parses translation files with the span preserving `toml_edit`
+//! parser, so every entry keeps the offset it is written at in its file, for
+//! diagnostics pointing at the TOML line and provenance lookups.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use toml::Table;
+use toml::de::Error as TomlError;
+use toml_edit::{ImDocument, TableLike};
+
+/// Location of an entry in its translation file
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceLocation {
+    /// Byte offset of the entry key
+    offset: usize,
+    /// Line of the entry key, starting at 1
+    line: usize,
+    /// Column of the entry key in characters, starting at 1
+    column: usize,
+}
+
+impl SourceLocation {
+    /// Creates a location from its byte offset, line and column
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self { offset, line, column }
+    }
+
+    /// Locates a byte offset in the content of a file
+    ///
+    /// Offsets past the content or inside a character are clamped to the
+    /// previous character.
+    pub fn of_offset(content: &str, offset: usize) -> Self {
+        let mut offset = offset.min(content.len());
+        while !content.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &content[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Gets the byte offset of the entry key
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Gets the line of the entry key, starting at 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the column of the entry key in characters, starting at 1
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+/// Writes the location as `line:column`
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Parses the content of a translation file along with the location of its
+/// entries
+///
+/// Every key is located at its dot-separated path as written in the file,
+/// nestings and languages included, such as `greeting` and `greeting.en`,
+/// before any key is taken or variant applied.
+///
+/// # Returns
+/// - `Ok((Table, BTreeMap))` with the table and the location of every path
+/// - `Err(TomlError)` if the content isn't valid TOML
+pub fn parse_with_locations(
+    content: &str,
+) -> Result<(Table, BTreeMap<String, SourceLocation>), TomlError> {
+    // Errors are reported by the `toml` parser, with its messages
+    let Ok(document) = ImDocument::parse(content.to_string()) else {
+        return content.parse::<Table>().map(|table| (table, BTreeMap::new()));
+    };
+
+    let mut locations = BTreeMap::new();
+    collect_locations(content, document.as_table(), "", &mut locations);
+
+    match toml_edit::de::from_document::<Table>(document) {
+        Ok(table) => Ok((table, locations)),
+        Err(_) => content.parse::<Table>().map(|table| (table, locations)),
+    }
+}
+
+/// Collects the location of the keys of a table and its nestings,
+/// recursively
+fn collect_locations(
+    content: &str,
+    table: &dyn TableLike,
+    path: &str,
+    locations: &mut BTreeMap<String, SourceLocation>,
+) {
+    for (key, item) in table.iter() {
+        let path = if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+
+        let span =
+            table.get_key_value(key).and_then(|(key, item)| key.span().or_else(|| item.span()));
+
+        if let Some(span) = span {
+            locations.insert(path.clone(), SourceLocation::of_offset(content, span.start));
+        }
+
+        if let Some(nested) = item.as_table_like() {
+            collect_locations(content, nested, &path, locations);
+        }
+    }
+}
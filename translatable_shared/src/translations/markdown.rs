@@ -0,0 +1,100 @@
+//! Markdown rendering of translations
+//!
+//! This module renders translations authored in Markdown, such as help texts
+//! or rich tooltips, to sanitized HTML. Raw HTML in the Markdown is escaped
+//! and links to other schemes than `http`, `https`, `mailto` and `tel` are
+//! dropped, so the rendered HTML only has the markup Markdown produces.
+//!
+//! Templates are kept as they are, so their values are replaced after the
+//! translation is rendered.
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, html};
+
+use super::random::{ENTRY_SEPARATOR, random_entries};
+
+/// Link schemes kept in the rendered HTML
+const SAFE_SCHEMES: [&str; 4] = ["http", "https", "mailto", "tel"];
+
+/// Renders a Markdown translation to sanitized HTML
+///
+/// Translations rendering to a single paragraph aren't wrapped in `<p>`, so
+/// they can be used inline, and random entries are rendered one by one.
+///
+/// # Example
+/// ```
+/// use translatable_shared::render_markdown;
+///
+/// assert_eq!(render_markdown("Hello **{name}**!"), "Hello <strong>{name}</strong>!");
+/// assert_eq!(render_markdown("<script>"), "&lt;script&gt;");
+/// ```
+pub fn render_markdown(translation: &str) -> String {
+    random_entries(translation)
+        .map(render_entry)
+        .collect::<Vec<_>>()
+        .join(&ENTRY_SEPARATOR.to_string())
+}
+
+/// Renders a single Markdown entry
+fn render_entry(markdown: &str) -> String {
+    let events =
+        Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH).map(|event| match event {
+            // Raw HTML is written as text
+            Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+
+            // Links are written by hand, as templates in their destination would be
+            // percent encoded otherwise
+            Event::Start(Tag::Link { dest_url, title, .. }) => {
+                let title = if title.is_empty() {
+                    String::new()
+                } else {
+                    format!(" title=\"{}\"", escape_attribute(&title))
+                };
+
+                Event::Html(CowStr::from(format!(
+                    "<a href=\"{}\"{title}>",
+                    escape_attribute(safe_url(&dest_url))
+                )))
+            },
+
+            Event::Start(Tag::Image { link_type, dest_url, title, id }) => {
+                Event::Start(Tag::Image {
+                    link_type,
+                    dest_url: CowStr::from(safe_url(&dest_url).to_string()),
+                    title,
+                    id,
+                })
+            },
+
+            event => event,
+        });
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, events);
+    let rendered = rendered.trim_end();
+
+    match rendered.strip_prefix("<p>").and_then(|inner| inner.strip_suffix("</p>")) {
+        Some(inner) if !inner.contains("<p>") => inner.to_string(),
+        _ => rendered.to_string(),
+    }
+}
+
+/// Gets the destination of a link if its scheme is safe, `#` otherwise
+///
+/// Relative destinations and destinations starting with a template are
+/// kept, the values of templates being escaped when replaced.
+fn safe_url(url: &str) -> &str {
+    let scheme = url
+        .split_once(':')
+        .map(|(scheme, _)| scheme)
+        .filter(|scheme| !scheme.is_empty() && !scheme.contains(['/', '?', '#', '{']));
+
+    match scheme {
+        Some(scheme) if !SAFE_SCHEMES.contains(&scheme.to_lowercase().as_str()) => "#",
+        _ => url,
+    }
+}
+
+/// Escapes the characters of an HTML attribute value
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,588 @@
+//! Plural and select messages
+//!
+//! This module parses the subset of the ICU MessageFormat syntax supported in
+//! translations, `{count, plural, one {# item} other {# items}}` and
+//! `{gender, select, female {her} other {their}}` blocks, along with
+//! `{?is_admin}Admin: {/}` conditional blocks, and selects their arms for a
+//! language and its arguments.
+//!
+//! Anything else is kept as text, so simple (`{name}`) and escaped
+//! (`{{name}}`) templates are replaced afterwards as usual.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
+
+use super::filter::split_filters;
+use crate::Language;
+
+/// Plural categories an arm can be selected by
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Closing tag of a conditional block
+const CONDITION_END: &str = "{/}";
+
+/// Value of the argument a conditional block is shown for
+const CONDITION_ENABLED: &str = "true";
+
+/// Kind of a choice block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ChoiceKind {
+    /// Arms selected by the plural category or exact value of a number
+    Plural,
+    /// Arms selected by the value of an argument
+    Select,
+    /// Single arm shown if a boolean argument is `true`
+    Condition,
+}
+
+/// Parsed part of a message
+#[derive(Debug)]
+enum Part<'a> {
+    /// Text written as is, templates included
+    Text(&'a str),
+
+    /// `#` inside a plural arm, written as the value of the plural argument
+    Argument(&'a str),
+
+    /// Plural or select block
+    Choice {
+        /// Argument the arm is selected by
+        argument: &'a str,
+        /// How the arm is selected
+        kind: ChoiceKind,
+        /// Selectors and their messages
+        arms: Vec<(&'a str, Vec<Part<'a>>)>,
+        /// Whole block, written as is if the argument is missing
+        source: &'a str,
+    },
+}
+
+/// Fragment of a message with its choices selected
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fragment<'a> {
+    /// Text containing the remaining templates
+    Text(&'a str),
+
+    /// Value of the named argument, in place of a `#`
+    Argument(&'a str),
+}
+
+/// Selector of a plural arm with the fragments of its expanded message
+pub type PluralVariant<'a> = (&'a str, Vec<Fragment<'a>>);
+
+/// Cursor over a message being parsed
+struct Parser<'a> {
+    /// Whole message
+    input: &'a str,
+    /// Byte position of the cursor, always at a character boundary
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Gets the rest of the message from the cursor
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Advances the cursor past any whitespace
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Parses parts up to the end of the message, or up to the closing
+    /// brace of an arm or tag of a condition, which is left unconsumed
+    ///
+    /// # Arguments
+    /// * `plural` - Argument of the innermost plural block, if any
+    /// * `closing` - Closing brace or tag of the block the parts are inside, if
+    ///   any
+    fn parts(
+        &mut self,
+        plural: Option<&'a str>,
+        closing: Option<&str>,
+    ) -> Result<Vec<Part<'a>>, String> {
+        let mut parts = Vec::new();
+        let mut text_start = self.position;
+
+        while let Some(character) = self.rest().chars().next() {
+            let start = self.position;
+
+            match character {
+                // Escaped templates are kept as text
+                '{' if self.rest().starts_with("{{") => {
+                    self.position += self.rest()[2..].find("}}").map_or(2, |end| end + 4);
+                },
+
+                '{' if self.rest().starts_with(CONDITION_END) => {
+                    if closing != Some(CONDITION_END) {
+                        return Err(format!("a '{CONDITION_END}' closes no condition"));
+                    }
+
+                    parts.push(Part::Text(&self.input[text_start..start]));
+                    return Ok(parts);
+                },
+
+                '{' if self.rest().starts_with("{?") => {
+                    parts.push(Part::Text(&self.input[text_start..start]));
+                    parts.push(self.condition(plural)?);
+                    text_start = self.position;
+                },
+
+                '{' => match self.choice(plural)? {
+                    Some(choice) => {
+                        parts.push(Part::Text(&self.input[text_start..start]));
+                        parts.push(choice);
+                        text_start = self.position;
+                    },
+
+                    // Simple templates are kept as text
+                    None => {
+                        self.position += self.rest().find('}').map_or(1, |end| end + 1);
+                    },
+                },
+
+                '}' if closing == Some("}") => {
+                    parts.push(Part::Text(&self.input[text_start..start]));
+                    return Ok(parts);
+                },
+
+                '#' if plural.is_some() => {
+                    parts.push(Part::Text(&self.input[text_start..start]));
+                    parts.extend(plural.map(Part::Argument));
+                    self.position += 1;
+                    text_start = self.position;
+                },
+
+                character => self.position += character.len_utf8(),
+            }
+        }
+
+        match closing {
+            Some(CONDITION_END) => return Err("a condition is not closed".into()),
+            Some(_) => return Err("an arm is not closed".into()),
+            None => {},
+        }
+
+        parts.push(Part::Text(&self.input[text_start..]));
+        Ok(parts)
+    }
+
+    /// Parses a plural or select block at an opening brace
+    ///
+    /// # Returns
+    /// - `Ok(Some(choice))` with the block, the cursor is moved past it
+    /// - `Ok(None)` if the brace opens something else, the cursor is kept
+    /// - `Err(message)` if the block is invalid
+    fn choice(&mut self, plural: Option<&'a str>) -> Result<Option<Part<'a>>, String> {
+        let start = self.position;
+        let header = &self.rest()[1..];
+
+        let Some(comma) = header.find(',') else { return Ok(None) };
+        if header[..comma].contains(['{', '}']) {
+            return Ok(None);
+        }
+
+        let argument = header[..comma].trim();
+        let Some(kind_end) = header[comma + 1..].find(',') else { return Ok(None) };
+        let kind = match header[comma + 1..comma + 1 + kind_end].trim() {
+            "plural" => ChoiceKind::Plural,
+            "select" => ChoiceKind::Select,
+            _ => return Ok(None),
+        };
+
+        if argument.is_empty() {
+            return Err("a block has no argument".into());
+        }
+
+        self.position += 1 + comma + 1 + kind_end + 1;
+
+        let nested_plural = if kind == ChoiceKind::Plural { Some(argument) } else { plural };
+        let mut arms = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.rest().chars().next() {
+                Some('}') => {
+                    self.position += 1;
+                    break;
+                },
+                None => return Err(format!("the '{argument}' block is not closed")),
+                Some(_) => {},
+            }
+
+            let selector_end =
+                self.rest().find(|c: char| c.is_whitespace() || c == '{').unwrap_or(0);
+            let selector = &self.rest()[..selector_end];
+            self.position += selector_end;
+
+            let valid = match kind {
+                ChoiceKind::Plural => {
+                    PLURAL_CATEGORIES.contains(&selector)
+                        || selector.strip_prefix('=').is_some_and(|n| n.parse::<f64>().is_ok())
+                },
+                ChoiceKind::Select | ChoiceKind::Condition => {
+                    !selector.is_empty() && !selector.contains('}')
+                },
+            };
+
+            if !valid {
+                return Err(format!("'{selector}' is not a valid selector for '{argument}'"));
+            }
+
+            self.skip_whitespace();
+            if !self.rest().starts_with('{') {
+                return Err(format!("the '{selector}' arm of '{argument}' has no message"));
+            }
+
+            self.position += 1;
+            let parts = self.parts(nested_plural, Some("}"))?;
+            self.position += 1;
+
+            arms.push((selector, parts));
+        }
+
+        if !arms.iter().any(|(selector, _)| *selector == "other") {
+            return Err(format!("the '{argument}' block has no 'other' arm"));
+        }
+
+        Ok(Some(Part::Choice {
+            argument,
+            kind,
+            arms,
+            source: &self.input[start..self.position],
+        }))
+    }
+
+    /// Parses a conditional block at an opening brace, the cursor is moved
+    /// past its closing tag
+    fn condition(&mut self, plural: Option<&'a str>) -> Result<Part<'a>, String> {
+        let start = self.position;
+        let header = &self.rest()[2..];
+
+        let Some(end) = header.find('}') else {
+            return Err("a condition is not closed".into());
+        };
+
+        let argument = header[..end].trim();
+        if argument.is_empty() || argument.contains(['{', ',']) {
+            return Err("a condition has no argument".into());
+        }
+
+        self.position += 2 + end + 1;
+        let parts = self.parts(plural, Some(CONDITION_END))?;
+        self.position += CONDITION_END.len();
+
+        Ok(Part::Choice {
+            argument,
+            kind: ChoiceKind::Condition,
+            arms: vec![(CONDITION_ENABLED, parts)],
+            source: &self.input[start..self.position],
+        })
+    }
+}
+
+/// Parses a message into its parts
+fn parse(translation: &str) -> Result<Vec<Part<'_>>, String> {
+    Parser { input: translation, position: 0 }.parts(None, None)
+}
+
+/// Validates the plural, select and conditional blocks of a translation
+///
+/// # Returns
+/// - `Ok(())` if every block is valid, or there are none
+/// - `Err(message)` describing the first invalid block
+pub fn validate_message(translation: &str) -> Result<(), String> {
+    parse(translation).map(|_| ())
+}
+
+/// Whether a translation contains plural, select or conditional blocks
+pub fn has_choices(translation: &str) -> bool {
+    parse(translation)
+        .is_ok_and(|parts| parts.iter().any(|part| matches!(part, Part::Choice { .. })))
+}
+
+/// Collects the arguments the plural, select and conditional blocks are
+/// selected by
+pub fn choice_arguments(translation: &str) -> Vec<String> {
+    /// Collects the choice arguments of some parts, recursively
+    fn collect(parts: &[Part], arguments: &mut Vec<String>) {
+        for part in parts {
+            if let Part::Choice { argument, arms, .. } = part {
+                arguments.push(argument.to_string());
+                arms.iter().for_each(|(_, parts)| collect(parts, arguments));
+            }
+        }
+    }
+
+    let mut arguments = Vec::new();
+    collect(&parse(translation).unwrap_or_default(), &mut arguments);
+    arguments
+}
+
+/// Collects the template names in a translation
+///
+/// Escaped templates (`{{name}}`) are skipped, the arguments of plural and
+/// select blocks and the templates of all their arms are included. Filter
+/// pipes are not part of the names.
+pub fn templates(translation: &str) -> Vec<String> {
+    /// Collects the simple template names of a text
+    fn text_templates(mut rest: &str, templates: &mut Vec<String>) {
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start..];
+
+            if let Some(escaped) = rest.strip_prefix("{{") {
+                rest = escaped;
+                continue;
+            }
+
+            let Some(end) = rest.find('}') else { break };
+            templates.push(split_filters(&rest[1..end]).0.to_string());
+            rest = &rest[end + 1..];
+        }
+    }
+
+    /// Collects the template names of some parts, recursively
+    fn collect(parts: &[Part], templates: &mut Vec<String>) {
+        for part in parts {
+            match part {
+                Part::Text(text) => text_templates(text, templates),
+                Part::Argument(_) => {},
+                Part::Choice { argument, arms, .. } => {
+                    templates.push(argument.to_string());
+                    arms.iter().for_each(|(_, parts)| collect(parts, templates));
+                },
+            }
+        }
+    }
+
+    let mut templates = Vec::new();
+
+    match parse(translation) {
+        Ok(parts) => collect(&parts, &mut templates),
+        Err(_) => text_templates(translation, &mut templates),
+    }
+
+    templates
+}
+
+/// Selects the CLDR cardinal plural category of a number
+///
+/// Only the rules of the most common language families are implemented,
+/// other languages use the English rules (`one` for exactly `1`). Values
+/// that aren't numbers are `other`.
+///
+/// # Arguments
+/// * `language` - Language the rules are selected for
+/// * `number` - Decimal representation of the number, such as `"1.5"`
+pub fn plural_category(language: &Language, number: &str) -> &'static str {
+    let number = number.trim();
+    let number = number.strip_prefix('-').unwrap_or(number);
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    let digits = |digits: &str| digits.chars().all(|c| c.is_ascii_digit());
+    if integer.is_empty() || !digits(integer) || !digits(fraction) {
+        return "other";
+    }
+
+    // Absurdly large integers saturate, which only affects their category
+    let i = integer.parse::<u128>().unwrap_or(u128::MAX);
+    let v = fraction.len();
+    let (i10, i100) = (i % 10, i % 100);
+
+    match language.code() {
+        "id" | "ja" | "jv" | "km" | "ko" | "lo" | "ms" | "my" | "th" | "vi" | "zh" => "other",
+
+        "ff" | "fr" | "hy" | "pt" if i <= 1 => "one",
+        "ff" | "fr" | "hy" | "pt" => "other",
+
+        "be" | "ru" | "uk" if v != 0 => "other",
+        "be" | "ru" | "uk" if i10 == 1 && i100 != 11 => "one",
+        "be" | "ru" | "uk" if (2..=4).contains(&i10) && !(12..=14).contains(&i100) => "few",
+        "be" | "ru" | "uk" => "many",
+
+        "bs" | "hr" | "sr" if v != 0 => "other",
+        "bs" | "hr" | "sr" if i10 == 1 && i100 != 11 => "one",
+        "bs" | "hr" | "sr" if (2..=4).contains(&i10) && !(12..=14).contains(&i100) => "few",
+        "bs" | "hr" | "sr" => "other",
+
+        "pl" if v != 0 => "other",
+        "pl" if i == 1 => "one",
+        "pl" if (2..=4).contains(&i10) && !(12..=14).contains(&i100) => "few",
+        "pl" => "many",
+
+        "cs" | "sk" if v != 0 => "many",
+        "cs" | "sk" if i == 1 => "one",
+        "cs" | "sk" if (2..=4).contains(&i) => "few",
+        "cs" | "sk" => "other",
+
+        "ar" if v != 0 => "other",
+        "ar" if i == 0 => "zero",
+        "ar" if i == 1 => "one",
+        "ar" if i == 2 => "two",
+        "ar" if (3..=10).contains(&i100) => "few",
+        "ar" if (11..=99).contains(&i100) => "many",
+        "ar" => "other",
+
+        "he" if v == 0 && i == 1 => "one",
+        "he" if v == 0 && i == 2 => "two",
+        "he" => "other",
+
+        _ if v == 0 && i == 1 => "one",
+        _ => "other",
+    }
+}
+
+/// Selects the arms of some parts, recursively
+fn select<'a>(
+    parts: &[Part<'a>],
+    language: Option<&Language>,
+    arguments: &[(&str, &dyn Display)],
+    fragments: &mut Vec<Fragment<'a>>,
+) {
+    for part in parts {
+        match part {
+            Part::Text(text) => fragments.push(Fragment::Text(text)),
+            Part::Argument(argument) => fragments.push(Fragment::Argument(argument)),
+            Part::Choice { argument, kind, arms, source } => {
+                let Some((_, value)) = arguments.iter().find(|(name, _)| name == argument) else {
+                    fragments.push(Fragment::Text(source));
+                    continue;
+                };
+
+                let value = value.to_string();
+                let arm = |selector: &str| arms.iter().find(|(arm, _)| *arm == selector);
+
+                let selected = match kind {
+                    ChoiceKind::Plural => arms
+                        .iter()
+                        .find(|(selector, _)| {
+                            selector.strip_prefix('=').is_some_and(|exact| {
+                                exact.parse::<f64>().ok() == value.trim().parse::<f64>().ok()
+                            })
+                        })
+                        .or_else(|| {
+                            language.and_then(|language| arm(plural_category(language, &value)))
+                        }),
+                    ChoiceKind::Select | ChoiceKind::Condition => arm(&value),
+                };
+
+                if let Some((_, parts)) = selected.or_else(|| arm("other")) {
+                    select(parts, language, arguments, fragments);
+                }
+            },
+        }
+    }
+}
+
+/// Selects the plural and select arms of a translation
+///
+/// Blocks whose argument is missing are kept as text, translations that
+/// fail to parse are a single text fragment.
+///
+/// # Arguments
+/// * `language` - Language of the plural rules, `None` only selects exact
+///   values and `other` arms
+/// * `translation` - Translation containing the blocks
+/// * `arguments` - Argument names and their values
+pub fn select_fragments<'a>(
+    language: Option<&Language>,
+    translation: &'a str,
+    arguments: &[(&str, &dyn Display)],
+) -> Vec<Fragment<'a>> {
+    let mut fragments = Vec::new();
+
+    match parse(translation) {
+        Ok(parts) => select(&parts, language, arguments, &mut fragments),
+        Err(_) => fragments.push(Fragment::Text(translation)),
+    }
+
+    fragments
+}
+
+/// Expands the plural block of a translation into the message of every arm,
+/// the text around the block included
+///
+/// Meant for formats whose plurals are whole messages, such as Android
+/// `plurals` resources, `#` is an argument fragment in the arms.
+///
+/// # Returns
+/// The argument of the block with the selectors and fragments of its arms,
+/// or `None` unless the translation has a single plural block and no other
+/// blocks
+pub fn plural_variants(translation: &str) -> Option<(&str, Vec<PluralVariant<'_>>)> {
+    let parts = parse(translation).ok()?;
+    let mut choices = parts.iter().filter(|part| matches!(part, Part::Choice { .. }));
+
+    let Some(Part::Choice {
+        argument, kind: ChoiceKind::Plural, arms, ..
+    }) = choices.next()
+    else {
+        return None;
+    };
+
+    if choices.next().is_some() {
+        return None;
+    }
+
+    let arms = arms
+        .iter()
+        .map(|(selector, arm)| {
+            let mut fragments = Vec::new();
+
+            // The arm is expanded in place of the block, nested blocks can't be
+            for part in &parts {
+                match part {
+                    Part::Text(text) => fragments.push(Fragment::Text(text)),
+                    Part::Argument(argument) => fragments.push(Fragment::Argument(argument)),
+                    Part::Choice { .. } => {
+                        for part in arm {
+                            match part {
+                                Part::Text(text) => fragments.push(Fragment::Text(text)),
+                                Part::Argument(argument) => {
+                                    fragments.push(Fragment::Argument(argument))
+                                },
+                                Part::Choice { .. } => return None,
+                            }
+                        }
+                    },
+                }
+            }
+
+            Some((*selector, fragments))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((argument, arms))
+}
+
+/// Selects the plural and select arms of a translation into a string
+///
+/// The translation is only copied if it contains any block, the values in
+/// place of `#` are written with their alternate `Display` output like
+/// templates.
+pub fn format_choices<'a>(
+    language: &Language,
+    translation: Cow<'a, str>,
+    arguments: &[(&str, &dyn Display)],
+) -> Cow<'a, str> {
+    if !has_choices(&translation) {
+        return translation;
+    }
+
+    let mut formatted = String::with_capacity(translation.len());
+
+    for fragment in select_fragments(Some(language), &translation, arguments) {
+        match fragment {
+            Fragment::Text(text) => formatted.push_str(text),
+            Fragment::Argument(argument) => {
+                if let Some((_, value)) = arguments.iter().find(|(name, _)| *name == argument) {
+                    let _ = write!(formatted, "{value:#}");
+                }
+            },
+        }
+    }
+
+    Cow::Owned(formatted)
+}
@@ -0,0 +1,17 @@
+pub mod binary;
+pub mod collection;
+pub mod condition;
+pub mod deprecation;
+pub mod filter;
+pub mod lint;
+pub mod location;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod message;
+pub mod node;
+pub mod random;
+pub mod unicode;
+pub mod value;
+pub mod variant;
+pub mod whitespace;
+pub mod writer;
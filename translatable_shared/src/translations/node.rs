@@ -0,0 +1,569 @@
+//! Hierarchical translation structure
+//!
+//! This module provides the in-memory representation of a translation file,
+//! along with its validation from TOML and its conversion to tokens for
+//! embedding in procedural macro output.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Iter;
+use std::iter::Flatten;
+use std::option::IntoIter as OptionIter;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use strum::ParseError;
+use thiserror::Error;
+use toml::{Table, Value};
+
+use super::message::validate_message;
+use super::random::{join_entries, random_entries};
+use super::unicode::normalize;
+use super::value::VALUES_KEY;
+use crate::Language;
+
+/// Errors occurring during TOML-to-translation structure transformation
+#[derive(Error, Debug)]
+pub enum TransformError {
+    /// Mixed content found in nesting node (strings and objects cannot coexist)
+    #[error("A nesting can contain either strings or other nestings, but not both.")]
+    InvalidNesting,
+
+    /// Template syntax error with unbalanced braces
+    #[error("Templates in translations should match '{{' and '}}'")]
+    UnclosedTemplate,
+
+    /// Invalid plural, select or conditional block
+    #[error("Invalid plural, select or conditional block: {0}.")]
+    InvalidMessage(String),
+
+    /// Invalid value type encountered in translation structure
+    #[error("Only strings and objects are allowed for nested objects.")]
+    InvalidValue,
+
+    /// Array-valued translation without entries or with other values than
+    /// strings
+    #[error("Translations with random entries should be non empty arrays of strings.")]
+    InvalidEntries,
+
+    /// Failed to parse language code from translation key
+    #[error("Couldn't parse ISO 639-1 string for translation key")]
+    LanguageParsing(#[from] ParseError),
+
+    /// The path to mutate doesn't exist
+    #[error("The path '{0}' doesn't exist.")]
+    MissingPath(String),
+
+    /// The path to rename to already exists
+    #[error("The path '{0}' already exists.")]
+    ExistingPath(String),
+
+    /// The lookup priority of a translation file isn't an integer
+    #[error("The '_priority' key should be an integer.")]
+    InvalidPriority,
+
+    /// The catalog version of a translation file isn't a non-negative
+    /// integer
+    #[error("The '_version' key should be a non-negative integer.")]
+    InvalidVersion,
+
+    /// The `_cfg` predicate of a table isn't a supported `cfg` predicate
+    #[error(
+        "Invalid '_cfg' predicate '{0}', expected `feature = \"name\"`, `all`, `any` or `not`."
+    )]
+    InvalidCondition(String),
+
+    /// The `_trim` key of a table isn't a boolean
+    #[error("The '_trim' key should be a boolean.")]
+    InvalidTrim,
+
+    /// The `_deprecated` key of a table isn't a string
+    #[error("The '_deprecated' key should be a string.")]
+    InvalidDeprecation,
+
+    /// A translation isn't NFC normalized or has invisible control
+    /// characters, only checked by strict builds
+    #[error("Invalid Unicode in translation, {0}.")]
+    InvalidUnicode(String),
+
+    /// A metadata key left after the known ones were applied
+    #[error(
+        "Unknown metadata key '{0}', keys starting with '_' are reserved for '_priority', '_cfg', \
+         '_trim', '_deprecated' and '_values'."
+    )]
+    UnknownMetadata(String),
+
+    /// A table with both languages and nestings
+    #[error(
+        "The table mixes the language '{language}' with the nesting '{nesting}', a table can \
+         contain either languages or nestings, but not both."
+    )]
+    MixedTable {
+        /// First language key of the table
+        language: String,
+        /// First nesting key of the table
+        nesting: String,
+    },
+
+    /// A language key that isn't an ISO 639-1 code
+    #[error("The key '{0}' holds a translation but isn't an ISO 639-1 language code.")]
+    UnknownLanguage(String),
+
+    /// A key holding other values than translations, random entries or
+    /// nestings
+    #[error("The key '{0}' should hold a string, an array of strings or a table.")]
+    UnexpectedValue(String),
+
+    /// A table without languages nor nestings
+    #[error("Tables should contain at least one language or nesting.")]
+    EmptyTable,
+
+    /// The document to update isn't valid TOML
+    #[error("Couldn't parse the TOML document: {0}")]
+    InvalidDocument(String),
+}
+
+/// Prefix of the keys reserved for the metadata of translation file tables,
+/// such as `_priority` or `_cfg`
+pub const METADATA_PREFIX: char = '_';
+
+/// What a key of a translation file table is treated as
+///
+/// A table contains either languages, and is a translation object, or
+/// nestings. Metadata keys are applied and removed before the table is
+/// converted to a [`TranslationNode`], so the ones left are unknown, except
+/// for the [`VALUES_KEY`] namespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyKind {
+    /// Key starting with [`METADATA_PREFIX`], such as `_trim`
+    Metadata,
+    /// Language of a translation, such as `en`, holding a string or random
+    /// entries
+    Language,
+    /// Nesting of other translation objects, holding a table
+    Nesting,
+}
+
+impl KeyKind {
+    /// Classifies a key of a translation file table by its name and value
+    pub fn of(key: &str, value: &Value) -> Self {
+        match value {
+            // The typed values are kept as a namespace of their own
+            Value::Table(_) if key == VALUES_KEY => Self::Nesting,
+            _ if key.starts_with(METADATA_PREFIX) => Self::Metadata,
+            Value::Table(_) => Self::Nesting,
+            _ => Self::Language,
+        }
+    }
+}
+
+/// Translations of a path per language
+pub type TranslationObject = HashMap<Language, String>;
+
+/// Represents hierarchical translation structure
+#[derive(Clone, Debug, PartialEq)]
+pub enum TranslationNode {
+    /// Nested namespace containing other translation objects
+    Object(HashMap<String, TranslationNode>),
+    /// Leaf node containing actual translations per language
+    Translation(TranslationObject),
+}
+
+/// Validates template brace balancing in translation strings
+fn templates_valid(translation: &str) -> bool {
+    let mut nestings = 0;
+
+    for character in translation.chars() {
+        match character {
+            '{' => nestings += 1,
+            '}' => nestings -= 1,
+            _ => {},
+        }
+    }
+
+    nestings == 0
+}
+
+impl TranslationNode {
+    /// Resolves a path through the nesting hierarchy to any node
+    ///
+    /// # Arguments
+    /// * `path` - Slice of path segments to resolve
+    ///
+    /// # Returns
+    /// Reference to the node if path exists, either nested or leaf
+    pub fn get_node(&self, path: Vec<&str>) -> Option<&TranslationNode> {
+        match path.split_first() {
+            Some((first, rest)) => match self {
+                Self::Object(nested) => nested.get(*first)?.get_node(rest.to_vec()),
+                Self::Translation(_) => None,
+            },
+            None => Some(self),
+        }
+    }
+
+    /// Resolves a translation path through the nesting hierarchy
+    ///
+    /// # Arguments
+    /// * `path` - Slice of path segments to resolve
+    ///
+    /// # Returns
+    /// Reference to translations if path exists and points to leaf node
+    pub fn get_path(&self, path: Vec<&str>) -> Option<&HashMap<Language, String>> {
+        match self {
+            Self::Object(nested) => {
+                let (first, rest) = path.split_first()?;
+                nested.get(*first)?.get_path(rest.to_vec())
+            },
+            Self::Translation(translation) => path.is_empty().then_some(translation),
+        }
+    }
+
+    /// Resolves a translation path through the nesting hierarchy for
+    /// mutation
+    ///
+    /// # Returns
+    /// Mutable reference to translations if path exists and points to leaf
+    /// node
+    pub fn get_path_mut(&mut self, path: Vec<&str>) -> Option<&mut HashMap<Language, String>> {
+        match self {
+            Self::Object(nested) => {
+                let (first, rest) = path.split_first()?;
+                nested.get_mut(*first)?.get_path_mut(rest.to_vec())
+            },
+            Self::Translation(translation) => path.is_empty().then_some(translation),
+        }
+    }
+}
+
+impl TranslationNode {
+    /// Sets the translation of a path to a language
+    ///
+    /// The nestings leading to the path are created if they don't exist, and
+    /// the translation is validated and normalized like the ones in
+    /// translation files.
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` with the translation replaced, if any
+    /// - `Err(TransformError)` if the translation is not valid or the path goes
+    ///   through a translation or ends in a nesting
+    pub fn set(
+        &mut self,
+        path: Vec<&str>,
+        language: Language,
+        translation: impl Into<String>,
+    ) -> Result<Option<String>, TransformError> {
+        let translation = normalize(translation.into());
+
+        if !templates_valid(&translation) {
+            return Err(TransformError::UnclosedTemplate);
+        }
+
+        validate_message(&translation).map_err(TransformError::InvalidMessage)?;
+
+        let (last, parents) = path.split_last().ok_or(TransformError::InvalidNesting)?;
+        let node = self
+            .nesting_mut(parents)?
+            .entry(last.to_string())
+            .or_insert_with(|| Self::Translation(HashMap::new()));
+
+        if node.is_empty() {
+            *node = Self::Translation(HashMap::new());
+        }
+
+        match node {
+            Self::Translation(translations) => Ok(translations.insert(language, translation)),
+            Self::Object(_) => Err(TransformError::InvalidNesting),
+        }
+    }
+
+    /// Removes the node at a path, either a nesting or a translation
+    ///
+    /// Nestings left empty are removed too, so the structure can still be
+    /// written as a translation file.
+    ///
+    /// # Returns
+    /// The node removed, if the path exists
+    pub fn remove(&mut self, path: Vec<&str>) -> Option<TranslationNode> {
+        let Self::Object(nested) = self else { return None };
+        let (first, rest) = path.split_first()?;
+
+        if rest.is_empty() {
+            return nested.remove(*first);
+        }
+
+        let child = nested.get_mut(*first)?;
+        let removed = child.remove(rest.to_vec());
+
+        if child.is_empty() {
+            nested.remove(*first);
+        }
+
+        removed
+    }
+
+    /// Moves the node at a path to another path, such as to rename a key
+    ///
+    /// # Returns
+    /// - `Ok(())` if the node was moved
+    /// - `Err(TransformError)` if `from` doesn't exist, `to` already exists or
+    ///   goes through a translation
+    pub fn rename_key(&mut self, from: Vec<&str>, to: Vec<&str>) -> Result<(), TransformError> {
+        if self.get_node(from.clone()).is_none() {
+            return Err(TransformError::MissingPath(from.join(".")));
+        }
+
+        if self.get_node(to.clone()).is_some() {
+            return Err(TransformError::ExistingPath(to.join(".")));
+        }
+
+        // Checked before removing, so failed renames leave the node as it was
+        let blocked = (1..to.len()).any(|length| {
+            matches!(self.get_node(to[..length].to_vec()), Some(Self::Translation(_)))
+        });
+
+        if blocked || to.starts_with(&from) {
+            return Err(TransformError::InvalidNesting);
+        }
+
+        let node = self.remove(from).ok_or(TransformError::InvalidNesting)?;
+        let (last, parents) = to.split_last().ok_or(TransformError::InvalidNesting)?;
+        self.nesting_mut(parents)?.insert(last.to_string(), node);
+
+        Ok(())
+    }
+
+    /// Resolves a nesting path for mutation, creating the nestings that
+    /// don't exist
+    fn nesting_mut(
+        &mut self,
+        path: &[&str],
+    ) -> Result<&mut HashMap<String, TranslationNode>, TransformError> {
+        // Empty nodes, such as new ones, can hold nestings
+        if self.is_empty() {
+            *self = Self::Object(HashMap::new());
+        }
+
+        let Self::Object(nested) = self else { return Err(TransformError::InvalidNesting) };
+
+        match path.split_first() {
+            Some((first, rest)) => nested
+                .entry(first.to_string())
+                .or_insert_with(|| Self::Object(HashMap::new()))
+                .nesting_mut(rest),
+            None => Ok(nested),
+        }
+    }
+}
+
+impl TranslationNode {
+    /// Iterates over the keys and nodes nested in the node, in arbitrary
+    /// order
+    ///
+    /// Translation objects have no nested nodes, their languages are
+    /// iterated over with [`HashMap::iter`].
+    pub fn iter(&self) -> Nested<'_> {
+        match self {
+            Self::Object(nested) => Some(nested.iter()),
+            Self::Translation(_) => None,
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// Collects the keys and nodes nested in the node, sorted by key
+    ///
+    /// Used wherever the order ends up in generated code, as the iteration
+    /// order of [`TranslationNode::iter`] changes between expansions.
+    pub fn sorted(&self) -> Vec<(&String, &TranslationNode)> {
+        let mut nested = self.iter().collect::<Vec<_>>();
+        nested.sort_by_key(|(key, _)| *key);
+        nested
+    }
+
+    /// Iterates over the keys of the nodes nested in the node, in arbitrary
+    /// order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Gets the number of nested nodes, or of languages of a translation
+    /// object
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Object(nested) => nested.len(),
+            Self::Translation(translations) => translations.len(),
+        }
+    }
+
+    /// Checks whether the node has no nestings or translations
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Collects the translation objects in the node with their dot-separated
+    /// paths, sorted by path
+    ///
+    /// A translation object node is collected with an empty path.
+    pub fn objects(&self) -> Vec<(String, &TranslationObject)> {
+        /// Collects the translation objects of a node below a path
+        fn collect<'a>(
+            node: &'a TranslationNode,
+            path: String,
+            objects: &mut Vec<(String, &'a TranslationObject)>,
+        ) {
+            match node {
+                TranslationNode::Object(nested) => {
+                    for (key, node) in nested {
+                        let path =
+                            if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                        collect(node, path, objects);
+                    }
+                },
+                TranslationNode::Translation(translations) => objects.push((path, translations)),
+            }
+        }
+
+        let mut objects = Vec::new();
+        collect(self, String::new(), &mut objects);
+        objects.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+        objects
+    }
+}
+
+/// Collects the translations of a translation object, sorted by language
+/// code
+pub fn sorted_translations(translations: &TranslationObject) -> Vec<(&Language, &String)> {
+    let mut translations = translations.iter().collect::<Vec<_>>();
+    translations.sort_by_key(|(language, _)| language.code());
+    translations
+}
+
+/// Iterator over the keys and nodes nested in a [`TranslationNode`]
+pub type Nested<'a> = Flatten<OptionIter<Iter<'a, String, TranslationNode>>>;
+
+impl<'a> IntoIterator for &'a TranslationNode {
+    type Item = (&'a String, &'a TranslationNode);
+    type IntoIter = Nested<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl ToTokens for TranslationNode {
+    /// Converts TranslationNode to procedural macro output tokens
+    ///
+    /// Entries are sorted so the same translations always expand to the
+    /// same tokens.
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Object(_) => {
+                let entries = self.sorted().into_iter().map(|(key, value)| {
+                    quote! { (#key.to_string(), #value) }
+                });
+
+                quote! {
+                    translatable::TranslationNode::Object(vec![#(#entries),*].into_iter().collect())
+                }
+            },
+
+            Self::Translation(translation) => {
+                let entries = sorted_translations(translation).into_iter().map(|(lang, value)| {
+                    quote! { (#lang, #value.to_string()) }
+                });
+
+                quote! {
+                    translatable::TranslationNode::Translation(vec![#(#entries),*].into_iter().collect())
+                }
+            },
+        });
+    }
+}
+
+impl TranslationNode {
+    /// Converts TOML table to validated translation structure
+    ///
+    /// Translations are normalized to NFC.
+    ///
+    /// # Arguments
+    /// * `value` - TOML table of the translation file
+    /// * `check_templates` - Whether unbalanced template braces are an error
+    pub fn from_table(value: Table, check_templates: bool) -> Result<Self, TransformError> {
+        let mut languages = None;
+        let mut nestings = None;
+
+        for (key, value) in &value {
+            if !matches!(value, Value::String(_) | Value::Array(_) | Value::Table(_)) {
+                return Err(TransformError::UnexpectedValue(key.clone()));
+            }
+
+            match KeyKind::of(key, value) {
+                KeyKind::Metadata => return Err(TransformError::UnknownMetadata(key.clone())),
+                KeyKind::Language => languages = languages.or(Some(key)),
+                KeyKind::Nesting => nestings = nestings.or(Some(key)),
+            }
+
+            if let (Some(language), Some(nesting)) = (languages, nestings) {
+                return Err(TransformError::MixedTable {
+                    language: language.clone(),
+                    nesting: nesting.clone(),
+                });
+            }
+        }
+
+        if nestings.is_some() {
+            let nesting = value
+                .into_iter()
+                .map(|(key, value)| match value {
+                    Value::Table(nested) => Ok((key, Self::from_table(nested, check_templates)?)),
+                    _ => Err(TransformError::InvalidNesting),
+                })
+                .collect::<Result<_, _>>()?;
+
+            return Ok(Self::Object(nesting));
+        }
+
+        if languages.is_none() {
+            return Err(TransformError::EmptyTable);
+        }
+
+        let mut translation = HashMap::new();
+
+        for (key, value) in value {
+            // Random entries are stored as a single translation
+            let translation_value = match value {
+                Value::String(translation_value) => translation_value,
+                Value::Array(entries) => join_entries(entries)?,
+                _ => return Err(TransformError::InvalidNesting),
+            };
+
+            // Random entries are validated one by one
+            for entry in random_entries(&translation_value) {
+                if check_templates && !templates_valid(entry) {
+                    return Err(TransformError::UnclosedTemplate);
+                }
+
+                // Translations with unbalanced braces are kept as they are
+                if templates_valid(entry) {
+                    validate_message(entry).map_err(TransformError::InvalidMessage)?;
+                }
+            }
+
+            let language = key.parse().map_err(|_| TransformError::UnknownLanguage(key))?;
+            translation.insert(language, normalize(translation_value));
+        }
+
+        Ok(Self::Translation(translation))
+    }
+}
+
+impl TryFrom<Table> for TranslationNode {
+    type Error = TransformError;
+
+    /// Converts TOML table to validated translation structure, unbalanced
+    /// template braces included
+    fn try_from(value: Table) -> Result<Self, Self::Error> {
+        Self::from_table(value, true)
+    }
+}
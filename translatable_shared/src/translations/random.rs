@@ -0,0 +1,45 @@
+//! Random translation entries
+//!
+//! This module stores the entries of array-valued translations, such as the
+//! greetings of a chatbot, in a single string, so they are validated and
+//! embedded like any other translation and one of them is picked at runtime.
+
+use toml::Value;
+
+use super::node::TransformError;
+
+/// Separator between the entries of a translation, the ASCII record
+/// separator
+pub const ENTRY_SEPARATOR: char = '\u{1e}';
+
+/// Checks whether a translation has entries to pick from
+pub fn has_random_entries(translation: &str) -> bool {
+    translation.contains(ENTRY_SEPARATOR)
+}
+
+/// Gets the entries of a translation, the translation itself if it has none
+pub fn random_entries(translation: &str) -> impl Iterator<Item = &str> + Clone {
+    translation.split(ENTRY_SEPARATOR)
+}
+
+/// Joins the entries of an array-valued translation
+///
+/// # Returns
+/// - `Ok(String)` with the entries joined by [`ENTRY_SEPARATOR`]
+/// - `Err(TransformError)` if the array is empty or has other values than
+///   strings
+pub fn join_entries(entries: Vec<Value>) -> Result<String, TransformError> {
+    let entries = entries
+        .into_iter()
+        .map(|entry| match entry {
+            Value::String(entry) if !entry.contains(ENTRY_SEPARATOR) => Ok(entry),
+            _ => Err(TransformError::InvalidEntries),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if entries.is_empty() {
+        return Err(TransformError::InvalidEntries);
+    }
+
+    Ok(entries.join(&ENTRY_SEPARATOR.to_string()))
+}
@@ -0,0 +1,72 @@
+//! Unicode normalization of translations
+//!
+//! This module normalizes translations to NFC, so visually identical strings
+//! typed with composed or decomposed characters compare equal, and checks for
+//! the translations a strict build rejects instead, the ones not normalized
+//! and the ones with invisible control characters.
+
+use toml::{Table, Value};
+use unicode_normalization::{UnicodeNormalization, is_nfc};
+
+use super::node::TransformError;
+
+/// Normalizes a translation to NFC, only copying it if it isn't already
+pub fn normalize(translation: String) -> String {
+    if is_nfc(&translation) { translation } else { translation.nfc().collect() }
+}
+
+/// Checks whether a character is invisible and not meant to be in a
+/// translation, such as a zero width space or a bidi override
+///
+/// Line breaks and tabs are kept, and so are the zero width joiners emoji
+/// sequences and some scripts rely on.
+fn invisible(character: char) -> bool {
+    matches!(
+        character,
+        '\u{200b}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}'
+            | '\u{2060}'..='\u{2064}' | '\u{2066}'..='\u{2069}' | '\u{feff}'
+    ) || (character.is_control() && !matches!(character, '\n' | '\r' | '\t'))
+}
+
+/// Checks the translations of a translation file table are normalized and
+/// have no invisible control characters
+///
+/// # Returns
+/// - `Ok(())` if every translation is valid
+/// - `Err(TransformError)` with the first invalid translation
+pub fn check_unicode(table: &Table) -> Result<(), TransformError> {
+    for value in table.values() {
+        match value {
+            Value::Table(nested) => check_unicode(nested)?,
+            Value::String(translation) => check_translation(translation)?,
+            // Random entries are checked one by one
+            Value::Array(entries) => {
+                for entry in entries.iter().filter_map(Value::as_str) {
+                    check_translation(entry)?;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a translation is normalized and has no invisible control
+/// characters
+fn check_translation(translation: &str) -> Result<(), TransformError> {
+    if let Some(character) = translation.chars().find(|character| invisible(*character)) {
+        return Err(TransformError::InvalidUnicode(format!(
+            "{translation:?} has the invisible U+{:04X} character",
+            character as u32
+        )));
+    }
+
+    if !is_nfc(translation) {
+        return Err(TransformError::InvalidUnicode(format!(
+            "{translation:?} isn't NFC normalized"
+        )));
+    }
+
+    Ok(())
+}
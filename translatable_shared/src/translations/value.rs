@@ -0,0 +1,67 @@
+//! Typed translation values
+//!
+//! This module applies the `_values` namespace of translation files, whose
+//! translation objects can hold other TOML values than strings, such as the
+//! first day of the week or the paper size of every language, so per-locale
+//! constants can live alongside the translations.
+
+use toml::{Table, Value};
+
+use super::node::TransformError;
+
+/// Key of the translation file table holding typed values
+pub const VALUES_KEY: &str = "_values";
+
+/// Converts the values of the `_values` table of a translation file table
+/// to strings
+///
+/// Strings are kept as they are, other values are stored as they're written
+/// in TOML, such as `1`, `true` or `["A4", "A5"]`, so they're validated and
+/// embedded like translations and parsed back when read.
+///
+/// # Returns
+/// - `Ok(())` if the values were converted
+/// - `Err(TransformError)` if `_values` is not a table
+pub fn apply_values(table: &mut Table) -> Result<(), TransformError> {
+    match table.get_mut(VALUES_KEY) {
+        Some(Value::Table(values)) => {
+            stringify_values(values);
+            Ok(())
+        },
+        Some(_) => Err(TransformError::InvalidValue),
+        None => Ok(()),
+    }
+}
+
+/// Converts the values of a table to strings, recursively
+fn stringify_values(table: &mut Table) {
+    for (_, value) in table.iter_mut() {
+        match value {
+            Value::Table(nested) => stringify_values(nested),
+            Value::String(_) => {},
+            other => *other = Value::String(other.to_string()),
+        }
+    }
+}
+
+/// Parses the elements of an array value, as stored by [`apply_values`]
+///
+/// # Returns
+/// The elements stored like values, or `None` if the value isn't an array
+pub fn array_elements(value: &str) -> Option<Vec<String>> {
+    let Value::Array(elements) =
+        format!("value = {value}").parse::<Table>().ok()?.remove("value")?
+    else {
+        return None;
+    };
+
+    Some(
+        elements
+            .into_iter()
+            .map(|element| match element {
+                Value::String(element) => element,
+                element => element.to_string(),
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,51 @@
+//! Named translation variants
+//!
+//! This module moves the named variants of translation objects, such as the
+//! copy of an A/B test, to paths of their own, so they are validated and
+//! embedded like any other translation object and selected when resolved.
+
+use toml::{Table, Value};
+
+/// Separator between a translation path and the name of one of its variants
+pub const VARIANT_SEPARATOR: char = '@';
+
+/// Gets the path a named variant of a translation path is stored at, such as
+/// `checkout.cta@b`
+pub fn variant_path(path: &str, variant: &str) -> String {
+    format!("{path}{VARIANT_SEPARATOR}{variant}")
+}
+
+/// Moves the named variants of the translation objects of a translation
+/// file table to paths of their own
+///
+/// A translation object can hold tables of languages named after its
+/// variants, such as `b.en = "Get it today"`, which are moved next to it at
+/// [`variant_path`]. Target operating system variants must be applied
+/// before, as every table left in a translation object is a named variant.
+pub fn apply_named_variants(table: &mut Table) {
+    let mut variants = Vec::new();
+
+    for (key, value) in table.iter_mut() {
+        let Value::Table(nested) = value else { continue };
+
+        // Only translation objects have strings
+        if nested.values().all(Value::is_table) {
+            apply_named_variants(nested);
+            continue;
+        }
+
+        let names = nested
+            .iter()
+            .filter(|(_, value)| value.is_table())
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        for name in names {
+            if let Some(variant) = nested.remove(&name) {
+                variants.push((variant_path(key, &name), variant));
+            }
+        }
+    }
+
+    table.extend(variants);
+}
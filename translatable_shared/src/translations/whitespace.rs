@@ -0,0 +1,78 @@
+//! Whitespace control of multi-line translations
+//!
+//! This module applies the `_trim` key of translation file tables, which
+//! dedents the translations of a nesting, a translation object or a whole
+//! file, so multi-line strings can be indented to be readable without the
+//! indentation reaching the user interface.
+
+use toml::{Table, Value};
+
+use super::node::TransformError;
+
+/// Key of a translation file table dedenting its translations
+pub const TRIM_KEY: &str = "_trim";
+
+/// Applies the `_trim` keys of a translation file table
+///
+/// The translations of a table with `_trim = true`, and of the tables nested
+/// in it unless they have `_trim = false`, are dedented with [`dedent`]. The
+/// `_trim` keys are removed so the table can be converted to a
+/// [`TranslationNode`].
+///
+/// # Returns
+/// - `Ok(())` if the keys were applied
+/// - `Err(TransformError)` if a `_trim` key is not a boolean
+///
+/// [`TranslationNode`]: super::node::TranslationNode
+pub fn apply_trim(table: &mut Table) -> Result<(), TransformError> {
+    trim_table(table, false)
+}
+
+/// Applies the `_trim` keys of a table, inheriting the one of its parent
+fn trim_table(table: &mut Table, inherited: bool) -> Result<(), TransformError> {
+    let trim = match table.remove(TRIM_KEY) {
+        Some(Value::Boolean(trim)) => trim,
+        Some(_) => return Err(TransformError::InvalidTrim),
+        None => inherited,
+    };
+
+    for (_, value) in table.iter_mut() {
+        match value {
+            Value::Table(nested) => trim_table(nested, trim)?,
+            Value::String(translation) if trim => *translation = dedent(translation),
+            // Random entries are dedented one by one
+            Value::Array(entries) if trim => {
+                for entry in entries {
+                    if let Value::String(translation) = entry {
+                        *translation = dedent(translation);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the indentation the lines of a translation share, the trailing
+/// whitespace of every line and the leading and trailing blank lines, such
+/// as `"\n    Hello,\n      world!\n"` being dedented to `"Hello,\n  world!"`
+pub fn dedent(translation: &str) -> String {
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+
+    let shared = translation
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(indentation)
+        .min()
+        .unwrap_or(0);
+
+    translation
+        .lines()
+        .map(|line| line[shared.min(indentation(line))..].trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
@@ -0,0 +1,182 @@
+//! Translation file serialization
+//!
+//! This module writes a translation structure back to TOML, either as a new
+//! translation file or by updating an existing one in place, so tooling can
+//! edit translation files without rewriting the comments around them.
+
+use std::collections::HashMap;
+
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
+
+use super::collection::{PRIORITY_KEY, VERSION_KEY};
+use super::condition::{CFG_KEY, TARGET_OS_VARIANTS};
+use super::node::{TransformError, TranslationNode, sorted_translations};
+use super::random::{ENTRY_SEPARATOR, has_random_entries, random_entries};
+use super::variant::VARIANT_SEPARATOR;
+use super::whitespace::{TRIM_KEY, dedent};
+
+impl TranslationNode {
+    /// Writes the structure as a new TOML translation file
+    ///
+    /// Every translation object is written as a table, with the keys and
+    /// languages sorted so the output is stable.
+    pub fn to_toml(&self) -> String {
+        let mut document = DocumentMut::new();
+        sync_table(document.as_table_mut(), self, &Vec::new());
+
+        document.to_string().trim_start().to_string()
+    }
+
+    /// Writes the structure over an existing TOML translation file
+    ///
+    /// The keys and translations the document already has keep their order,
+    /// formatting and comments, the ones changed are replaced in place, the
+    /// ones missing from the structure are removed and new ones are added
+    /// next to the tables of the same nesting, like with
+    /// [`to_toml`](Self::to_toml). The `_priority`, `_cfg` and `_trim` keys
+    /// are kept, along with the target operating system variants and the
+    /// indentation of the values dedented by `_trim`. Named variants, stored at
+    /// their own paths, are written back as tables of their translation object,
+    /// and random entries as arrays.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the updated document
+    /// - `Err(TransformError)` if the document is not valid TOML
+    pub fn update_toml(&self, document: &str) -> Result<String, TransformError> {
+        let mut document = document
+            .parse::<DocumentMut>()
+            .map_err(|err| TransformError::InvalidDocument(err.to_string()))?;
+
+        sync_table(document.as_table_mut(), self, &Vec::new());
+
+        Ok(document.to_string())
+    }
+}
+
+/// Named variants of a translation object, by name
+type Variants<'a> = Vec<(&'a str, &'a TranslationNode)>;
+
+/// Updates a TOML table to hold a node
+///
+/// Translation objects are written with their named variants as tables.
+fn sync_table(table: &mut dyn TableLike, node: &TranslationNode, variants: &Variants) {
+    match node {
+        TranslationNode::Object(nested) => {
+            let mut keys = Vec::new();
+            let mut nested_variants = HashMap::<&str, Variants>::new();
+
+            for (key, node) in nested {
+                match key.split_once(VARIANT_SEPARATOR) {
+                    Some((key, name)) => nested_variants.entry(key).or_default().push((name, node)),
+                    None => keys.push(key.as_str()),
+                }
+            }
+
+            // The priority, version, predicates and trimming of the file aren't part
+            // of the structure
+            remove_stale(table, |key| {
+                [PRIORITY_KEY, VERSION_KEY, CFG_KEY, TRIM_KEY].contains(&key)
+                    || nested.contains_key(key)
+            });
+
+            keys.sort();
+
+            for key in keys {
+                let mut variants = nested_variants.remove(key).unwrap_or_default();
+                variants.sort_by_key(|(name, _)| *name);
+
+                match table.get_mut(key).and_then(Item::as_table_like_mut) {
+                    Some(existing) => sync_table(existing, &nested[key], &variants),
+                    None => {
+                        table.insert(key, Item::Table(new_table(&nested[key], &variants)));
+                    },
+                }
+            }
+        },
+        TranslationNode::Translation(translations) => {
+            remove_stale(table, |key| {
+                key == CFG_KEY
+                    || key == TRIM_KEY
+                    || TARGET_OS_VARIANTS.contains(&key)
+                    || variants.iter().any(|(name, _)| *name == key)
+                    || translations.keys().any(|language| language.code() == key)
+            });
+
+            for (language, translation) in sorted_translations(translations) {
+                match table.get_mut(language.code()) {
+                    // Values dedented by `_trim` keep their indentation
+                    Some(Item::Value(existing))
+                        if joined_value(existing).is_some_and(|existing| {
+                            existing == *translation || dedent_entries(&existing) == *translation
+                        }) => {},
+                    // Replaced keeping the comments around the value
+                    Some(Item::Value(existing)) => {
+                        let decor = existing.decor().clone();
+                        *existing = translation_value(translation);
+                        *existing.decor_mut() = decor;
+                    },
+                    _ => {
+                        table.insert(language.code(), Item::Value(translation_value(translation)));
+                    },
+                }
+            }
+
+            for (name, variant) in variants {
+                match table.get_mut(name).and_then(Item::as_table_like_mut) {
+                    Some(existing) => sync_table(existing, variant, &Vec::new()),
+                    None => {
+                        table.insert(name, Item::Table(new_table(variant, &Vec::new())));
+                    },
+                }
+            }
+        },
+    }
+}
+
+/// Removes the keys of a TOML table the node doesn't have
+fn remove_stale(table: &mut dyn TableLike, keep: impl Fn(&str) -> bool) {
+    let stale =
+        table.iter().map(|(key, _)| key.to_string()).filter(|key| !keep(key)).collect::<Vec<_>>();
+
+    for key in stale {
+        table.remove(&key);
+    }
+}
+
+/// Creates the TOML table of a new node, nestings being implicit
+fn new_table(node: &TranslationNode, variants: &Variants) -> Table {
+    let mut table = Table::new();
+
+    match node {
+        TranslationNode::Object(_) => table.set_implicit(true),
+        TranslationNode::Translation(_) => table.decor_mut().set_prefix("\n"),
+    }
+
+    sync_table(&mut table, node, variants);
+    table
+}
+
+/// Creates the TOML value of a translation, an array if it has random entries
+fn translation_value(translation: &str) -> Value {
+    match has_random_entries(translation) {
+        true => Value::Array(random_entries(translation).collect()),
+        false => Value::from(translation),
+    }
+}
+
+/// Reads a TOML value as a translation, the entries of arrays joined
+fn joined_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(translation) => Some(translation.value().clone()),
+        Value::Array(entries) => {
+            let entries = entries.iter().map(Value::as_str).collect::<Option<Vec<_>>>()?;
+            Some(entries.join(&ENTRY_SEPARATOR.to_string()))
+        },
+        _ => None,
+    }
+}
+
+/// Dedents every random entry of a translation
+fn dedent_entries(translation: &str) -> String {
+    random_entries(translation).map(dedent).collect::<Vec<_>>().join(&ENTRY_SEPARATOR.to_string())
+}